@@ -28,7 +28,7 @@ async function arb(): Promise<void> {
       });
 
       for (const [symbol, oracle] of oracles) {
-        const { syntheticAsset, syntheticMint } = syntheticAssetPDA(client.programId, new PublicKey(oracle.oracle));
+        const { syntheticAsset, syntheticMint } = syntheticAssetPDA(client.programId, symbol);
         if (await context.connection.getAccountInfo(syntheticAsset) != null) {
           const marginAccount = marginAccountPDA(
             client.programId,
@@ -46,6 +46,7 @@ async function arb(): Promise<void> {
           const price = await client.getOraclePrice(new PublicKey(oracle.oracle));
 
           await client.mintSyntheticAsset({
+            symbol,
             owner: context.wallet.publicKey,
             syntheticOracle: new PublicKey(oracle.oracle),
             collateralMint: usdcMint,
@@ -89,7 +90,7 @@ async function arb(): Promise<void> {
 
   const publicKeys: PublicKey[] = [];
   for (const [symbol, oracle] of oracles) {
-    const { syntheticMint } = syntheticAssetPDA(client.programId, new PublicKey(oracle.oracle));
+    const { syntheticMint } = syntheticAssetPDA(client.programId, symbol);
     const { swapPool } = swapPoolPDA(tokenSwap.programId, syntheticMint, usdcMint);
     publicKeys.push(swapPool);
   }
@@ -1,12 +1,49 @@
+import { BN } from "@coral-xyz/anchor";
+import NodeWallet from "@coral-xyz/anchor/dist/cjs/nodewallet";
+import { Transaction } from "@solana/web3.js";
 import chalk from 'chalk';
-import { Context } from "../../sdk/src";
+import { Context, ResynthClient, SYNTH_DECIMALS } from "../../sdk/src";
 
 async function liquidate(): Promise<void> {
-  const context = new Context();
+  const context = new Context("localnet", undefined, NodeWallet.local());
+  const client = new ResynthClient(context);
 
-  //TODO check which synth accounts can be liquidated
+  const syntheticAssets = await client.fetchAllSyntheticAssets();
+  const marginAccounts = await client.fetchAllMarginAccounts();
 
-  //TODO liquidate them via the token swap
+  for (const marginAccount of marginAccounts) {
+    const syntheticAsset = syntheticAssets.find(asset => asset.publicKey.equals(marginAccount.account.syntheticAsset));
+    if (marginAccount.account.normalizedDebt.isZero() || !syntheticAsset) {
+      continue;
+    }
+
+    // Debt is repaid from the liquidators own synthetic tokens
+    const synthBalance = await context.getTokenBalance(syntheticAsset.account.syntheticMint);
+    if (synthBalance == 0) {
+      continue;
+    }
+
+    // The program caps the repayment at the close factor of the debt
+    const instruction = await client.liquidateMarginAccountInstruction({
+      repayAmount: new BN(Math.floor(synthBalance * 10 ** SYNTH_DECIMALS)),
+      liquidator: context.wallet.publicKey,
+      syntheticAsset,
+      marginAccount: marginAccount.publicKey,
+    });
+    const transaction = new Transaction().add(instruction);
+
+    // Healthy margin accounts are rejected by the program, so only send liquidations that simulate
+    const { blockhash } = await context.connection.getLatestBlockhash();
+    transaction.recentBlockhash = blockhash;
+    transaction.feePayer = context.wallet.publicKey;
+    const simulation = await context.connection.simulateTransaction(transaction, [context.wallet.payer]);
+    if (simulation.value.err) {
+      continue;
+    }
+
+    await context.provider.sendAndConfirm(transaction);
+    console.log(chalk.green(`liquidated ${marginAccount.publicKey.toBase58()}`));
+  }
 }
 
 liquidate();
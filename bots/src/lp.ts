@@ -3,7 +3,7 @@ import NodeWallet from "@coral-xyz/anchor/dist/cjs/nodewallet";
 import { AccountLayout, getAssociatedTokenAddressSync } from "@solana/spl-token";
 import { PublicKey } from "@solana/web3.js";
 import chalk from 'chalk';
-import { Context, marginAccountPDA, ResynthClient, swapPoolPDA, syntheticAssetPDA, TokenFaucetClient, TokenSwapClient } from "../../sdk/src";
+import { Context, DEFAULT_SYNTHETIC_ASSET_PARAMS, marginAccountPDA, ResynthClient, swapPoolPDA, SYNTH_DECIMALS, syntheticAssetPDA, TokenFaucetClient, TokenSwapClient } from "../../sdk/src";
 
 const max_lp_portfolio_amount = 20_000;
 
@@ -39,7 +39,7 @@ async function lp(): Promise<void> {
 
     console.log(`symbol: ${symbol} price: ${price} oracle: ${address.toBase58()}`);
 
-    const { syntheticAsset, collateralVault, syntheticMint, assetAuthority } = syntheticAssetPDA(client.programId, address);
+    const { syntheticAsset, collateralVault, syntheticMint, assetAuthority } = syntheticAssetPDA(client.programId, symbol);
 
     const synthTokenAccount = getAssociatedTokenAddressSync(syntheticMint, context.wallet.publicKey);
 
@@ -48,8 +48,11 @@ async function lp(): Promise<void> {
     if (synthBalance == 0) {
       if (await context.connection.getAccountInfo(syntheticAsset) == null) {
         await client.initializeSyntheticAsset({
+          symbol,
+          params: DEFAULT_SYNTHETIC_ASSET_PARAMS,
           collateralMint: collateralMint,
           syntheticOracle: address,
+          syntheticDecimals: SYNTH_DECIMALS,
         });
       }
 
@@ -67,6 +70,7 @@ async function lp(): Promise<void> {
       }
 
       await client.mintSyntheticAsset({
+        symbol,
         owner: context.wallet.publicKey,
         syntheticOracle: address,
         collateralMint: collateralMint,
@@ -1,6 +1,6 @@
 use anchor_lang::prelude::*;
 pub mod pc;
-use pc::Price;
+use pc::{Price, PriceStatus};
 
 declare_id!("HVKkrPBatCq4KkEo2j92o273xTw596hhUoFsMNnNbUfM");
 
@@ -16,6 +16,8 @@ pub mod pyth {
         price_oracle.magic = 2712847316;
         price_oracle.agg.price = price;
         price_oracle.agg.conf = conf;
+        price_oracle.agg.status = PriceStatus::Trading;
+        price_oracle.timestamp = Clock::get()?.unix_timestamp;
         price_oracle.valid_slot = 228506959; //todo just turned 1->2 for negative delay
         price_oracle.ver = 2;
         price_oracle.atype = 3;
@@ -32,6 +34,8 @@ pub mod pyth {
 
         price_oracle.agg.price = price as i64;
         price_oracle.agg.conf = conf;
+        price_oracle.agg.status = PriceStatus::Trading;
+        price_oracle.timestamp = Clock::get()?.unix_timestamp;
         price_oracle.twap = price_oracle
             .twap
             .checked_add(price)
@@ -54,6 +58,8 @@ pub mod pyth {
             .unwrap(); //todo
         price_oracle.agg.price = price as i64;
         price_oracle.agg.conf = conf;
+        price_oracle.agg.status = PriceStatus::Trading;
+        price_oracle.timestamp = Clock::get()?.unix_timestamp;
         price_oracle.valid_slot = slot;
 
         Ok(())
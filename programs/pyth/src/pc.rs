@@ -89,7 +89,7 @@ pub struct Price {
     pub drv1: i64,             // Space for future derived values.
     pub drv2: i64,             // Space for future derived values.
     pub drv3: i64,             // Space for future derived values.
-    pub drv4: i64,             // Space for future derived values.
+    pub timestamp: i64,        // Publish time of agg. price.
     pub drv5: i64,             // Space for future derived values.
     pub prod: AccKey,          // Product account key.
     pub next: AccKey,          // Next Price account in linked list.
@@ -9,6 +9,97 @@ pub enum Errors {
     #[msg("The margin account is undercollateralized")]
     Undercollateralized,
 
-    #[msg("Collateral decimals is rqeuired to be 6 because it's value is hardcoded to $1")]
-    InvalidCollateralMintDecimals,
+    #[msg("The margin account is healthy and cannot be liquidated")]
+    MarginAccountHealthy,
+    #[msg("The liquidation parameters are invalid")]
+    InvalidLiquidationParameters,
+    #[msg("Math overflow")]
+    MathOverflow,
+
+    #[msg("The oracle price is not currently trading")]
+    OracleNotTrading,
+    #[msg("The oracle confidence interval is too wide")]
+    OracleConfidenceTooWide,
+    #[msg("The oracle parameters are invalid")]
+    InvalidOracleParameters,
+    #[msg("The collateral ratios are invalid")]
+    InvalidCollateralRatios,
+
+    #[msg("The collateral is not accepted by the synthetic asset")]
+    InvalidCollateral,
+    #[msg("The collateral parameters are invalid")]
+    InvalidCollateralParameters,
+    #[msg("The synthetic asset accepts the maximum number of collateral types")]
+    CollateralTypesFull,
+
+    #[msg("The fee parameters are invalid")]
+    InvalidFeeParameters,
+    #[msg("The margin account does not have enough collateral to pay the fee")]
+    InsufficientCollateralForFee,
+
+    #[msg("The synthetic asset is not in the debt pool")]
+    InvalidDebtPool,
+    #[msg("The synthetic asset shares a debt pool")]
+    SyntheticAssetPooled,
+    #[msg("The debt pool has the maximum number of synthetic assets")]
+    DebtPoolFull,
+    #[msg("The synthetic asset has outstanding debt")]
+    OutstandingDebt,
+
+    #[msg("The cross-margin account borrows the maximum number of synthetic assets")]
+    CrossMarginPositionsFull,
+    #[msg("The synthetic asset accounts do not match the cross-margin positions")]
+    InvalidCrossMarginPosition,
+
+    #[msg("Minting would exceed the debt ceiling of the synthetic asset")]
+    DebtCeilingExceeded,
+    #[msg("Minting would exceed the debt cap of a single margin account")]
+    AccountDebtCapExceeded,
+    #[msg("The margin account debt must be repaid in full or stay above the minimum debt")]
+    DebtBelowMinimum,
+    #[msg("The per-account debt cap must be at least the minimum debt")]
+    InvalidDebtLimits,
+
+    #[msg("A vault holds less collateral than deposited into the synthetic asset")]
+    CollateralAccountingMismatch,
+    #[msg("The synthetic supply exceeds the debt owed to the synthetic asset")]
+    DebtAccountingMismatch,
+
+    #[msg("The margin account has outstanding debt")]
+    MarginAccountHasDebt,
+
+    #[msg("The synthetic asset is not active and can't be minted")]
+    SyntheticAssetNotActive,
+
+    #[msg("The synthetic asset is shut down")]
+    SyntheticAssetShutdown,
+    #[msg("The synthetic asset is not shut down")]
+    SyntheticAssetNotShutdown,
+    #[msg("The settlement price must be positive")]
+    InvalidSettlementPrice,
+    #[msg("All debt must be settled and fees collected before redeeming")]
+    SettlementPending,
+
+    #[msg("The margin account belongs to another synthetic asset")]
+    InvalidMarginAccount,
+    #[msg("Margin accounts must be passed in order of collateral ratio, lowest first")]
+    InvalidRedemptionOrder,
+
+    #[msg("The circuit breaker is cooling down after the oracle price moved too fast")]
+    CircuitBreakerCooldown,
+
+    #[msg("The oracle account is not owned by an allowed oracle program")]
+    InvalidOracleOwner,
+    #[msg("Symbols must be between 1 and 32 bytes")]
+    InvalidSymbol,
+
+    #[msg("At most 4 oracle programs can be allowed, none of them the default pubkey")]
+    InvalidOraclePrograms,
+    #[msg("Only the program upgrade authority can initialize the program config")]
+    InvalidProgramAuthority,
+
+    #[msg("A margin account can only be redeemed from once per redemption")]
+    DuplicateMarginAccount,
+    #[msg("Every margin account must be closed before closing the synthetic asset")]
+    MarginAccountsOpen,
 }
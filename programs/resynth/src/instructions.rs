@@ -1,11 +1,81 @@
+mod accept_admin;
+mod add_collateral_type;
+mod add_pool_synthetic_asset;
+mod audit_synthetic_asset;
+mod burn_pool_debt;
 mod burn_synthetic_asset;
+mod close_margin_account;
+mod close_synthetic_asset;
+mod collect_stability_fees;
+mod confirm_oracle_price;
+mod cross_margin_burn;
+mod cross_margin_deposit;
+mod cross_margin_mint;
+mod cross_margin_withdraw;
+mod deposit_collateral;
+mod exchange_synthetic_asset;
+mod initialize_cross_margin_account;
+mod initialize_debt_pool;
 mod initialize_margin_account;
+mod initialize_program_config;
 mod initialize_synthetic_asset;
+mod issue_pool_debt;
+mod liquidate_cross_margin_account;
+mod liquidate_margin_account;
+mod liquidate_pool_account;
 mod mint_synthetic_asset;
-mod close_synthetic_asset;
+mod redeem;
+mod redeem_settlement;
+mod repay;
+mod set_oracle;
+mod set_oracle_programs;
+mod settle_cross_margin_position;
+mod settle_margin_account;
+mod shutdown_synthetic_asset;
+mod transfer_admin;
+mod update_circuit_breaker;
+mod update_collateral_type;
+mod update_synthetic_asset;
+mod withdraw_collateral;
+mod withdraw_treasury;
 
+pub use accept_admin::*;
+pub use add_collateral_type::*;
+pub use add_pool_synthetic_asset::*;
+pub use audit_synthetic_asset::*;
+pub use burn_pool_debt::*;
 pub use burn_synthetic_asset::*;
+pub use close_margin_account::*;
+pub use close_synthetic_asset::*;
+pub use collect_stability_fees::*;
+pub use confirm_oracle_price::*;
+pub use cross_margin_burn::*;
+pub use cross_margin_deposit::*;
+pub use cross_margin_mint::*;
+pub use cross_margin_withdraw::*;
+pub use deposit_collateral::*;
+pub use exchange_synthetic_asset::*;
+pub use initialize_cross_margin_account::*;
+pub use initialize_debt_pool::*;
 pub use initialize_margin_account::*;
+pub use initialize_program_config::*;
 pub use initialize_synthetic_asset::*;
+pub use issue_pool_debt::*;
+pub use liquidate_cross_margin_account::*;
+pub use liquidate_margin_account::*;
+pub use liquidate_pool_account::*;
 pub use mint_synthetic_asset::*;
-pub use close_synthetic_asset::*;
+pub use redeem::*;
+pub use redeem_settlement::*;
+pub use repay::*;
+pub use set_oracle::*;
+pub use set_oracle_programs::*;
+pub use settle_cross_margin_position::*;
+pub use settle_margin_account::*;
+pub use shutdown_synthetic_asset::*;
+pub use transfer_admin::*;
+pub use update_circuit_breaker::*;
+pub use update_collateral_type::*;
+pub use update_synthetic_asset::*;
+pub use withdraw_collateral::*;
+pub use withdraw_treasury::*;
@@ -0,0 +1,25 @@
+use crate::SyntheticAsset;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct AcceptAdmin<'info> {
+    /// The synthetic asset being transferred
+    #[account(mut,
+      has_one = pending_admin,
+    )]
+    pub synthetic_asset: AccountLoader<'info, SyntheticAsset>,
+
+    /// The proposed admin of the synthetic asset
+    pub pending_admin: Signer<'info>,
+}
+
+impl<'info> AcceptAdmin<'info> {
+    pub fn process(ctx: Context<Self>) -> Result<()> {
+        let mut synthetic_asset = ctx.accounts.synthetic_asset.load_mut()?;
+
+        synthetic_asset.admin = ctx.accounts.pending_admin.key();
+        synthetic_asset.pending_admin = Pubkey::default();
+
+        Ok(())
+    }
+}
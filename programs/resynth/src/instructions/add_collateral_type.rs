@@ -0,0 +1,104 @@
+use crate::{
+    seeds, validate_loan_to_value, validate_oracle, CollateralType, Errors, ProgramConfig,
+    SyntheticAsset,
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+#[derive(Accounts)]
+pub struct AddCollateralType<'info> {
+    /// The synthetic asset accepting the new collateral
+    #[account(mut,
+      has_one = admin,
+      has_one = asset_authority,
+    )]
+    pub synthetic_asset: AccountLoader<'info, SyntheticAsset>,
+
+    /// The mint of the new collateral
+    pub collateral_mint: Box<Account<'info, Mint>>,
+
+    /// The vault of the new collateral
+    #[account(init,
+        seeds = [
+            seeds::VAULT.as_ref(),
+            synthetic_asset.key().as_ref(),
+            collateral_mint.key().as_ref(),
+        ],
+        bump,
+        payer = payer,
+        token::mint = collateral_mint,
+        token::authority = asset_authority,
+    )]
+    pub collateral_vault: Box<Account<'info, TokenAccount>>,
+
+    /// The oracle price feed of the new collateral
+    /// CHECK: Validated as a price account of an allowed oracle program
+    pub collateral_oracle: AccountInfo<'info>,
+    /// The program-wide config listing the allowed oracle programs
+    #[account(
+      seeds = [
+        seeds::PROGRAM_CONFIG.as_ref(),
+      ],
+      bump,
+    )]
+    pub program_config: AccountLoader<'info, ProgramConfig>,
+
+    /// The mint authority that can mint synthetic assets and transfer vault collateral
+    /// CHECK:
+    pub asset_authority: AccountInfo<'info>,
+
+    /// The admin of the synthetic asset
+    pub admin: Signer<'info>,
+
+    /// The payer of rent for the vault
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// The token program to initialize token accounts
+    pub token_program: Program<'info, Token>,
+
+    /// The system program to create accounts
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> AddCollateralType<'info> {
+    pub fn process(ctx: Context<Self>, loan_to_value_bps: u64) -> Result<()> {
+        validate_oracle(
+            &ctx.accounts.collateral_oracle,
+            &*ctx.accounts.program_config.load()?,
+        )?;
+
+        validate_loan_to_value(loan_to_value_bps)?;
+
+        let mut synthetic_asset = ctx.accounts.synthetic_asset.load_mut()?;
+        let collateral_mint = ctx.accounts.collateral_mint.key();
+
+        // The primary collateral is valued at $1 and can't also be registered
+        require_keys_neq!(
+            collateral_mint,
+            synthetic_asset.collateral_mint,
+            Errors::InvalidCollateralParameters
+        );
+
+        // Register the collateral in the first unused slot
+        let collateral_type = synthetic_asset
+            .collateral_types
+            .iter_mut()
+            .find(|collateral_type| !collateral_type.is_registered())
+            .ok_or(Errors::CollateralTypesFull)?;
+
+        *collateral_type = CollateralType {
+            mint: collateral_mint,
+            vault: ctx.accounts.collateral_vault.key(),
+            oracle: ctx.accounts.collateral_oracle.key(),
+            oracle_program: *ctx.accounts.collateral_oracle.owner,
+            loan_to_value_bps,
+            total_collateral: 0,
+            settlement_collateral: 0,
+            decimals: ctx.accounts.collateral_mint.decimals,
+            padding: [0; 7],
+        };
+
+        Ok(())
+    }
+}
@@ -0,0 +1,51 @@
+use crate::{DebtPool, Errors, SyntheticAsset};
+use anchor_lang::prelude::*;
+use anchor_spl::token::Mint;
+
+#[derive(Accounts)]
+pub struct AddPoolSyntheticAsset<'info> {
+    /// The debt pool the synthetic asset joins
+    #[account(mut,
+      has_one = admin,
+    )]
+    pub debt_pool: AccountLoader<'info, DebtPool>,
+
+    /// The synthetic asset joining the debt pool
+    #[account(mut,
+      has_one = admin,
+      has_one = synthetic_mint,
+    )]
+    pub synthetic_asset: AccountLoader<'info, SyntheticAsset>,
+    /// The synthetic mint of the synthetic asset
+    pub synthetic_mint: Box<Account<'info, Mint>>,
+
+    /// The admin of both the debt pool and the synthetic asset
+    pub admin: Signer<'info>,
+}
+
+impl<'info> AddPoolSyntheticAsset<'info> {
+    pub fn process(ctx: Context<Self>) -> Result<()> {
+        let mut debt_pool = ctx.accounts.debt_pool.load_mut()?;
+        let mut synthetic_asset = ctx.accounts.synthetic_asset.load_mut()?;
+
+        require!(!synthetic_asset.is_pooled(), Errors::SyntheticAssetPooled);
+
+        // Synthetic assets in circulation are owed by isolated margin accounts,
+        // and would be counted again as pool debt
+        require!(
+            ctx.accounts.synthetic_mint.supply == 0,
+            Errors::OutstandingDebt
+        );
+
+        let synthetic_asset_count = debt_pool.synthetic_asset_count();
+        let pooled_asset = debt_pool
+            .synthetic_assets
+            .get_mut(synthetic_asset_count)
+            .ok_or(Errors::DebtPoolFull)?;
+        *pooled_asset = ctx.accounts.synthetic_asset.key();
+
+        synthetic_asset.debt_pool = ctx.accounts.debt_pool.key();
+
+        Ok(())
+    }
+}
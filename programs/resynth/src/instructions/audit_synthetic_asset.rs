@@ -0,0 +1,67 @@
+use crate::{CollateralSlot, Errors, SyntheticAsset};
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, TokenAccount};
+
+#[derive(Accounts)]
+pub struct AuditSyntheticAsset<'info> {
+    /// The synthetic asset being audited
+    #[account(
+      has_one = collateral_vault,
+      has_one = synthetic_mint,
+    )]
+    pub synthetic_asset: AccountLoader<'info, SyntheticAsset>,
+    /// The vault of the primary collateral
+    pub collateral_vault: Box<Account<'info, TokenAccount>>,
+    /// The synthetic mint of the synthetic asset
+    pub synthetic_mint: Box<Account<'info, Mint>>,
+}
+
+impl<'info> AuditSyntheticAsset<'info> {
+    pub fn process(ctx: Context<Self>) -> Result<()> {
+        let synthetic_asset = ctx.accounts.synthetic_asset.load()?;
+
+        // Every vault must hold at least the collateral deposited into it, and the collateral
+        // set aside for holders after a shutdown. Tokens sent to a vault directly are not owed.
+        require!(
+            ctx.accounts.collateral_vault.amount
+                >= synthetic_asset.owed_collateral(CollateralSlot::Primary)?,
+            Errors::CollateralAccountingMismatch
+        );
+
+        // The vaults of registered collateral are passed as remaining accounts, in registry order
+        let collateral_type_count = synthetic_asset.collateral_type_count();
+        require!(
+            ctx.remaining_accounts.len() == collateral_type_count,
+            Errors::InvalidCollateral
+        );
+        for (index, vault_info) in ctx.remaining_accounts.iter().enumerate() {
+            require_keys_eq!(
+                vault_info.key(),
+                synthetic_asset.collateral_types[index].vault,
+                Errors::InvalidCollateral
+            );
+
+            let vault = Account::<TokenAccount>::try_from(vault_info)?;
+            require!(
+                vault.amount
+                    >= synthetic_asset.owed_collateral(CollateralSlot::Registered(index))?,
+                Errors::CollateralAccountingMismatch
+            );
+        }
+
+        // Every synthetic token, and every fee yet to be minted, must be owed by some debt.
+        // Tokens burned by their holders directly are no longer owed by anyone.
+        let owed_supply = ctx
+            .accounts
+            .synthetic_mint
+            .supply
+            .checked_add(synthetic_asset.accrued_fees)
+            .ok_or(Errors::MathOverflow)?;
+        require!(
+            owed_supply <= synthetic_asset.total_debt,
+            Errors::DebtAccountingMismatch
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,219 @@
+use crate::{
+    load_pool_asset_prices, seeds, usd_value, DebtPool, MarginAccount, PriceRequirement,
+    SyntheticAsset,
+};
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{burn, transfer, Burn, Mint, Token, TokenAccount, Transfer},
+};
+
+#[derive(Accounts)]
+pub struct BurnPoolDebt<'info> {
+    /// The synthetic asset account
+    #[account(mut,
+      has_one = debt_pool,
+      has_one = synthetic_mint,
+      has_one = synthetic_oracle,
+      has_one = asset_authority,
+    )]
+    pub synthetic_asset: AccountLoader<'info, SyntheticAsset>,
+    /// The debt pool shared by the synthetic asset
+    #[account(mut)]
+    pub debt_pool: AccountLoader<'info, DebtPool>,
+    /// The vault that collateral is withdrawn from, of any collateral the synthetic asset accepts
+    #[account(mut,
+      token::mint = collateral_mint,
+    )]
+    pub collateral_vault: Box<Account<'info, TokenAccount>>,
+    /// The mint of the collateral being withdrawn
+    pub collateral_mint: Box<Account<'info, Mint>>,
+    /// The synthetic mint of the synthetic asset
+    #[account(mut)]
+    pub synthetic_mint: Box<Account<'info, Mint>>,
+    /// The oracle price feed, to determine margin account health
+    /// CHECK:
+    pub synthetic_oracle: AccountInfo<'info>,
+    /// The mint authority that can mint synthetic assets and transfer vault collateral
+    /// CHECK:
+    pub asset_authority: AccountInfo<'info>,
+
+    /// The receiver of the synthetic asset
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    /// The margin account of the owner, to track collateral and debt
+    #[account(mut,
+      has_one = owner,
+      has_one = synthetic_asset,
+    )]
+    pub margin_account: AccountLoader<'info, MarginAccount>,
+    /// The treasury receiving fees, in the collateral of the vault
+    #[account(
+        init_if_needed,
+        seeds = [
+            seeds::TREASURY.as_ref(),
+            synthetic_asset.key().as_ref(),
+            collateral_mint.key().as_ref(),
+        ],
+        bump,
+        payer = owner,
+        token::mint = collateral_mint,
+        token::authority = asset_authority,
+    )]
+    pub treasury: Box<Account<'info, TokenAccount>>,
+    /// The owners account that collateral will be transferred from
+    #[account(
+      init_if_needed,
+      payer = owner,
+      associated_token::mint = collateral_mint,
+      associated_token::authority = owner,
+    )]
+    pub collateral_account: Box<Account<'info, TokenAccount>>,
+    /// The owners account that will receive synthetic tokens
+    #[account(mut,
+        token::authority = owner,
+    )]
+    pub synthetic_account: Box<Account<'info, TokenAccount>>,
+
+    pub system_program: Program<'info, System>,
+
+    /// The token program for CPI calls
+    pub token_program: Program<'info, Token>,
+
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+impl<'info> BurnPoolDebt<'info> {
+    /// CPI context to transfer collateral from the owners account to the vault
+    pub fn collateral_transfer_context(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        return CpiContext::new(
+            self.token_program.to_account_info(),
+            Transfer {
+                from: self.collateral_vault.to_account_info(),
+                to: self.collateral_account.to_account_info(),
+                authority: self.asset_authority.to_account_info(),
+            },
+        );
+    }
+
+    /// CPI context to mint synthetic tokens to the owners token account
+    pub fn burn_synthetic_context(&self) -> CpiContext<'_, '_, '_, 'info, Burn<'info>> {
+        return CpiContext::new(
+            self.token_program.to_account_info(),
+            Burn {
+                mint: self.synthetic_mint.to_account_info(),
+                from: self.synthetic_account.to_account_info(),
+                authority: self.owner.to_account_info(),
+            },
+        );
+    }
+
+    /// CPI context to transfer fees from the vault to the treasury
+    pub fn fee_transfer_context(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        return CpiContext::new(
+            self.token_program.to_account_info(),
+            Transfer {
+                from: self.collateral_vault.to_account_info(),
+                to: self.treasury.to_account_info(),
+                authority: self.asset_authority.to_account_info(),
+            },
+        );
+    }
+
+    pub fn process(ctx: Context<Self>, collateral_amount: u64, burn_amount: u64) -> Result<()> {
+        let mut synthetic_asset = ctx.accounts.synthetic_asset.load_mut()?;
+        let mut debt_pool = ctx.accounts.debt_pool.load_mut()?;
+        let mut margin_account = ctx.accounts.margin_account.load_mut()?;
+
+        // Find which collateral is being withdrawn
+        let slot = synthetic_asset.collateral_slot(&ctx.accounts.collateral_vault.key())?;
+
+        // Repaying stays open while a feed is halted, but withdrawing collateral needs a live price
+        let requirement = if collateral_amount == 0 {
+            PriceRequirement::LastTrading
+        } else {
+            PriceRequirement::Trading
+        };
+
+        // Unwrap the oracle prices and the pool debt. Registered collateral oracles
+        // and the accounts of every pooled synthetic asset are passed as remaining accounts
+        let mut prices = load_pool_asset_prices(
+            &ctx.accounts.synthetic_oracle,
+            ctx.remaining_accounts,
+            &synthetic_asset,
+            &debt_pool,
+            requirement,
+        )?;
+
+        // Trip the circuit breaker on a bad print, and never withdraw against one
+        let current_slot = Clock::get()?.slot;
+        synthetic_asset.observe_price(&prices.synthetic_price, current_slot);
+        if collateral_amount > 0 {
+            synthetic_asset.verify_price_stable(current_slot)?;
+        }
+
+        // Never burn more than the outstanding debt
+        let (burn_amount, repaid_shares) =
+            margin_account.pool_repayment(&synthetic_asset, &prices, burn_amount)?;
+
+        // Charge the burn fee out of the margin account collateral. This happens before
+        // the health snapshot, so that paying the fee never blocks repaying debt.
+        let fee_amount = synthetic_asset.fee_collateral(
+            &prices,
+            slot,
+            burn_amount,
+            synthetic_asset.params.burn_fee_bps,
+        )?;
+        margin_account.charge_fee(slot, fee_amount)?;
+        synthetic_asset.withdraw_collateral(slot, fee_amount);
+
+        // Update the margin account balances
+        let previous_margin_account = *margin_account;
+        margin_account.withdraw_collateral(slot, collateral_amount);
+        synthetic_asset.withdraw_collateral(slot, collateral_amount);
+
+        // Repay the debt shares for the value burned
+        let burn_value = usd_value(
+            burn_amount,
+            synthetic_asset.synthetic_decimals,
+            &prices.synthetic_price,
+        )?;
+        prices.pool_debt.repay(burn_value, repaid_shares);
+        margin_account.repay_debt_shares(repaid_shares);
+        debt_pool.total_debt_shares = prices.pool_debt.total_debt_shares;
+        synthetic_asset.retire_debt(burn_amount)?;
+        synthetic_asset
+            .verify_minimum_debt(margin_account.pool_debt(&synthetic_asset, &prices)?)?;
+
+        // Verify burning does not make the margin account unhealthy
+        margin_account.require_healthy(
+            &previous_margin_account,
+            &synthetic_asset,
+            &prices,
+            synthetic_asset.params.initial_collateral_ratio_bps,
+        )?;
+
+        // Transfer collateral from the vault to the user
+        let signer_seeds: &[&[&[u8]]] = &[&synthetic_asset.signer_seeds()];
+        transfer(
+            ctx.accounts
+                .collateral_transfer_context()
+                .with_signer(signer_seeds),
+            collateral_amount,
+        )?;
+
+        // Transfer the fee from the vault to the treasury
+        transfer(
+            ctx.accounts
+                .fee_transfer_context()
+                .with_signer(signer_seeds),
+            fee_amount,
+        )?;
+
+        // Burn the synthetic asset from the user token account
+        burn(ctx.accounts.burn_synthetic_context(), burn_amount)?;
+
+        Ok(())
+    }
+}
@@ -1,26 +1,25 @@
-use crate::{Errors, MarginAccount, SyntheticAsset};
+use crate::{load_asset_prices, seeds, Errors, MarginAccount, PriceRequirement, SyntheticAsset};
 use anchor_lang::prelude::*;
 use anchor_spl::{
     associated_token::AssociatedToken,
     token::{burn, transfer, Burn, Mint, Token, TokenAccount, Transfer},
 };
-use pyth_sdk_solana::load_price_feed_from_account_info;
 
 #[derive(Accounts)]
 pub struct BurnSyntheticAsset<'info> {
     /// The synthetic asset account
-    #[account(
-      has_one = collateral_vault,
-      has_one = collateral_mint,
+    #[account(mut,
       has_one = synthetic_mint,
       has_one = synthetic_oracle,
       has_one = asset_authority,
     )]
     pub synthetic_asset: AccountLoader<'info, SyntheticAsset>,
-    /// The vault that is receiving collateral
-    #[account(mut)]
+    /// The vault that collateral is withdrawn from, of any collateral the synthetic asset accepts
+    #[account(mut,
+      token::mint = collateral_mint,
+    )]
     pub collateral_vault: Box<Account<'info, TokenAccount>>,
-    /// The collateral mint of the synthetic asset
+    /// The mint of the collateral being withdrawn
     pub collateral_mint: Box<Account<'info, Mint>>,
     /// The synthetic mint of the synthetic asset
     #[account(mut)]
@@ -42,6 +41,20 @@ pub struct BurnSyntheticAsset<'info> {
       has_one = synthetic_asset,
     )]
     pub margin_account: AccountLoader<'info, MarginAccount>,
+    /// The treasury receiving fees, in the collateral of the vault
+    #[account(
+        init_if_needed,
+        seeds = [
+            seeds::TREASURY.as_ref(),
+            synthetic_asset.key().as_ref(),
+            collateral_mint.key().as_ref(),
+        ],
+        bump,
+        payer = owner,
+        token::mint = collateral_mint,
+        token::authority = asset_authority,
+    )]
+    pub treasury: Box<Account<'info, TokenAccount>>,
     /// The owners account that collateral will be transferred from
     #[account(
       init_if_needed,
@@ -89,20 +102,84 @@ impl<'info> BurnSyntheticAsset<'info> {
         );
     }
 
+    /// CPI context to transfer fees from the vault to the treasury
+    pub fn fee_transfer_context(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        return CpiContext::new(
+            self.token_program.to_account_info(),
+            Transfer {
+                from: self.collateral_vault.to_account_info(),
+                to: self.treasury.to_account_info(),
+                authority: self.asset_authority.to_account_info(),
+            },
+        );
+    }
+
     pub fn process(ctx: Context<Self>, collateral_amount: u64, burn_amount: u64) -> Result<()> {
-        let synthetic_asset = ctx.accounts.synthetic_asset.load()?;
+        let mut synthetic_asset = ctx.accounts.synthetic_asset.load_mut()?;
         let mut margin_account = ctx.accounts.margin_account.load_mut()?;
 
-        // Unwrap the oracle price
-        let oracle_price = load_price_feed_from_account_info(&ctx.accounts.synthetic_oracle)
-            .map_err(|_| Errors::InvalidOracle)?
-            .get_price_unchecked();
+        // Debt is settled at the settlement price after a shutdown
+        require!(
+            !synthetic_asset.is_shutdown(),
+            Errors::SyntheticAssetShutdown
+        );
+
+        // Accrue the stability fee before the debt changes
+        synthetic_asset.accrue_stability_fee(Clock::get()?.unix_timestamp)?;
+
+        // Find which collateral is being withdrawn
+        let slot = synthetic_asset.collateral_slot(&ctx.accounts.collateral_vault.key())?;
+
+        // Repaying stays open while a feed is halted, but withdrawing collateral needs a live price
+        let requirement = if collateral_amount == 0 {
+            PriceRequirement::LastTrading
+        } else {
+            PriceRequirement::Trading
+        };
+
+        // Unwrap the oracle prices. Registered collateral oracles are passed as remaining accounts
+        let prices = load_asset_prices(
+            &ctx.accounts.synthetic_oracle,
+            ctx.remaining_accounts,
+            &synthetic_asset,
+            requirement,
+        )?;
+
+        // Trip the circuit breaker on a bad print, and never withdraw against one
+        let current_slot = Clock::get()?.slot;
+        synthetic_asset.observe_price(&prices.synthetic_price, current_slot);
+        if collateral_amount > 0 {
+            synthetic_asset.verify_price_stable(current_slot)?;
+        }
+
+        // Never burn more than the outstanding debt
+        let burn_amount = burn_amount.min(margin_account.debt(&synthetic_asset)?);
+
+        // Charge the burn fee out of the margin account collateral. This happens before
+        // the health snapshot, so that paying the fee never blocks repaying debt.
+        let fee_amount = synthetic_asset.fee_collateral(
+            &prices,
+            slot,
+            burn_amount,
+            synthetic_asset.params.burn_fee_bps,
+        )?;
+        margin_account.charge_fee(slot, fee_amount)?;
+        synthetic_asset.withdraw_collateral(slot, fee_amount);
 
         // Update the margin account balances
-        margin_account.burn_synthetic_asset(collateral_amount, burn_amount);
+        let previous_margin_account = *margin_account;
+        let normalized_amount = synthetic_asset.repay(burn_amount)?;
+        margin_account.burn_synthetic_asset(slot, collateral_amount, normalized_amount);
+        synthetic_asset.withdraw_collateral(slot, collateral_amount);
+        synthetic_asset.verify_minimum_debt(margin_account.debt(&synthetic_asset)?)?;
 
-        // Verify minting does not make the margin account unhealthy
-        margin_account.verify_healthy(oracle_price)?;
+        // Verify burning does not make the margin account unhealthy
+        margin_account.require_healthy(
+            &previous_margin_account,
+            &synthetic_asset,
+            &prices,
+            synthetic_asset.params.initial_collateral_ratio_bps,
+        )?;
 
         // Transfer collateral from the vault to the user
         let signer_seeds: &[&[&[u8]]] = &[&synthetic_asset.signer_seeds()];
@@ -113,6 +190,14 @@ impl<'info> BurnSyntheticAsset<'info> {
             collateral_amount,
         )?;
 
+        // Transfer the fee from the vault to the treasury
+        transfer(
+            ctx.accounts
+                .fee_transfer_context()
+                .with_signer(signer_seeds),
+            fee_amount,
+        )?;
+
         // Burn the synthetic asset from the user token account
         burn(ctx.accounts.burn_synthetic_context(), burn_amount)?;
 
@@ -0,0 +1,118 @@
+use crate::{CollateralSlot, Errors, MarginAccount, SyntheticAsset, MAX_COLLATERAL_TYPES};
+use anchor_lang::prelude::*;
+use anchor_spl::token::{transfer, Token, TokenAccount, Transfer};
+
+#[derive(Accounts)]
+pub struct CloseMarginAccount<'info> {
+    /// The synthetic asset account
+    #[account(mut,
+      has_one = collateral_vault,
+      has_one = asset_authority,
+    )]
+    pub synthetic_asset: AccountLoader<'info, SyntheticAsset>,
+    /// The vault of the primary collateral
+    #[account(mut)]
+    pub collateral_vault: Box<Account<'info, TokenAccount>>,
+    /// The authority that can transfer vault collateral
+    /// CHECK:
+    pub asset_authority: AccountInfo<'info>,
+
+    /// The owner of the margin account
+    pub owner: Signer<'info>,
+
+    /// The margin account to close, which must have no debt
+    #[account(mut,
+      has_one = owner,
+      has_one = synthetic_asset,
+      close = destination,
+    )]
+    pub margin_account: AccountLoader<'info, MarginAccount>,
+    /// The owners account that will receive the remaining primary collateral
+    #[account(mut,
+      token::mint = collateral_vault.mint,
+    )]
+    pub collateral_account: Box<Account<'info, TokenAccount>>,
+    /// The receiver of the margin account rent
+    /// CHECK:
+    #[account(mut)]
+    pub destination: AccountInfo<'info>,
+
+    /// The token program for CPI calls
+    pub token_program: Program<'info, Token>,
+}
+
+impl<'info> CloseMarginAccount<'info> {
+    /// CPI context to transfer collateral from a vault to an account of the owner
+    pub fn collateral_transfer_context(
+        &self,
+        vault: AccountInfo<'info>,
+        collateral_account: AccountInfo<'info>,
+    ) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        return CpiContext::new(
+            self.token_program.to_account_info(),
+            Transfer {
+                from: vault,
+                to: collateral_account,
+                authority: self.asset_authority.to_account_info(),
+            },
+        );
+    }
+
+    pub fn process(ctx: Context<'_, '_, '_, 'info, Self>) -> Result<()> {
+        let mut synthetic_asset = ctx.accounts.synthetic_asset.load_mut()?;
+        let mut margin_account = ctx.accounts.margin_account.load_mut()?;
+
+        // Debt can't be forgiven by closing the margin account
+        require!(!margin_account.has_debt(), Errors::MarginAccountHasDebt);
+
+        // The vaults of registered collateral with a balance, and the owners accounts that will
+        // receive it, are passed as remaining account pairs in registry order
+        let mut collateral_accounts = ctx.remaining_accounts.chunks(2);
+        let mut transfers = Vec::with_capacity(MAX_COLLATERAL_TYPES + 1);
+        transfers.push((
+            ctx.accounts.collateral_vault.to_account_info(),
+            ctx.accounts.collateral_account.to_account_info(),
+            margin_account.collateral_balance(CollateralSlot::Primary),
+        ));
+        for index in 0..synthetic_asset.collateral_type_count() {
+            let amount = margin_account.collateral_balance(CollateralSlot::Registered(index));
+            if amount == 0 {
+                continue;
+            }
+
+            let accounts = collateral_accounts
+                .next()
+                .filter(|accounts| accounts.len() == 2)
+                .ok_or(Errors::InvalidCollateral)?;
+            require_keys_eq!(
+                accounts[0].key(),
+                synthetic_asset.collateral_types[index].vault,
+                Errors::InvalidCollateral
+            );
+            transfers.push((accounts[0].clone(), accounts[1].clone(), amount));
+        }
+
+        // Sweep all remaining collateral out of the margin account
+        for slot in std::iter::once(CollateralSlot::Primary)
+            .chain((0..MAX_COLLATERAL_TYPES).map(CollateralSlot::Registered))
+        {
+            let amount = margin_account.collateral_balance(slot);
+            margin_account.withdraw_collateral(slot, amount);
+            synthetic_asset.withdraw_collateral(slot, amount);
+        }
+        synthetic_asset.margin_account_count -= 1;
+
+        // Transfer the remaining collateral from the vaults to the owner
+        let signer_seeds: &[&[&[u8]]] = &[&synthetic_asset.signer_seeds()];
+        for (vault, collateral_account, amount) in transfers {
+            transfer(
+                ctx.accounts
+                    .collateral_transfer_context(vault, collateral_account)
+                    .with_signer(signer_seeds),
+                amount,
+            )?;
+        }
+
+        Ok(())
+    }
+}
@@ -1,17 +1,14 @@
-use crate::SyntheticAsset;
+use crate::{seeds, Errors, SyntheticAsset, SyntheticAssetStatus};
 use anchor_lang::prelude::*;
-use anchor_spl::token::{close_account, transfer, CloseAccount, Token, TokenAccount, Transfer};
+use anchor_spl::token::{
+    close_account, transfer, CloseAccount, Mint, Token, TokenAccount, Transfer,
+};
 
-// This instruction dumps all collateral to the signer wallet,
-// and closes the synthetic asset account. After running this there is no
-// going back: the margin account PDA must be bumped.
-
-// replace with your local wallet to control closing
-mod signer_key {
-    use anchor_lang::prelude::*;
-
-    declare_id!("EjvPni9o9ku9oeNBdXwEAY4YxzyNi5E335wQP97YQmdM");
-}
+// Closing a synthetic asset happens in two steps. While anything is still owed,
+// this instruction only disables minting, so that holders can burn their synthetic
+// tokens and margin accounts can withdraw their collateral. Once the synthetic asset
+// is wound down and every margin account is closed, calling it again closes the vaults,
+// the treasuries and the synthetic asset account.
 
 #[derive(Accounts)]
 pub struct CloseSyntheticAsset<'info> {
@@ -19,25 +16,26 @@ pub struct CloseSyntheticAsset<'info> {
     #[account(mut,
       has_one = asset_authority,
       has_one = collateral_vault,
-      close = signer,
+      has_one = synthetic_mint,
+      has_one = admin,
     )]
     pub synthetic_asset: AccountLoader<'info, SyntheticAsset>,
     /// The vault to close
     #[account(mut)]
     pub collateral_vault: Box<Account<'info, TokenAccount>>,
+    /// The synthetic mint, which must have no supply left to close
+    pub synthetic_mint: Box<Account<'info, Mint>>,
     /// The mint authority that can transfer vault collateral
     /// CHECK:
     pub asset_authority: AccountInfo<'info>,
 
-    /// The receiver of the vault collateral
-    #[account(mut,
-      address = signer_key::ID,
-    )]
-    pub signer: Signer<'info>,
+    /// The admin of the synthetic asset, who receives the rent
+    #[account(mut)]
+    pub admin: Signer<'info>,
 
-    /// The owners account that vault collateral will be transferred to
+    /// The admins account that receives collateral sent to the vault without a deposit
     #[account(mut,
-      token::authority = signer
+      token::authority = admin
     )]
     pub collateral_account: Box<Account<'info, TokenAccount>>,
 
@@ -46,50 +44,163 @@ pub struct CloseSyntheticAsset<'info> {
 }
 
 impl<'info> CloseSyntheticAsset<'info> {
-    /// CPI context to transfer collateral from the owners account to the vault
-    pub fn collateral_transfer_context(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+    /// CPI context to transfer tokens held by the synthetic asset to an account of the admin
+    pub fn token_transfer_context(
+        &self,
+        from: AccountInfo<'info>,
+        to: AccountInfo<'info>,
+    ) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
         return CpiContext::new(
             self.token_program.to_account_info(),
             Transfer {
-                from: self.collateral_vault.to_account_info(),
-                to: self.collateral_account.to_account_info(),
+                from,
+                to,
                 authority: self.asset_authority.to_account_info(),
             },
         );
     }
 
-    /// CPI context to mint synthetic tokens to the owners token account
-    pub fn close_vault_context(&self) -> CpiContext<'_, '_, '_, 'info, CloseAccount<'info>> {
+    /// CPI context to close a token account of the synthetic asset, returning its rent to the admin
+    pub fn close_token_account_context(
+        &self,
+        account: AccountInfo<'info>,
+    ) -> CpiContext<'_, '_, '_, 'info, CloseAccount<'info>> {
         return CpiContext::new(
             self.token_program.to_account_info(),
             CloseAccount {
-                account: self.collateral_vault.to_account_info(),
-                destination: self.collateral_account.to_account_info(),
+                account,
+                destination: self.admin.to_account_info(),
                 authority: self.asset_authority.to_account_info(),
             },
         );
     }
 
-    pub fn process(ctx: Context<Self>) -> Result<()> {
-        let synthetic_asset = ctx.accounts.synthetic_asset.load()?;
-
-        // Mint the synthetic asset to the user token account
-        let signer_seeds: &[&[&[u8]]] = &[&synthetic_asset.signer_seeds()];
-
-        // Transfer collateral from the user to the vault
+    /// Transfer everything left in a vault or treasury to the admin, then close it
+    fn sweep_token_account(
+        &self,
+        account: AccountInfo<'info>,
+        destination: AccountInfo<'info>,
+        signer_seeds: &[&[&[u8]]],
+    ) -> Result<()> {
+        let amount = Account::<TokenAccount>::try_from(&account)?.amount;
         transfer(
-            ctx.accounts
-                .collateral_transfer_context()
+            self.token_transfer_context(account.clone(), destination)
                 .with_signer(signer_seeds),
-            ctx.accounts.collateral_vault.amount,
+            amount,
         )?;
 
         close_account(
-            ctx.accounts
-                .close_vault_context()
-                .with_signer(signer_seeds)
+            self.close_token_account_context(account)
                 .with_signer(signer_seeds),
-        )?;
+        )
+    }
+
+    pub fn process(ctx: Context<'_, '_, '_, 'info, Self>) -> Result<()> {
+        let mut synthetic_asset = ctx.accounts.synthetic_asset.load_mut()?;
+
+        // Closing an asset with outstanding debt or collateral would confiscate it.
+        // Stop minting instead, so that holders and margin accounts can exit.
+        if !synthetic_asset.is_wound_down(ctx.accounts.synthetic_mint.supply) {
+            if synthetic_asset.is_active() {
+                synthetic_asset.status = SyntheticAssetStatus::WindingDown as u8;
+            }
+            return Ok(());
+        }
+
+        // The debt pool still values every pooled synthetic asset
+        require!(!synthetic_asset.is_pooled(), Errors::SyntheticAssetPooled);
+
+        // Margin accounts left open would be valid against a synthetic asset
+        // initialized again under the same symbol
+        require!(
+            synthetic_asset.margin_account_count == 0,
+            Errors::MarginAccountsOpen
+        );
+
+        // The treasuries and registered collateral vaults can't be withdrawn from once the
+        // synthetic asset is closed. The primary collateral and synthetic treasuries are passed
+        // as remaining accounts, followed by the vault, treasury and admins account of every
+        // registered collateral type in registry order. Treasuries never created are skipped.
+        let collateral_type_count = synthetic_asset.collateral_type_count();
+        require!(
+            ctx.remaining_accounts.len() == 2 + 3 * collateral_type_count,
+            Errors::InvalidCollateral
+        );
+        let synthetic_asset_key = ctx.accounts.synthetic_asset.key();
+        let treasury_address = |mint: &Pubkey| {
+            Pubkey::find_program_address(
+                &[
+                    seeds::TREASURY.as_ref(),
+                    synthetic_asset_key.as_ref(),
+                    mint.as_ref(),
+                ],
+                ctx.program_id,
+            )
+            .0
+        };
+
+        let collateral_account = ctx.accounts.collateral_account.to_account_info();
+        let mut sweeps = vec![(
+            ctx.accounts.collateral_vault.to_account_info(),
+            collateral_account.clone(),
+        )];
+        let mut treasuries = vec![(
+            &ctx.remaining_accounts[0],
+            synthetic_asset.collateral_mint,
+            collateral_account,
+        )];
+        for (collateral_type, accounts) in synthetic_asset
+            .collateral_types
+            .iter()
+            .zip(ctx.remaining_accounts[2..].chunks(3))
+        {
+            let (vault, treasury, admin_account) = (&accounts[0], &accounts[1], &accounts[2]);
+            require_keys_eq!(
+                vault.key(),
+                collateral_type.vault,
+                Errors::InvalidCollateral
+            );
+            sweeps.push((vault.clone(), admin_account.clone()));
+            treasuries.push((treasury, collateral_type.mint, admin_account.clone()));
+        }
+        for (treasury, mint, admin_account) in treasuries {
+            require_keys_eq!(
+                treasury.key(),
+                treasury_address(&mint),
+                Errors::InvalidCollateral
+            );
+            if !treasury.data_is_empty() {
+                sweeps.push((treasury.clone(), admin_account));
+            }
+        }
+
+        // Nothing in the vaults is owed to margin accounts anymore, and the fees in the
+        // treasuries belong to the admin, who could have withdrawn them
+        let signer_seeds: &[&[&[u8]]] = &[&synthetic_asset.signer_seeds()];
+        for (account, destination) in sweeps {
+            ctx.accounts
+                .sweep_token_account(account, destination, signer_seeds)?;
+        }
+
+        // The synthetic treasury is empty, since no synthetic supply is left
+        let synthetic_treasury = &ctx.remaining_accounts[1];
+        require_keys_eq!(
+            synthetic_treasury.key(),
+            treasury_address(&synthetic_asset.synthetic_mint),
+            Errors::InvalidCollateral
+        );
+        if !synthetic_treasury.data_is_empty() {
+            close_account(
+                ctx.accounts
+                    .close_token_account_context(synthetic_treasury.clone())
+                    .with_signer(signer_seeds),
+            )?;
+        }
+
+        drop(synthetic_asset);
+        ctx.accounts
+            .synthetic_asset
+            .close(ctx.accounts.admin.to_account_info())?;
 
         Ok(())
     }
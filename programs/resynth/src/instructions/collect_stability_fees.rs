@@ -0,0 +1,76 @@
+use crate::{seeds, SyntheticAsset};
+use anchor_lang::prelude::*;
+use anchor_spl::token::{mint_to, Mint, MintTo, Token, TokenAccount};
+
+#[derive(Accounts)]
+pub struct CollectStabilityFees<'info> {
+    /// The synthetic asset account
+    #[account(mut,
+      has_one = synthetic_mint,
+      has_one = asset_authority,
+    )]
+    pub synthetic_asset: AccountLoader<'info, SyntheticAsset>,
+    /// The synthetic mint of the synthetic asset
+    #[account(mut)]
+    pub synthetic_mint: Box<Account<'info, Mint>>,
+    /// The mint authority that can mint synthetic assets and transfer vault collateral
+    /// CHECK:
+    pub asset_authority: AccountInfo<'info>,
+
+    /// The treasury receiving stability fees, in the synthetic asset
+    #[account(
+        init_if_needed,
+        seeds = [
+            seeds::TREASURY.as_ref(),
+            synthetic_asset.key().as_ref(),
+            synthetic_mint.key().as_ref(),
+        ],
+        bump,
+        payer = payer,
+        token::mint = synthetic_mint,
+        token::authority = asset_authority,
+    )]
+    pub treasury: Box<Account<'info, TokenAccount>>,
+
+    /// The payer of rent for the treasury
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// The token program for CPI calls
+    pub token_program: Program<'info, Token>,
+
+    /// The system program to create accounts
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> CollectStabilityFees<'info> {
+    /// CPI context to mint stability fees to the treasury
+    pub fn mint_fees_context(&self) -> CpiContext<'_, '_, '_, 'info, MintTo<'info>> {
+        return CpiContext::new(
+            self.token_program.to_account_info(),
+            MintTo {
+                mint: self.synthetic_mint.to_account_info(),
+                to: self.treasury.to_account_info(),
+                authority: self.asset_authority.to_account_info(),
+            },
+        );
+    }
+
+    pub fn process(ctx: Context<Self>) -> Result<()> {
+        let mut synthetic_asset = ctx.accounts.synthetic_asset.load_mut()?;
+
+        synthetic_asset.accrue_stability_fee(Clock::get()?.unix_timestamp)?;
+
+        let fees = synthetic_asset.accrued_fees;
+        synthetic_asset.accrued_fees = 0;
+
+        // Mint the accrued fees to the treasury
+        let signer_seeds: &[&[&[u8]]] = &[&synthetic_asset.signer_seeds()];
+        mint_to(
+            ctx.accounts.mint_fees_context().with_signer(signer_seeds),
+            fees,
+        )?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,35 @@
+use crate::{load_oracle_price, PriceRequirement, PriceSide, SyntheticAsset};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct ConfirmOraclePrice<'info> {
+    /// The synthetic asset whose oracle price is confirmed
+    #[account(mut,
+      has_one = admin,
+      has_one = synthetic_oracle,
+    )]
+    pub synthetic_asset: AccountLoader<'info, SyntheticAsset>,
+    /// The oracle price feed of the synthetic asset
+    /// CHECK:
+    pub synthetic_oracle: AccountInfo<'info>,
+
+    /// The admin of the synthetic asset
+    pub admin: Signer<'info>,
+}
+
+impl<'info> ConfirmOraclePrice<'info> {
+    pub fn process(ctx: Context<Self>) -> Result<()> {
+        let mut synthetic_asset = ctx.accounts.synthetic_asset.load_mut()?;
+
+        // The admin vouches for the current price, ending the cooldown early
+        let price = load_oracle_price(
+            &ctx.accounts.synthetic_oracle,
+            &synthetic_asset,
+            PriceSide::Debt,
+            PriceRequirement::Trading,
+        )?;
+        synthetic_asset.confirm_price(&price.price, Clock::get()?.slot);
+
+        Ok(())
+    }
+}
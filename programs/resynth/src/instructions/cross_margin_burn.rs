@@ -0,0 +1,152 @@
+use crate::{
+    load_oracle_price, seeds, AssetPrices, CollateralSlot, CrossMarginAccount, Errors,
+    PositionValuation, PriceRequirement, PriceSide, SyntheticAsset,
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token::{burn, transfer, Burn, Mint, Token, TokenAccount, Transfer};
+
+#[derive(Accounts)]
+pub struct CrossMarginBurn<'info> {
+    /// The synthetic asset account
+    #[account(mut,
+      has_one = collateral_mint,
+      has_one = synthetic_mint,
+      has_one = synthetic_oracle,
+      has_one = asset_authority,
+    )]
+    pub synthetic_asset: AccountLoader<'info, SyntheticAsset>,
+    /// The collateral mint shared by the synthetic asset and the cross-margin account
+    pub collateral_mint: Box<Account<'info, Mint>>,
+    /// The synthetic mint of the synthetic asset
+    #[account(mut)]
+    pub synthetic_mint: Box<Account<'info, Mint>>,
+    /// The oracle price feed, to value the burn fee
+    /// CHECK:
+    pub synthetic_oracle: AccountInfo<'info>,
+    /// The authority of the synthetic asset, which owns the treasury
+    /// CHECK:
+    pub asset_authority: AccountInfo<'info>,
+
+    /// The owner of the cross-margin account
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    /// The cross-margin account of the owner, to track collateral and debt
+    #[account(mut,
+      has_one = owner,
+      has_one = collateral_mint,
+      has_one = collateral_vault,
+    )]
+    pub cross_margin_account: AccountLoader<'info, CrossMarginAccount>,
+    /// The vault of the cross-margin account, which fees are paid from
+    #[account(mut)]
+    pub collateral_vault: Box<Account<'info, TokenAccount>>,
+    /// The treasury receiving fees, in the collateral of the synthetic asset
+    #[account(mut,
+        seeds = [
+            seeds::TREASURY.as_ref(),
+            synthetic_asset.key().as_ref(),
+            collateral_mint.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub treasury: Box<Account<'info, TokenAccount>>,
+    /// The owners account that synthetic tokens will be burned from
+    #[account(mut,
+        token::authority = owner,
+    )]
+    pub synthetic_account: Box<Account<'info, TokenAccount>>,
+
+    /// The token program for CPI calls
+    pub token_program: Program<'info, Token>,
+}
+
+impl<'info> CrossMarginBurn<'info> {
+    /// CPI context to burn synthetic tokens from the owners token account
+    pub fn burn_synthetic_context(&self) -> CpiContext<'_, '_, '_, 'info, Burn<'info>> {
+        return CpiContext::new(
+            self.token_program.to_account_info(),
+            Burn {
+                mint: self.synthetic_mint.to_account_info(),
+                from: self.synthetic_account.to_account_info(),
+                authority: self.owner.to_account_info(),
+            },
+        );
+    }
+
+    /// CPI context to transfer fees from the cross-margin vault to the treasury
+    pub fn fee_transfer_context(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        return CpiContext::new(
+            self.token_program.to_account_info(),
+            Transfer {
+                from: self.collateral_vault.to_account_info(),
+                to: self.treasury.to_account_info(),
+                authority: self.cross_margin_account.to_account_info(),
+            },
+        );
+    }
+
+    pub fn process(ctx: Context<Self>, burn_amount: u64) -> Result<()> {
+        let mut synthetic_asset = ctx.accounts.synthetic_asset.load_mut()?;
+        let mut cross_margin_account = ctx.accounts.cross_margin_account.load_mut()?;
+
+        // Debt is settled at the settlement price after a shutdown
+        require!(
+            !synthetic_asset.is_shutdown(),
+            Errors::SyntheticAssetShutdown
+        );
+
+        // Accrue the stability fee before the debt changes
+        synthetic_asset.accrue_stability_fee(Clock::get()?.unix_timestamp)?;
+
+        // Find the position being repaid
+        let index = cross_margin_account
+            .position_index(&ctx.accounts.synthetic_asset.key())
+            .ok_or(Errors::InvalidCrossMarginPosition)?;
+
+        // Unwrap the oracle price of the synthetic asset being burned
+        let synthetic_price = load_oracle_price(
+            &ctx.accounts.synthetic_oracle,
+            &synthetic_asset,
+            PriceSide::Debt,
+            PriceRequirement::LastTrading,
+        )?;
+
+        // Never burn more than the outstanding debt
+        let valuation = PositionValuation::new(&synthetic_asset, &synthetic_price);
+        let burn_amount = burn_amount.min(cross_margin_account.debt(index, &valuation)?);
+
+        // Charge the burn fee out of the cross-margin account collateral. Burning only
+        // repays debt, so paying the fee never needs a health check.
+        let prices = AssetPrices {
+            synthetic_price: synthetic_price.price,
+            ..Default::default()
+        };
+        let fee_amount = synthetic_asset.fee_collateral(
+            &prices,
+            CollateralSlot::Primary,
+            burn_amount,
+            synthetic_asset.params.burn_fee_bps,
+        )?;
+        cross_margin_account.charge_fee(fee_amount)?;
+
+        // Update the cross-margin account balances
+        let normalized_amount = synthetic_asset.repay(burn_amount)?;
+        cross_margin_account.repay(index, normalized_amount);
+        synthetic_asset.verify_minimum_debt(cross_margin_account.debt(index, &valuation)?)?;
+
+        // Transfer the fee from the cross-margin vault to the treasury
+        let signer_seeds: &[&[&[u8]]] = &[&cross_margin_account.signer_seeds()];
+        transfer(
+            ctx.accounts
+                .fee_transfer_context()
+                .with_signer(signer_seeds),
+            fee_amount,
+        )?;
+
+        // Burn the synthetic asset from the user token account
+        burn(ctx.accounts.burn_synthetic_context(), burn_amount)?;
+
+        Ok(())
+    }
+}
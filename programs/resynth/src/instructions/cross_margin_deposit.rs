@@ -0,0 +1,56 @@
+use crate::CrossMarginAccount;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{transfer, Token, TokenAccount, Transfer};
+
+#[derive(Accounts)]
+pub struct CrossMarginDeposit<'info> {
+    /// The depositor of collateral, who doesn't have to be the cross-margin account owner
+    pub depositor: Signer<'info>,
+
+    /// The cross-margin account being credited with collateral
+    #[account(mut,
+      has_one = collateral_vault,
+    )]
+    pub cross_margin_account: AccountLoader<'info, CrossMarginAccount>,
+    /// The vault of the cross-margin account
+    #[account(mut)]
+    pub collateral_vault: Box<Account<'info, TokenAccount>>,
+    /// The depositors account that collateral will be transferred from
+    #[account(mut,
+      token::authority = depositor
+    )]
+    pub depositor_collateral_account: Box<Account<'info, TokenAccount>>,
+
+    /// The token program for CPI calls
+    pub token_program: Program<'info, Token>,
+}
+
+impl<'info> CrossMarginDeposit<'info> {
+    /// CPI context to transfer collateral from the depositors account to the vault
+    pub fn collateral_transfer_context(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        return CpiContext::new(
+            self.token_program.to_account_info(),
+            Transfer {
+                from: self.depositor_collateral_account.to_account_info(),
+                to: self.collateral_vault.to_account_info(),
+                authority: self.depositor.to_account_info(),
+            },
+        );
+    }
+
+    pub fn process(ctx: Context<Self>, collateral_amount: u64) -> Result<()> {
+        // Depositing only improves health, so no oracle is needed
+        ctx.accounts
+            .cross_margin_account
+            .load_mut()?
+            .deposit_collateral(collateral_amount);
+
+        // Transfer collateral from the depositor to the vault
+        transfer(
+            ctx.accounts.collateral_transfer_context(),
+            collateral_amount,
+        )?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,179 @@
+use crate::{
+    load_oracle_price, load_position_valuations, seeds, AssetPrices, CollateralSlot,
+    CrossMarginAccount, Errors, PositionValuation, PriceRequirement, PriceSide, SyntheticAsset,
+};
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{mint_to, transfer, Mint, MintTo, Token, TokenAccount, Transfer},
+};
+
+#[derive(Accounts)]
+pub struct CrossMarginMint<'info> {
+    /// The synthetic asset account
+    #[account(mut,
+      has_one = collateral_mint,
+      has_one = synthetic_mint,
+      has_one = synthetic_oracle,
+      has_one = asset_authority,
+    )]
+    pub synthetic_asset: AccountLoader<'info, SyntheticAsset>,
+    /// The collateral mint shared by the synthetic asset and the cross-margin account
+    pub collateral_mint: Box<Account<'info, Mint>>,
+    /// The synthetic mint of the synthetic asset
+    #[account(mut)]
+    pub synthetic_mint: Box<Account<'info, Mint>>,
+    /// The oracle price feed, to determine cross-margin account health
+    /// CHECK:
+    pub synthetic_oracle: AccountInfo<'info>,
+    /// The mint authority that can mint synthetic assets
+    /// CHECK:
+    pub asset_authority: AccountInfo<'info>,
+
+    /// The receiver of the synthetic asset
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    /// The cross-margin account of the owner, to track collateral and debt
+    #[account(mut,
+      has_one = owner,
+      has_one = collateral_mint,
+      has_one = collateral_vault,
+    )]
+    pub cross_margin_account: AccountLoader<'info, CrossMarginAccount>,
+    /// The vault of the cross-margin account, which fees are paid from
+    #[account(mut)]
+    pub collateral_vault: Box<Account<'info, TokenAccount>>,
+    /// The treasury receiving fees, in the collateral of the synthetic asset
+    #[account(
+        init_if_needed,
+        seeds = [
+            seeds::TREASURY.as_ref(),
+            synthetic_asset.key().as_ref(),
+            collateral_mint.key().as_ref(),
+        ],
+        bump,
+        payer = owner,
+        token::mint = collateral_mint,
+        token::authority = asset_authority,
+    )]
+    pub treasury: Box<Account<'info, TokenAccount>>,
+    /// The owners account that will receive synthetic tokens
+    #[account(
+        init_if_needed,
+        payer = owner,
+        associated_token::mint = synthetic_mint,
+        associated_token::authority = owner,
+    )]
+    pub synthetic_account: Box<Account<'info, TokenAccount>>,
+
+    pub system_program: Program<'info, System>,
+
+    /// The token program for CPI calls
+    pub token_program: Program<'info, Token>,
+
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+impl<'info> CrossMarginMint<'info> {
+    /// CPI context to mint synthetic tokens to the owners token account
+    pub fn mint_synthetic_context(&self) -> CpiContext<'_, '_, '_, 'info, MintTo<'info>> {
+        return CpiContext::new(
+            self.token_program.to_account_info(),
+            MintTo {
+                mint: self.synthetic_mint.to_account_info(),
+                to: self.synthetic_account.to_account_info(),
+                authority: self.asset_authority.to_account_info(),
+            },
+        );
+    }
+
+    /// CPI context to transfer fees from the cross-margin vault to the treasury
+    pub fn fee_transfer_context(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        return CpiContext::new(
+            self.token_program.to_account_info(),
+            Transfer {
+                from: self.collateral_vault.to_account_info(),
+                to: self.treasury.to_account_info(),
+                authority: self.cross_margin_account.to_account_info(),
+            },
+        );
+    }
+
+    pub fn process(ctx: Context<Self>, mint_amount: u64) -> Result<()> {
+        let synthetic_asset_key = ctx.accounts.synthetic_asset.key();
+        let mut synthetic_asset = ctx.accounts.synthetic_asset.load_mut()?;
+        let mut cross_margin_account = ctx.accounts.cross_margin_account.load_mut()?;
+
+        // Pooled synthetic assets are backed by debt pool shares instead
+        require!(!synthetic_asset.is_pooled(), Errors::SyntheticAssetPooled);
+
+        // Accrue the stability fee before the debt changes
+        synthetic_asset.accrue_stability_fee(Clock::get()?.unix_timestamp)?;
+
+        // Unwrap the oracle price of the synthetic asset being minted
+        let synthetic_price = load_oracle_price(
+            &ctx.accounts.synthetic_oracle,
+            &synthetic_asset,
+            PriceSide::Debt,
+            PriceRequirement::Trading,
+        )?;
+
+        // Trip the circuit breaker on a bad print, and never mint against one
+        let current_slot = Clock::get()?.slot;
+        synthetic_asset.observe_price(&synthetic_price.price, current_slot);
+        synthetic_asset.verify_price_stable(current_slot)?;
+
+        // Update the cross-margin account balances
+        let previous_cross_margin_account = *cross_margin_account;
+        let normalized_amount = synthetic_asset.borrow(mint_amount)?;
+        let index = cross_margin_account.borrow(&synthetic_asset_key, normalized_amount)?;
+        let valuation = PositionValuation::new(&synthetic_asset, &synthetic_price);
+        synthetic_asset.verify_account_debt(cross_margin_account.debt(index, &valuation)?)?;
+
+        // Charge the mint fee out of the cross-margin account collateral
+        let prices = AssetPrices {
+            synthetic_price: synthetic_price.price,
+            ..Default::default()
+        };
+        let fee_amount = synthetic_asset.fee_collateral(
+            &prices,
+            CollateralSlot::Primary,
+            mint_amount,
+            synthetic_asset.params.mint_fee_bps,
+        )?;
+        cross_margin_account.charge_fee(fee_amount)?;
+
+        // Value every position. The synthetic assets and oracles of the
+        // other open positions are passed as remaining accounts
+        let valuations = load_position_valuations(
+            &cross_margin_account,
+            Some((synthetic_asset_key, valuation)),
+            ctx.remaining_accounts,
+            PriceRequirement::Trading,
+        )?;
+
+        // Verify minting does not make the cross-margin account unhealthy
+        cross_margin_account.require_healthy(&previous_cross_margin_account, &valuations)?;
+
+        // Mint the synthetic asset to the user token account
+        let signer_seeds: &[&[&[u8]]] = &[&synthetic_asset.signer_seeds()];
+        mint_to(
+            ctx.accounts
+                .mint_synthetic_context()
+                .with_signer(signer_seeds),
+            mint_amount,
+        )?;
+
+        // Transfer the fee from the cross-margin vault to the treasury
+        let signer_seeds: &[&[&[u8]]] = &[&cross_margin_account.signer_seeds()];
+        transfer(
+            ctx.accounts
+                .fee_transfer_context()
+                .with_signer(signer_seeds),
+            fee_amount,
+        )?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,70 @@
+use crate::{load_position_valuations, CrossMarginAccount, PriceRequirement};
+use anchor_lang::prelude::*;
+use anchor_spl::token::{transfer, Token, TokenAccount, Transfer};
+
+#[derive(Accounts)]
+pub struct CrossMarginWithdraw<'info> {
+    /// The owner of the cross-margin account
+    pub owner: Signer<'info>,
+
+    /// The cross-margin account of the owner, to track collateral and debt
+    #[account(mut,
+      has_one = owner,
+      has_one = collateral_vault,
+    )]
+    pub cross_margin_account: AccountLoader<'info, CrossMarginAccount>,
+    /// The vault of the cross-margin account
+    #[account(mut)]
+    pub collateral_vault: Box<Account<'info, TokenAccount>>,
+    /// The owners account that will receive collateral
+    #[account(mut)]
+    pub collateral_account: Box<Account<'info, TokenAccount>>,
+
+    /// The token program for CPI calls
+    pub token_program: Program<'info, Token>,
+}
+
+impl<'info> CrossMarginWithdraw<'info> {
+    /// CPI context to transfer collateral from the vault to the owners account
+    pub fn collateral_transfer_context(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        return CpiContext::new(
+            self.token_program.to_account_info(),
+            Transfer {
+                from: self.collateral_vault.to_account_info(),
+                to: self.collateral_account.to_account_info(),
+                authority: self.cross_margin_account.to_account_info(),
+            },
+        );
+    }
+
+    pub fn process(ctx: Context<Self>, collateral_amount: u64) -> Result<()> {
+        let mut cross_margin_account = ctx.accounts.cross_margin_account.load_mut()?;
+
+        // Value every position. Their synthetic assets and oracles are passed as remaining
+        // accounts, and no collateral is withdrawn while any of their prices is cooling down
+        let valuations = load_position_valuations(
+            &cross_margin_account,
+            None,
+            ctx.remaining_accounts,
+            PriceRequirement::Trading,
+        )?;
+
+        // Update the cross-margin account balances
+        let previous_cross_margin_account = *cross_margin_account;
+        cross_margin_account.withdraw_collateral(collateral_amount);
+
+        // Verify withdrawing does not make the cross-margin account unhealthy
+        cross_margin_account.require_healthy(&previous_cross_margin_account, &valuations)?;
+
+        // Transfer collateral from the vault to the owner
+        let signer_seeds: &[&[&[u8]]] = &[&cross_margin_account.signer_seeds()];
+        transfer(
+            ctx.accounts
+                .collateral_transfer_context()
+                .with_signer(signer_seeds),
+            collateral_amount,
+        )?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,64 @@
+use crate::{MarginAccount, SyntheticAsset};
+use anchor_lang::prelude::*;
+use anchor_spl::token::{transfer, Token, TokenAccount, Transfer};
+
+#[derive(Accounts)]
+pub struct DepositCollateral<'info> {
+    /// The synthetic asset account
+    #[account(mut)]
+    pub synthetic_asset: AccountLoader<'info, SyntheticAsset>,
+    /// The vault that is receiving collateral, of any collateral the synthetic asset accepts
+    #[account(mut)]
+    pub collateral_vault: Box<Account<'info, TokenAccount>>,
+
+    /// The depositor of collateral, who doesn't have to be the margin account owner
+    pub depositor: Signer<'info>,
+
+    /// The margin account being credited with collateral
+    #[account(mut,
+      has_one = synthetic_asset,
+    )]
+    pub margin_account: AccountLoader<'info, MarginAccount>,
+    /// The depositors account that collateral will be transferred from
+    #[account(mut,
+      token::authority = depositor
+    )]
+    pub depositor_collateral_account: Box<Account<'info, TokenAccount>>,
+
+    /// The token program for CPI calls
+    pub token_program: Program<'info, Token>,
+}
+
+impl<'info> DepositCollateral<'info> {
+    /// CPI context to transfer collateral from the depositors account to the vault
+    pub fn collateral_transfer_context(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        return CpiContext::new(
+            self.token_program.to_account_info(),
+            Transfer {
+                from: self.depositor_collateral_account.to_account_info(),
+                to: self.collateral_vault.to_account_info(),
+                authority: self.depositor.to_account_info(),
+            },
+        );
+    }
+
+    pub fn process(ctx: Context<Self>, collateral_amount: u64) -> Result<()> {
+        let mut synthetic_asset = ctx.accounts.synthetic_asset.load_mut()?;
+        let mut margin_account = ctx.accounts.margin_account.load_mut()?;
+
+        // Find which collateral is being deposited
+        let slot = synthetic_asset.collateral_slot(&ctx.accounts.collateral_vault.key())?;
+
+        // Depositing only improves health, so no oracle is needed
+        margin_account.deposit_collateral(slot, collateral_amount);
+        synthetic_asset.deposit_collateral(slot, collateral_amount);
+
+        // Transfer collateral from the depositor to the vault
+        transfer(
+            ctx.accounts.collateral_transfer_context(),
+            collateral_amount,
+        )?;
+
+        Ok(())
+    }
+}
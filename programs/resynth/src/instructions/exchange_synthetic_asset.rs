@@ -0,0 +1,144 @@
+use crate::{
+    load_oracle_price, token_amount, usd_value, Errors, PriceRequirement, PriceSide, SyntheticAsset,
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token::{burn, mint_to, Burn, Mint, MintTo, Token, TokenAccount};
+
+#[derive(Accounts)]
+pub struct ExchangeSyntheticAsset<'info> {
+    /// The synthetic asset being exchanged
+    #[account(mut,
+      constraint = source_synthetic_asset.load()?.synthetic_mint == source_mint.key(),
+      constraint = source_synthetic_asset.load()?.synthetic_oracle == source_oracle.key(),
+    )]
+    pub source_synthetic_asset: AccountLoader<'info, SyntheticAsset>,
+    /// The synthetic mint of the synthetic asset being exchanged
+    #[account(mut)]
+    pub source_mint: Box<Account<'info, Mint>>,
+    /// The oracle price feed of the synthetic asset being exchanged
+    /// CHECK:
+    pub source_oracle: AccountInfo<'info>,
+
+    /// The synthetic asset being received
+    #[account(mut,
+      constraint = target_synthetic_asset.load()?.synthetic_mint == target_mint.key(),
+      constraint = target_synthetic_asset.load()?.synthetic_oracle == target_oracle.key(),
+      constraint = target_synthetic_asset.load()?.asset_authority == target_asset_authority.key(),
+    )]
+    pub target_synthetic_asset: AccountLoader<'info, SyntheticAsset>,
+    /// The synthetic mint of the synthetic asset being received
+    #[account(mut)]
+    pub target_mint: Box<Account<'info, Mint>>,
+    /// The oracle price feed of the synthetic asset being received
+    /// CHECK:
+    pub target_oracle: AccountInfo<'info>,
+    /// The mint authority of the synthetic asset being received
+    /// CHECK:
+    pub target_asset_authority: AccountInfo<'info>,
+
+    /// The owner of the synthetic assets
+    pub owner: Signer<'info>,
+
+    /// The owners account that synthetic tokens will be burned from
+    #[account(mut,
+      token::mint = source_mint,
+      token::authority = owner,
+    )]
+    pub source_account: Box<Account<'info, TokenAccount>>,
+    /// The account that will receive the exchanged synthetic tokens
+    #[account(mut,
+      token::mint = target_mint,
+    )]
+    pub target_account: Box<Account<'info, TokenAccount>>,
+
+    /// The token program for CPI calls
+    pub token_program: Program<'info, Token>,
+}
+
+impl<'info> ExchangeSyntheticAsset<'info> {
+    /// CPI context to burn synthetic tokens from the owners token account
+    pub fn burn_source_context(&self) -> CpiContext<'_, '_, '_, 'info, Burn<'info>> {
+        return CpiContext::new(
+            self.token_program.to_account_info(),
+            Burn {
+                mint: self.source_mint.to_account_info(),
+                from: self.source_account.to_account_info(),
+                authority: self.owner.to_account_info(),
+            },
+        );
+    }
+
+    /// CPI context to mint synthetic tokens to the target token account
+    pub fn mint_target_context(&self) -> CpiContext<'_, '_, '_, 'info, MintTo<'info>> {
+        return CpiContext::new(
+            self.token_program.to_account_info(),
+            MintTo {
+                mint: self.target_mint.to_account_info(),
+                to: self.target_account.to_account_info(),
+                authority: self.target_asset_authority.to_account_info(),
+            },
+        );
+    }
+
+    pub fn process(ctx: Context<Self>, amount: u64) -> Result<()> {
+        let mut source_synthetic_asset = ctx.accounts.source_synthetic_asset.load_mut()?;
+        let mut target_synthetic_asset = ctx.accounts.target_synthetic_asset.load_mut()?;
+
+        // Exchanging only changes the composition of the pool debt, which every
+        // staker shares. Isolated synthetic assets are owed by specific margin accounts.
+        require!(
+            source_synthetic_asset.is_pooled()
+                && source_synthetic_asset.debt_pool == target_synthetic_asset.debt_pool,
+            Errors::InvalidDebtPool
+        );
+
+        let source_price = load_oracle_price(
+            &ctx.accounts.source_oracle,
+            &source_synthetic_asset,
+            PriceSide::Collateral,
+            PriceRequirement::Trading,
+        )?;
+        let target_price = load_oracle_price(
+            &ctx.accounts.target_oracle,
+            &target_synthetic_asset,
+            PriceSide::Debt,
+            PriceRequirement::Trading,
+        )?;
+
+        // Trip the circuit breakers on a bad print, and never exchange at one.
+        // A bad print of either side would move value between the exchanger and the pool.
+        let current_slot = Clock::get()?.slot;
+        source_synthetic_asset.observe_price(&source_price.price, current_slot);
+        target_synthetic_asset.observe_price(&target_price.price, current_slot);
+        source_synthetic_asset.verify_price_stable(current_slot)?;
+        target_synthetic_asset.verify_price_stable(current_slot)?;
+
+        // Exchange at oracle prices, rounding down in favor of the pool
+        let value = usd_value(
+            amount,
+            source_synthetic_asset.synthetic_decimals,
+            &source_price.price,
+        )?;
+        let target_amount = token_amount(
+            value,
+            target_synthetic_asset.synthetic_decimals,
+            &target_price.price,
+        )?;
+
+        // Move the debt from the exchanged to the received synthetic asset
+        source_synthetic_asset.retire_debt(amount)?;
+        target_synthetic_asset.issue_debt(target_amount)?;
+
+        // Burn the exchanged synthetic asset from the owner token account
+        burn(ctx.accounts.burn_source_context(), amount)?;
+
+        // Mint the received synthetic asset to the target token account
+        let signer_seeds: &[&[&[u8]]] = &[&target_synthetic_asset.signer_seeds()];
+        mint_to(
+            ctx.accounts.mint_target_context().with_signer(signer_seeds),
+            target_amount,
+        )?;
+
+        Ok(())
+    }
+}
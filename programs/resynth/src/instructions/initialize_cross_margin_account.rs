@@ -0,0 +1,67 @@
+use crate::{seeds, CrossMarginAccount, MAX_CROSS_MARGIN_POSITIONS};
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+#[derive(Accounts)]
+pub struct InitializeCrossMarginAccount<'info> {
+    /// The payer of cross-margin account rent
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// The owner of the cross-margin account, who can mint assets
+    /// CHECK:
+    pub owner: AccountInfo<'info>,
+
+    /// The collateral mint backing every position of the cross-margin account
+    pub collateral_mint: Box<Account<'info, Mint>>,
+
+    /// The cross-margin account to initialize
+    #[account(init,
+      seeds = [
+        seeds::CROSS_MARGIN_ACCOUNT.as_ref(),
+        collateral_mint.key().as_ref(),
+        owner.key().as_ref(),
+      ],
+      bump,
+      payer = payer,
+      space = 8 + std::mem::size_of::<CrossMarginAccount>(),
+    )]
+    pub cross_margin_account: AccountLoader<'info, CrossMarginAccount>,
+
+    /// The vault holding the collateral of the cross-margin account
+    #[account(init,
+        seeds = [
+            seeds::VAULT.as_ref(),
+            cross_margin_account.key().as_ref(),
+        ],
+        bump,
+        payer = payer,
+        token::mint = collateral_mint,
+        token::authority = cross_margin_account,
+    )]
+    pub collateral_vault: Box<Account<'info, TokenAccount>>,
+
+    /// The token program to initialize token accounts
+    pub token_program: Program<'info, Token>,
+
+    /// System program for CPI
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> InitializeCrossMarginAccount<'info> {
+    pub fn process(ctx: Context<Self>) -> Result<()> {
+        // Initialize all cross-margin account fields
+        *ctx.accounts.cross_margin_account.load_init()? = CrossMarginAccount {
+            owner: ctx.accounts.owner.key(),
+            collateral_mint: ctx.accounts.collateral_mint.key(),
+            collateral_vault: ctx.accounts.collateral_vault.key(),
+            collateral_deposited: 0,
+            positions: [Default::default(); MAX_CROSS_MARGIN_POSITIONS],
+            bump: [ctx.bumps["cross_margin_account"]],
+            collateral_decimals: ctx.accounts.collateral_mint.decimals,
+            padding: [0; 6],
+        };
+
+        Ok(())
+    }
+}
@@ -0,0 +1,48 @@
+use crate::{seeds, DebtPool, ProgramConfig, MAX_POOL_SYNTHETIC_ASSETS};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct InitializeDebtPool<'info> {
+    /// The program-wide debt pool to initialize
+    #[account(init,
+        seeds = [
+            seeds::DEBT_POOL.as_ref(),
+        ],
+        bump,
+        payer = payer,
+        space = 8 + std::mem::size_of::<DebtPool>(),
+    )]
+    pub debt_pool: AccountLoader<'info, DebtPool>,
+
+    /// The program-wide config, whose admin owns the singleton debt pool
+    #[account(
+      seeds = [
+        seeds::PROGRAM_CONFIG.as_ref(),
+      ],
+      bump,
+      has_one = admin,
+    )]
+    pub program_config: AccountLoader<'info, ProgramConfig>,
+
+    /// The admin that can add synthetic assets to the debt pool
+    pub admin: Signer<'info>,
+
+    /// The payer of rent for the debt pool
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// The system program to create accounts
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> InitializeDebtPool<'info> {
+    pub fn process(ctx: Context<Self>) -> Result<()> {
+        *ctx.accounts.debt_pool.load_init()? = DebtPool {
+            admin: ctx.accounts.admin.key(),
+            synthetic_assets: [Pubkey::default(); MAX_POOL_SYNTHETIC_ASSETS],
+            total_debt_shares: 0,
+        };
+
+        Ok(())
+    }
+}
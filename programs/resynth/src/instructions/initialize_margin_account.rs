@@ -1,4 +1,4 @@
-use crate::{seeds, MarginAccount, SyntheticAsset};
+use crate::{seeds, MarginAccount, SyntheticAsset, MAX_COLLATERAL_TYPES};
 use anchor_lang::prelude::*;
 
 #[derive(Accounts)]
@@ -12,6 +12,7 @@ pub struct InitializeMarginAccount<'info> {
     pub owner: AccountInfo<'info>,
 
     /// The synthetic asset the margin account is associated with
+    #[account(mut)]
     pub synthetic_asset: AccountLoader<'info, SyntheticAsset>,
 
     /// The margin account to initialize
@@ -38,9 +39,17 @@ impl<'info> InitializeMarginAccount<'info> {
             owner: ctx.accounts.owner.key(),
             synthetic_asset: ctx.accounts.synthetic_asset.key(),
             collateral_deposited: 0,
-            synthetic_asset_borrowed: 0,
+            normalized_debt: 0,
+            debt_shares: 0,
+            collateral_type_deposited: [0; MAX_COLLATERAL_TYPES],
         };
 
+        // The synthetic asset can't be closed while margin accounts refer to it
+        ctx.accounts
+            .synthetic_asset
+            .load_mut()?
+            .margin_account_count += 1;
+
         Ok(())
     }
 }
@@ -0,0 +1,51 @@
+use crate::{program::Resynth, seeds, Errors, ProgramConfig, MAX_ORACLE_PROGRAMS};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct InitializeProgramConfig<'info> {
+    /// The program-wide config to initialize
+    #[account(init,
+        seeds = [
+            seeds::PROGRAM_CONFIG.as_ref(),
+        ],
+        bump,
+        payer = payer,
+        space = 8 + std::mem::size_of::<ProgramConfig>(),
+    )]
+    pub program_config: AccountLoader<'info, ProgramConfig>,
+
+    /// The admin that can update the program config. The config is a singleton that
+    /// decides which oracles are trusted, so only the upgrade authority can claim it.
+    pub admin: Signer<'info>,
+
+    /// The resynth program being configured
+    #[account(
+      constraint = program.programdata_address()? == Some(program_data.key()) @ Errors::InvalidProgramAuthority,
+    )]
+    pub program: Program<'info, Resynth>,
+    /// The program data of the resynth program, naming its upgrade authority
+    #[account(
+      constraint = program_data.upgrade_authority_address == Some(admin.key()) @ Errors::InvalidProgramAuthority,
+    )]
+    pub program_data: Account<'info, ProgramData>,
+
+    /// The payer of rent for the program config
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// The system program to create accounts
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> InitializeProgramConfig<'info> {
+    pub fn process(ctx: Context<Self>, oracle_programs: Vec<Pubkey>) -> Result<()> {
+        let mut program_config = ctx.accounts.program_config.load_init()?;
+        *program_config = ProgramConfig {
+            admin: ctx.accounts.admin.key(),
+            oracle_programs: [Pubkey::default(); MAX_ORACLE_PROGRAMS],
+        };
+        program_config.set_oracle_programs(&oracle_programs)?;
+
+        Ok(())
+    }
+}
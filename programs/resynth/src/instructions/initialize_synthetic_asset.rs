@@ -1,15 +1,24 @@
-use crate::{errors::Errors, seeds, state::SyntheticAsset};
+use crate::{
+    errors::Errors,
+    oracle::validate_oracle,
+    seeds,
+    state::{
+        ProgramConfig, SyntheticAsset, SyntheticAssetParams, SyntheticAssetStatus,
+        BORROW_INDEX_ONE, MAX_SYMBOL_LENGTH,
+    },
+};
 use anchor_lang::prelude::*;
 use anchor_spl::token::{Mint, Token, TokenAccount};
-use pyth_sdk_solana::load_price_feed_from_account_info;
 
 #[derive(Accounts)]
+#[instruction(symbol: String, params: SyntheticAssetParams, synthetic_decimals: u8)]
 pub struct InitializeSyntheticAsset<'info> {
-    /// The synthetic asset account to initialize
+    /// The synthetic asset account to initialize, derived from its symbol so that
+    /// the oracle can be replaced without moving the synthetic asset
     #[account(init,
         seeds = [
             seeds::ASSET.as_ref(),
-            synthetic_oracle.key().as_ref(),
+            symbol.as_bytes(),
         ],
         bump,
         payer = payer,
@@ -33,7 +42,7 @@ pub struct InitializeSyntheticAsset<'info> {
     )]
     pub collateral_vault: Box<Account<'info, TokenAccount>>,
 
-    /// The synthetic asset mint
+    /// The synthetic asset mint, with the decimals chosen for the asset
     #[account(init,
         seeds = [
             seeds::MINT.as_ref(),
@@ -41,15 +50,28 @@ pub struct InitializeSyntheticAsset<'info> {
         ],
         bump,
         payer = payer,
-        mint::decimals = 9,
+        mint::decimals = synthetic_decimals,
         mint::authority = asset_authority,
     )]
     pub synthetic_mint: Box<Account<'info, Mint>>,
 
     /// The synthetic asset oracle price feed, to determine margin account health
-    /// CHECK:
+    /// CHECK: Validated as a price account of an allowed oracle program
     pub synthetic_oracle: AccountInfo<'info>,
 
+    /// The program-wide config listing the allowed oracle programs
+    #[account(
+      seeds = [
+        seeds::PROGRAM_CONFIG.as_ref(),
+      ],
+      bump,
+      constraint = program_config.load()?.admin == config_admin.key(),
+    )]
+    pub program_config: AccountLoader<'info, ProgramConfig>,
+    /// The admin of the program config. Symbols are first come first served,
+    /// so only the deployment can claim them.
+    pub config_admin: Signer<'info>,
+
     /// The mint authority that can mint synthetic assets and transfer vault collateral
     #[account(
       seeds = [
@@ -61,6 +83,10 @@ pub struct InitializeSyntheticAsset<'info> {
     /// CHECK:
     pub asset_authority: AccountInfo<'info>,
 
+    /// The admin that can update the synthetic asset parameters
+    /// CHECK:
+    pub admin: AccountInfo<'info>,
+
     /// The payer of rent for various accounts
     #[account(mut)]
     pub payer: Signer<'info>,
@@ -73,19 +99,26 @@ pub struct InitializeSyntheticAsset<'info> {
 }
 
 impl<'info> InitializeSyntheticAsset<'info> {
-    pub fn process(ctx: Context<Self>) -> Result<()> {
-        // Load the price feed to validate it's a feed
-        // This doesn't verify it's owned by the pyth program.
-        // Assume users trust the admin configured the synthetic asset correctly.
-        load_price_feed_from_account_info(&ctx.accounts.synthetic_oracle)
-            .map_err(|_| Errors::InvalidOracle)?;
-
-        // The USD price of one unit of collateral is hard coded to 1,
-        // so the conversion between one token and one lamport must be constant.
+    pub fn process(
+        ctx: Context<Self>,
+        symbol: String,
+        params: SyntheticAssetParams,
+        synthetic_decimals: u8,
+    ) -> Result<()> {
+        // Validate the price feed is owned by an oracle program allowed on this cluster
+        validate_oracle(
+            &ctx.accounts.synthetic_oracle,
+            &*ctx.accounts.program_config.load()?,
+        )?;
+
+        params.validate()?;
+
         require!(
-            ctx.accounts.collateral_mint.decimals == 6,
-            Errors::InvalidCollateralMintDecimals
+            !symbol.is_empty() && symbol.len() <= MAX_SYMBOL_LENGTH,
+            Errors::InvalidSymbol
         );
+        let mut symbol_bytes = [0; MAX_SYMBOL_LENGTH];
+        symbol_bytes[..symbol.len()].copy_from_slice(symbol.as_bytes());
 
         // Initialize all synthetic asset fields
         *ctx.accounts.synthetic_asset.load_init()? = SyntheticAsset {
@@ -94,8 +127,31 @@ impl<'info> InitializeSyntheticAsset<'info> {
             collateral_vault: ctx.accounts.collateral_vault.key(),
             synthetic_mint: ctx.accounts.synthetic_mint.key(),
             synthetic_oracle: ctx.accounts.synthetic_oracle.key(),
+            oracle_program: *ctx.accounts.synthetic_oracle.owner,
             asset_authority: ctx.accounts.asset_authority.key(),
+            admin: ctx.accounts.admin.key(),
+            pending_admin: Pubkey::default(),
+            debt_pool: Pubkey::default(),
+            symbol: symbol_bytes,
+            params,
+            collateral_types: Default::default(),
+            borrow_index: BORROW_INDEX_ONE,
+            last_accrual_time: Clock::get()?.unix_timestamp,
+            total_normalized_debt: 0,
+            accrued_fees: 0,
+            total_debt: 0,
+            total_collateral: 0,
+            margin_account_count: 0,
+            settlement_collateral: 0,
+            settlement_price: 0,
+            last_price: 0,
+            last_price_slot: 0,
+            cooldown_end_slot: 0,
             asset_authority_bump: [ctx.bumps["asset_authority"]],
+            collateral_decimals: ctx.accounts.collateral_mint.decimals,
+            synthetic_decimals,
+            status: SyntheticAssetStatus::Active as u8,
+            settlement_expo: 0,
         };
 
         Ok(())
@@ -0,0 +1,208 @@
+use crate::{
+    load_pool_asset_prices, seeds, usd_value, DebtPool, MarginAccount, PriceRequirement,
+    SyntheticAsset,
+};
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{mint_to, transfer, Mint, MintTo, Token, TokenAccount, Transfer},
+};
+
+#[derive(Accounts)]
+pub struct IssuePoolDebt<'info> {
+    /// The synthetic asset account
+    #[account(mut,
+      has_one = debt_pool,
+      has_one = synthetic_mint,
+      has_one = synthetic_oracle,
+      has_one = asset_authority,
+    )]
+    pub synthetic_asset: AccountLoader<'info, SyntheticAsset>,
+    /// The debt pool shared by the synthetic asset
+    #[account(mut)]
+    pub debt_pool: AccountLoader<'info, DebtPool>,
+    /// The vault that is receiving collateral, of any collateral the synthetic asset accepts
+    #[account(mut,
+      token::mint = collateral_mint,
+    )]
+    pub collateral_vault: Box<Account<'info, TokenAccount>>,
+    /// The mint of the collateral being deposited
+    pub collateral_mint: Box<Account<'info, Mint>>,
+    /// The synthetic mint of the synthetic asset
+    #[account(mut)]
+    pub synthetic_mint: Box<Account<'info, Mint>>,
+    /// The oracle price feed, to determine margin account health
+    /// CHECK:
+    pub synthetic_oracle: AccountInfo<'info>,
+    /// The mint authority that can mint synthetic assets and transfer vault collateral
+    /// CHECK:
+    pub asset_authority: AccountInfo<'info>,
+
+    /// The receiver of the synthetic asset
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    /// The margin account of the owner, to track collateral and debt
+    #[account(mut,
+      has_one = owner,
+      has_one = synthetic_asset,
+    )]
+    pub margin_account: AccountLoader<'info, MarginAccount>,
+    /// The treasury receiving fees, in the collateral of the vault
+    #[account(
+        init_if_needed,
+        seeds = [
+            seeds::TREASURY.as_ref(),
+            synthetic_asset.key().as_ref(),
+            collateral_mint.key().as_ref(),
+        ],
+        bump,
+        payer = owner,
+        token::mint = collateral_mint,
+        token::authority = asset_authority,
+    )]
+    pub treasury: Box<Account<'info, TokenAccount>>,
+    /// The owners account that collateral will be transferred from
+    #[account(mut,
+      token::authority = owner
+    )]
+    pub collateral_account: Box<Account<'info, TokenAccount>>,
+    /// The owners account that will receive synthetic tokens
+    #[account(
+        init_if_needed,
+        payer = owner,
+        associated_token::mint = synthetic_mint,
+        associated_token::authority = owner,
+    )]
+    pub synthetic_account: Box<Account<'info, TokenAccount>>,
+
+    pub system_program: Program<'info, System>,
+
+    /// The token program for CPI calls
+    pub token_program: Program<'info, Token>,
+
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+impl<'info> IssuePoolDebt<'info> {
+    /// CPI context to transfer collateral from the owners account to the vault
+    pub fn collateral_transfer_context(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        return CpiContext::new(
+            self.token_program.to_account_info(),
+            Transfer {
+                from: self.collateral_account.to_account_info(),
+                to: self.collateral_vault.to_account_info(),
+                authority: self.owner.to_account_info(),
+            },
+        );
+    }
+
+    /// CPI context to mint synthetic tokens to the owners token account
+    pub fn mint_synthetic_context(&self) -> CpiContext<'_, '_, '_, 'info, MintTo<'info>> {
+        return CpiContext::new(
+            self.token_program.to_account_info(),
+            MintTo {
+                mint: self.synthetic_mint.to_account_info(),
+                to: self.synthetic_account.to_account_info(),
+                authority: self.asset_authority.to_account_info(),
+            },
+        );
+    }
+
+    /// CPI context to transfer fees from the vault to the treasury
+    pub fn fee_transfer_context(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        return CpiContext::new(
+            self.token_program.to_account_info(),
+            Transfer {
+                from: self.collateral_vault.to_account_info(),
+                to: self.treasury.to_account_info(),
+                authority: self.asset_authority.to_account_info(),
+            },
+        );
+    }
+
+    pub fn process(ctx: Context<Self>, collateral_amount: u64, mint_amount: u64) -> Result<()> {
+        let mut synthetic_asset = ctx.accounts.synthetic_asset.load_mut()?;
+        let mut debt_pool = ctx.accounts.debt_pool.load_mut()?;
+        let mut margin_account = ctx.accounts.margin_account.load_mut()?;
+
+        // Find which collateral is being deposited
+        let slot = synthetic_asset.collateral_slot(&ctx.accounts.collateral_vault.key())?;
+
+        // Unwrap the oracle prices and the pool debt. Registered collateral oracles
+        // and the accounts of every pooled synthetic asset are passed as remaining accounts
+        let mut prices = load_pool_asset_prices(
+            &ctx.accounts.synthetic_oracle,
+            ctx.remaining_accounts,
+            &synthetic_asset,
+            &debt_pool,
+            PriceRequirement::Trading,
+        )?;
+
+        // Trip the circuit breaker on a bad print, and never mint against one
+        let current_slot = Clock::get()?.slot;
+        synthetic_asset.observe_price(&prices.synthetic_price, current_slot);
+        synthetic_asset.verify_price_stable(current_slot)?;
+
+        // Update the margin account balances
+        let previous_margin_account = *margin_account;
+        margin_account.deposit_collateral(slot, collateral_amount);
+        synthetic_asset.deposit_collateral(slot, collateral_amount);
+
+        // Issue debt shares for the value minted
+        let mint_value = usd_value(
+            mint_amount,
+            synthetic_asset.synthetic_decimals,
+            &prices.synthetic_price,
+        )?;
+        let debt_shares = prices.pool_debt.issue(mint_value)?;
+        margin_account.issue_debt_shares(debt_shares);
+        debt_pool.total_debt_shares = prices.pool_debt.total_debt_shares;
+        synthetic_asset.issue_debt(mint_amount)?;
+        synthetic_asset
+            .verify_account_debt(margin_account.pool_debt(&synthetic_asset, &prices)?)?;
+
+        // Charge the mint fee out of the margin account collateral
+        let fee_amount = synthetic_asset.fee_collateral(
+            &prices,
+            slot,
+            mint_amount,
+            synthetic_asset.params.mint_fee_bps,
+        )?;
+        margin_account.charge_fee(slot, fee_amount)?;
+        synthetic_asset.withdraw_collateral(slot, fee_amount);
+
+        // Verify minting does not make the margin account unhealthy
+        margin_account.require_healthy(
+            &previous_margin_account,
+            &synthetic_asset,
+            &prices,
+            synthetic_asset.params.initial_collateral_ratio_bps,
+        )?;
+
+        // Transfer collateral from the user to the vault
+        transfer(
+            ctx.accounts.collateral_transfer_context(),
+            collateral_amount,
+        )?;
+
+        // Mint the synthetic asset to the user token account
+        let signer_seeds: &[&[&[u8]]] = &[&synthetic_asset.signer_seeds()];
+        mint_to(
+            ctx.accounts
+                .mint_synthetic_context()
+                .with_signer(signer_seeds),
+            mint_amount,
+        )?;
+
+        // Transfer the fee from the vault to the treasury
+        transfer(
+            ctx.accounts
+                .fee_transfer_context()
+                .with_signer(signer_seeds),
+            fee_amount,
+        )?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,168 @@
+use crate::{
+    load_oracle_price, load_position_valuations, CrossMarginAccount, Errors, HealthCheck,
+    PositionValuation, PriceRequirement, PriceSide, SyntheticAsset,
+};
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{burn, transfer, Burn, Mint, Token, TokenAccount, Transfer},
+};
+
+#[derive(Accounts)]
+pub struct LiquidateCrossMarginAccount<'info> {
+    /// The synthetic asset of the position being repaid
+    #[account(mut,
+      has_one = collateral_mint,
+      has_one = synthetic_mint,
+      has_one = synthetic_oracle,
+    )]
+    pub synthetic_asset: AccountLoader<'info, SyntheticAsset>,
+    /// The collateral mint shared by the synthetic asset and the cross-margin account
+    pub collateral_mint: Box<Account<'info, Mint>>,
+    /// The synthetic mint of the synthetic asset
+    #[account(mut)]
+    pub synthetic_mint: Box<Account<'info, Mint>>,
+    /// The oracle price feed, to determine cross-margin account health
+    /// CHECK:
+    pub synthetic_oracle: AccountInfo<'info>,
+
+    /// The liquidator repaying debt in exchange for collateral
+    #[account(mut)]
+    pub liquidator: Signer<'info>,
+
+    /// The unhealthy cross-margin account being liquidated
+    #[account(mut,
+      has_one = collateral_mint,
+      has_one = collateral_vault,
+    )]
+    pub cross_margin_account: AccountLoader<'info, CrossMarginAccount>,
+    /// The vault of the cross-margin account that collateral is seized from
+    #[account(mut)]
+    pub collateral_vault: Box<Account<'info, TokenAccount>>,
+    /// The liquidators account that will receive seized collateral
+    #[account(
+      init_if_needed,
+      payer = liquidator,
+      associated_token::mint = collateral_mint,
+      associated_token::authority = liquidator,
+    )]
+    pub liquidator_collateral_account: Box<Account<'info, TokenAccount>>,
+    /// The liquidators account that synthetic tokens will be burned from
+    #[account(mut,
+        token::authority = liquidator,
+    )]
+    pub liquidator_synthetic_account: Box<Account<'info, TokenAccount>>,
+
+    pub system_program: Program<'info, System>,
+
+    /// The token program for CPI calls
+    pub token_program: Program<'info, Token>,
+
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+impl<'info> LiquidateCrossMarginAccount<'info> {
+    /// CPI context to transfer seized collateral from the cross-margin vault to the liquidator
+    pub fn collateral_transfer_context(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        return CpiContext::new(
+            self.token_program.to_account_info(),
+            Transfer {
+                from: self.collateral_vault.to_account_info(),
+                to: self.liquidator_collateral_account.to_account_info(),
+                authority: self.cross_margin_account.to_account_info(),
+            },
+        );
+    }
+
+    /// CPI context to burn synthetic tokens from the liquidators token account
+    pub fn burn_synthetic_context(&self) -> CpiContext<'_, '_, '_, 'info, Burn<'info>> {
+        return CpiContext::new(
+            self.token_program.to_account_info(),
+            Burn {
+                mint: self.synthetic_mint.to_account_info(),
+                from: self.liquidator_synthetic_account.to_account_info(),
+                authority: self.liquidator.to_account_info(),
+            },
+        );
+    }
+
+    pub fn process(ctx: Context<Self>, repay_amount: u64) -> Result<()> {
+        let synthetic_asset_key = ctx.accounts.synthetic_asset.key();
+        let mut synthetic_asset = ctx.accounts.synthetic_asset.load_mut()?;
+        let mut cross_margin_account = ctx.accounts.cross_margin_account.load_mut()?;
+
+        // Debt is settled at the settlement price after a shutdown
+        require!(
+            !synthetic_asset.is_shutdown(),
+            Errors::SyntheticAssetShutdown
+        );
+
+        // Accrue the stability fee before the debt changes
+        synthetic_asset.accrue_stability_fee(Clock::get()?.unix_timestamp)?;
+
+        // Find the position being repaid
+        let index = cross_margin_account
+            .position_index(&synthetic_asset_key)
+            .ok_or(Errors::InvalidCrossMarginPosition)?;
+
+        // Value every position. The synthetic assets and oracles of the
+        // other open positions are passed as remaining accounts
+        let synthetic_price = load_oracle_price(
+            &ctx.accounts.synthetic_oracle,
+            &synthetic_asset,
+            PriceSide::Debt,
+            PriceRequirement::LastTrading,
+        )?;
+
+        // Liquidations keep working through a cooldown, but still trip the circuit breaker
+        synthetic_asset.observe_price(&synthetic_price.price, Clock::get()?.slot);
+
+        let valuation = PositionValuation::new(&synthetic_asset, &synthetic_price);
+        let valuations = load_position_valuations(
+            &cross_margin_account,
+            Some((synthetic_asset_key, valuation)),
+            ctx.remaining_accounts,
+            PriceRequirement::LastTrading,
+        )?;
+
+        // Only unhealthy cross-margin accounts can be liquidated
+        require!(
+            !cross_margin_account.verify_healthy(&valuations, HealthCheck::Maintenance)?,
+            Errors::MarginAccountHealthy
+        );
+
+        // Limit the debt repaid in one liquidation to the close factor
+        let repay_amount = repay_amount.min(cross_margin_account.max_liquidation_amount(
+            index,
+            &synthetic_asset,
+            &valuation,
+        )?);
+
+        // The liquidator receives the value of the debt repaid, plus a bonus,
+        // limited to the collateral left in the cross-margin account
+        let (repay_amount, collateral_amount) = cross_margin_account.liquidation_collateral(
+            &synthetic_asset,
+            &valuation,
+            repay_amount,
+        )?;
+
+        // Update the cross-margin account balances
+        let normalized_amount = synthetic_asset.repay(repay_amount)?;
+        cross_margin_account.repay(index, normalized_amount);
+        cross_margin_account.withdraw_collateral(collateral_amount);
+
+        // Transfer seized collateral from the cross-margin vault to the liquidator
+        let signer_seeds: &[&[&[u8]]] = &[&cross_margin_account.signer_seeds()];
+        transfer(
+            ctx.accounts
+                .collateral_transfer_context()
+                .with_signer(signer_seeds),
+            collateral_amount,
+        )?;
+
+        // Burn the repaid synthetic asset from the liquidator token account
+        burn(ctx.accounts.burn_synthetic_context(), repay_amount)?;
+
+        Ok(())
+    }
+}
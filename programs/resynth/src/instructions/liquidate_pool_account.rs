@@ -0,0 +1,179 @@
+use crate::{
+    load_pool_asset_prices, usd_value, DebtPool, Errors, MarginAccount, PriceRequirement,
+    SyntheticAsset,
+};
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{burn, transfer, Burn, Mint, Token, TokenAccount, Transfer},
+};
+
+#[derive(Accounts)]
+pub struct LiquidatePoolAccount<'info> {
+    /// The synthetic asset account
+    #[account(mut,
+      has_one = debt_pool,
+      has_one = synthetic_mint,
+      has_one = synthetic_oracle,
+      has_one = asset_authority,
+    )]
+    pub synthetic_asset: AccountLoader<'info, SyntheticAsset>,
+    /// The debt pool shared by the synthetic asset
+    #[account(mut)]
+    pub debt_pool: AccountLoader<'info, DebtPool>,
+    /// The vault that collateral is seized from, of any collateral the synthetic asset accepts
+    #[account(mut,
+      token::mint = collateral_mint,
+    )]
+    pub collateral_vault: Box<Account<'info, TokenAccount>>,
+    /// The mint of the collateral being seized
+    pub collateral_mint: Box<Account<'info, Mint>>,
+    /// The synthetic mint of the synthetic asset
+    #[account(mut)]
+    pub synthetic_mint: Box<Account<'info, Mint>>,
+    /// The oracle price feed, to determine margin account health
+    /// CHECK:
+    pub synthetic_oracle: AccountInfo<'info>,
+    /// The mint authority that can mint synthetic assets and transfer vault collateral
+    /// CHECK:
+    pub asset_authority: AccountInfo<'info>,
+
+    /// The liquidator repaying debt in exchange for collateral
+    #[account(mut)]
+    pub liquidator: Signer<'info>,
+
+    /// The unhealthy margin account being liquidated, which owes debt pool shares
+    #[account(mut,
+      has_one = synthetic_asset,
+    )]
+    pub margin_account: AccountLoader<'info, MarginAccount>,
+    /// The liquidators account that will receive seized collateral
+    #[account(
+      init_if_needed,
+      payer = liquidator,
+      associated_token::mint = collateral_mint,
+      associated_token::authority = liquidator,
+    )]
+    pub liquidator_collateral_account: Box<Account<'info, TokenAccount>>,
+    /// The liquidators account that synthetic tokens will be burned from
+    #[account(mut,
+        token::authority = liquidator,
+    )]
+    pub liquidator_synthetic_account: Box<Account<'info, TokenAccount>>,
+
+    pub system_program: Program<'info, System>,
+
+    /// The token program for CPI calls
+    pub token_program: Program<'info, Token>,
+
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+impl<'info> LiquidatePoolAccount<'info> {
+    /// CPI context to transfer seized collateral from the vault to the liquidator
+    pub fn collateral_transfer_context(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        return CpiContext::new(
+            self.token_program.to_account_info(),
+            Transfer {
+                from: self.collateral_vault.to_account_info(),
+                to: self.liquidator_collateral_account.to_account_info(),
+                authority: self.asset_authority.to_account_info(),
+            },
+        );
+    }
+
+    /// CPI context to burn synthetic tokens from the liquidators token account
+    pub fn burn_synthetic_context(&self) -> CpiContext<'_, '_, '_, 'info, Burn<'info>> {
+        return CpiContext::new(
+            self.token_program.to_account_info(),
+            Burn {
+                mint: self.synthetic_mint.to_account_info(),
+                from: self.liquidator_synthetic_account.to_account_info(),
+                authority: self.liquidator.to_account_info(),
+            },
+        );
+    }
+
+    pub fn process(ctx: Context<Self>, repay_amount: u64) -> Result<()> {
+        let mut synthetic_asset = ctx.accounts.synthetic_asset.load_mut()?;
+        let mut debt_pool = ctx.accounts.debt_pool.load_mut()?;
+        let mut margin_account = ctx.accounts.margin_account.load_mut()?;
+
+        // Find which collateral is being seized
+        let slot = synthetic_asset.collateral_slot(&ctx.accounts.collateral_vault.key())?;
+
+        // Unwrap the oracle prices and the pool debt. Registered collateral oracles
+        // and the accounts of every pooled synthetic asset are passed as remaining accounts
+        let mut prices = load_pool_asset_prices(
+            &ctx.accounts.synthetic_oracle,
+            ctx.remaining_accounts,
+            &synthetic_asset,
+            &debt_pool,
+            PriceRequirement::LastTrading,
+        )?;
+
+        // Liquidations keep working through a cooldown, but still trip the circuit breaker
+        synthetic_asset.observe_price(&prices.synthetic_price, Clock::get()?.slot);
+
+        // Only unhealthy margin accounts can be liquidated
+        require!(
+            !margin_account.verify_healthy(
+                &synthetic_asset,
+                &prices,
+                synthetic_asset.params.maintenance_collateral_ratio_bps
+            )?,
+            Errors::MarginAccountHealthy
+        );
+
+        // Limit the debt repaid in one liquidation to the close factor
+        let repay_amount = repay_amount
+            .min(margin_account.max_pool_liquidation_amount(&synthetic_asset, &prices)?);
+        let (repay_amount, repaid_shares) =
+            margin_account.pool_repayment(&synthetic_asset, &prices, repay_amount)?;
+
+        // The liquidator receives the value of the debt repaid, plus a bonus
+        // limited to the collateral left in the margin account
+        let (covered_amount, collateral_amount) =
+            margin_account.liquidation_collateral(&synthetic_asset, &prices, slot, repay_amount)?;
+        let (repay_amount, repaid_shares) = if covered_amount < repay_amount {
+            margin_account.pool_repayment(&synthetic_asset, &prices, covered_amount)?
+        } else {
+            (repay_amount, repaid_shares)
+        };
+
+        // Update the margin account balances
+        margin_account.withdraw_collateral(slot, collateral_amount);
+        synthetic_asset.withdraw_collateral(slot, collateral_amount);
+
+        // Repay the debt shares for the value burned
+        let repay_value = usd_value(
+            repay_amount,
+            synthetic_asset.synthetic_decimals,
+            &prices.synthetic_price,
+        )?;
+        prices.pool_debt.repay(repay_value, repaid_shares);
+        margin_account.repay_debt_shares(repaid_shares);
+        debt_pool.total_debt_shares = prices.pool_debt.total_debt_shares;
+        synthetic_asset.retire_debt(repay_amount)?;
+
+        // Partial liquidations can't leave dust debt behind, unless the collateral ran out
+        if margin_account.collateral_balance(slot) > 0 {
+            synthetic_asset
+                .verify_minimum_debt(margin_account.pool_debt(&synthetic_asset, &prices)?)?;
+        }
+
+        // Transfer seized collateral from the vault to the liquidator
+        let signer_seeds: &[&[&[u8]]] = &[&synthetic_asset.signer_seeds()];
+        transfer(
+            ctx.accounts
+                .collateral_transfer_context()
+                .with_signer(signer_seeds),
+            collateral_amount,
+        )?;
+
+        // Burn the repaid synthetic asset from the liquidator token account
+        burn(ctx.accounts.burn_synthetic_context(), repay_amount)?;
+
+        Ok(())
+    }
+}
@@ -1,24 +1,26 @@
-use crate::{Errors, MarginAccount, SyntheticAsset};
+use crate::{load_asset_prices, seeds, Errors, MarginAccount, PriceRequirement, SyntheticAsset};
 use anchor_lang::prelude::*;
 use anchor_spl::{
     associated_token::AssociatedToken,
     token::{mint_to, transfer, Mint, MintTo, Token, TokenAccount, Transfer},
 };
-use pyth_sdk_solana::load_price_feed_from_account_info;
 
 #[derive(Accounts)]
 pub struct MintSyntheticAsset<'info> {
     /// The synthetic asset account
-    #[account(
-      has_one = collateral_vault,
+    #[account(mut,
       has_one = synthetic_mint,
       has_one = synthetic_oracle,
       has_one = asset_authority,
     )]
     pub synthetic_asset: AccountLoader<'info, SyntheticAsset>,
-    /// The vault that is receiving collateral
-    #[account(mut)]
+    /// The vault that is receiving collateral, of any collateral the synthetic asset accepts
+    #[account(mut,
+      token::mint = collateral_mint,
+    )]
     pub collateral_vault: Box<Account<'info, TokenAccount>>,
+    /// The mint of the collateral being deposited
+    pub collateral_mint: Box<Account<'info, Mint>>,
     /// The synthetic mint of the synthetic asset
     #[account(mut)]
     pub synthetic_mint: Box<Account<'info, Mint>>,
@@ -39,6 +41,20 @@ pub struct MintSyntheticAsset<'info> {
       has_one = synthetic_asset,
     )]
     pub margin_account: AccountLoader<'info, MarginAccount>,
+    /// The treasury receiving fees, in the collateral of the vault
+    #[account(
+        init_if_needed,
+        seeds = [
+            seeds::TREASURY.as_ref(),
+            synthetic_asset.key().as_ref(),
+            collateral_mint.key().as_ref(),
+        ],
+        bump,
+        payer = owner,
+        token::mint = collateral_mint,
+        token::authority = asset_authority,
+    )]
+    pub treasury: Box<Account<'info, TokenAccount>>,
     /// The owners account that collateral will be transferred from
     #[account(mut,
       token::authority = owner
@@ -86,20 +102,81 @@ impl<'info> MintSyntheticAsset<'info> {
         );
     }
 
+    /// CPI context to transfer fees from the vault to the treasury
+    pub fn fee_transfer_context(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        return CpiContext::new(
+            self.token_program.to_account_info(),
+            Transfer {
+                from: self.collateral_vault.to_account_info(),
+                to: self.treasury.to_account_info(),
+                authority: self.asset_authority.to_account_info(),
+            },
+        );
+    }
+
     pub fn process(ctx: Context<Self>, collateral_amount: u64, mint_amount: u64) -> Result<()> {
-        let synthetic_asset = ctx.accounts.synthetic_asset.load()?;
+        let mut synthetic_asset = ctx.accounts.synthetic_asset.load_mut()?;
         let mut margin_account = ctx.accounts.margin_account.load_mut()?;
 
-        // Unwrap the oracle price
-        let oracle_price = load_price_feed_from_account_info(&ctx.accounts.synthetic_oracle)
-            .map_err(|_| Errors::InvalidOracle)?
-            .get_price_unchecked();
+        // Margin accounts of pooled synthetic assets owe debt pool shares instead
+        require!(!synthetic_asset.is_pooled(), Errors::SyntheticAssetPooled);
+
+        // Accrue the stability fee before the debt changes
+        synthetic_asset.accrue_stability_fee(Clock::get()?.unix_timestamp)?;
+
+        // Find which collateral is being deposited
+        let slot = synthetic_asset.collateral_slot(&ctx.accounts.collateral_vault.key())?;
+
+        // Unwrap the oracle prices. Registered collateral oracles are passed as remaining accounts.
+        // Depositing collateral without minting keeps working while the feeds are halted
+        let requirement = if mint_amount > 0 {
+            PriceRequirement::Trading
+        } else {
+            PriceRequirement::LastTrading
+        };
+        let prices = load_asset_prices(
+            &ctx.accounts.synthetic_oracle,
+            ctx.remaining_accounts,
+            &synthetic_asset,
+            requirement,
+        )?;
+
+        // Trip the circuit breaker on a bad print, and never mint against one
+        let current_slot = Clock::get()?.slot;
+        synthetic_asset.observe_price(&prices.synthetic_price, current_slot);
+        if mint_amount > 0 {
+            synthetic_asset.verify_price_stable(current_slot)?;
+        }
 
         // Update the margin account balances
-        margin_account.mint_synthetic_asset(collateral_amount, mint_amount);
+        let previous_margin_account = *margin_account;
+        let normalized_amount = synthetic_asset.borrow(mint_amount)?;
+        margin_account.mint_synthetic_asset(slot, collateral_amount, normalized_amount);
+        synthetic_asset.deposit_collateral(slot, collateral_amount);
+
+        // Collateral only deposits don't change the debt, so they are allowed on
+        // margin accounts left below the minimum debt or above the cap by a params change
+        if mint_amount > 0 {
+            synthetic_asset.verify_account_debt(margin_account.debt(&synthetic_asset)?)?;
+        }
+
+        // Charge the mint fee out of the margin account collateral
+        let fee_amount = synthetic_asset.fee_collateral(
+            &prices,
+            slot,
+            mint_amount,
+            synthetic_asset.params.mint_fee_bps,
+        )?;
+        margin_account.charge_fee(slot, fee_amount)?;
+        synthetic_asset.withdraw_collateral(slot, fee_amount);
 
         // Verify minting does not make the margin account unhealthy
-        margin_account.verify_healthy(oracle_price)?;
+        margin_account.require_healthy(
+            &previous_margin_account,
+            &synthetic_asset,
+            &prices,
+            synthetic_asset.params.initial_collateral_ratio_bps,
+        )?;
 
         // Transfer collateral from the user to the vault
         transfer(
@@ -116,6 +193,14 @@ impl<'info> MintSyntheticAsset<'info> {
             mint_amount,
         )?;
 
+        // Transfer the fee from the vault to the treasury
+        transfer(
+            ctx.accounts
+                .fee_transfer_context()
+                .with_signer(signer_seeds),
+            fee_amount,
+        )?;
+
         Ok(())
     }
 }
@@ -0,0 +1,238 @@
+use crate::{
+    load_asset_prices, seeds, CollateralSlot, Errors, MarginAccount, PriceRequirement,
+    SyntheticAsset, BASIS_POINTS_DENOMINATOR,
+};
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{burn, transfer, Burn, Mint, Token, TokenAccount, Transfer},
+};
+
+#[derive(Accounts)]
+pub struct Redeem<'info> {
+    /// The synthetic asset account
+    #[account(mut,
+      has_one = collateral_vault,
+      has_one = collateral_mint,
+      has_one = synthetic_mint,
+      has_one = synthetic_oracle,
+      has_one = asset_authority,
+    )]
+    pub synthetic_asset: AccountLoader<'info, SyntheticAsset>,
+    /// The vault of the primary collateral, which is paid out
+    #[account(mut)]
+    pub collateral_vault: Box<Account<'info, TokenAccount>>,
+    /// The primary collateral mint
+    pub collateral_mint: Box<Account<'info, Mint>>,
+    /// The synthetic mint of the synthetic asset
+    #[account(mut)]
+    pub synthetic_mint: Box<Account<'info, Mint>>,
+    /// The oracle price feed, to value the synthetic asset redeemed
+    /// CHECK:
+    pub synthetic_oracle: AccountInfo<'info>,
+    /// The authority that can transfer vault collateral
+    /// CHECK:
+    pub asset_authority: AccountInfo<'info>,
+
+    /// The holder redeeming synthetic tokens
+    #[account(mut)]
+    pub redeemer: Signer<'info>,
+
+    /// The treasury receiving redemption fees, in the primary collateral
+    #[account(
+        init_if_needed,
+        seeds = [
+            seeds::TREASURY.as_ref(),
+            synthetic_asset.key().as_ref(),
+            collateral_mint.key().as_ref(),
+        ],
+        bump,
+        payer = redeemer,
+        token::mint = collateral_mint,
+        token::authority = asset_authority,
+    )]
+    pub treasury: Box<Account<'info, TokenAccount>>,
+    /// The redeemers account that will receive collateral
+    #[account(
+      init_if_needed,
+      payer = redeemer,
+      associated_token::mint = collateral_mint,
+      associated_token::authority = redeemer,
+    )]
+    pub redeemer_collateral_account: Box<Account<'info, TokenAccount>>,
+    /// The redeemers account that synthetic tokens will be burned from
+    #[account(mut,
+        token::authority = redeemer,
+    )]
+    pub redeemer_synthetic_account: Box<Account<'info, TokenAccount>>,
+
+    pub system_program: Program<'info, System>,
+
+    /// The token program for CPI calls
+    pub token_program: Program<'info, Token>,
+
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+impl<'info> Redeem<'info> {
+    /// CPI context to transfer redeemed collateral from the vault to the redeemer
+    pub fn collateral_transfer_context(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        return CpiContext::new(
+            self.token_program.to_account_info(),
+            Transfer {
+                from: self.collateral_vault.to_account_info(),
+                to: self.redeemer_collateral_account.to_account_info(),
+                authority: self.asset_authority.to_account_info(),
+            },
+        );
+    }
+
+    /// CPI context to transfer fees from the vault to the treasury
+    pub fn fee_transfer_context(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        return CpiContext::new(
+            self.token_program.to_account_info(),
+            Transfer {
+                from: self.collateral_vault.to_account_info(),
+                to: self.treasury.to_account_info(),
+                authority: self.asset_authority.to_account_info(),
+            },
+        );
+    }
+
+    /// CPI context to burn synthetic tokens from the redeemers token account
+    pub fn burn_synthetic_context(&self) -> CpiContext<'_, '_, '_, 'info, Burn<'info>> {
+        return CpiContext::new(
+            self.token_program.to_account_info(),
+            Burn {
+                mint: self.synthetic_mint.to_account_info(),
+                from: self.redeemer_synthetic_account.to_account_info(),
+                authority: self.redeemer.to_account_info(),
+            },
+        );
+    }
+
+    pub fn process(ctx: Context<Self>, amount: u64) -> Result<()> {
+        let synthetic_asset_key = ctx.accounts.synthetic_asset.key();
+        let mut synthetic_asset = ctx.accounts.synthetic_asset.load_mut()?;
+
+        // Margin accounts of pooled synthetic assets owe debt pool shares instead
+        require!(!synthetic_asset.is_pooled(), Errors::SyntheticAssetPooled);
+
+        // Debt is settled at the settlement price after a shutdown
+        require!(
+            !synthetic_asset.is_shutdown(),
+            Errors::SyntheticAssetShutdown
+        );
+
+        // Accrue the stability fee before the debt changes
+        synthetic_asset.accrue_stability_fee(Clock::get()?.unix_timestamp)?;
+
+        // The remaining accounts are the registered collateral oracles in registry order,
+        // followed by the margin accounts to redeem from, lowest collateral ratio first.
+        // The redeemer chooses which margin accounts are passed, so the ordering is only
+        // enforced among them, not against every margin account of the synthetic asset
+        let collateral_type_count = synthetic_asset.collateral_type_count();
+        require!(
+            ctx.remaining_accounts.len() >= collateral_type_count,
+            Errors::InvalidOracle
+        );
+        let (collateral_oracles, margin_accounts) =
+            ctx.remaining_accounts.split_at(collateral_type_count);
+
+        // Unwrap the oracle prices
+        let prices = load_asset_prices(
+            &ctx.accounts.synthetic_oracle,
+            collateral_oracles,
+            &synthetic_asset,
+            PriceRequirement::Trading,
+        )?;
+
+        let mut redeemed_amount = 0;
+        let mut collateral_amount = 0;
+        let mut previous_collateral_ratio = 0;
+        let mut redeemed_margin_accounts = Vec::with_capacity(margin_accounts.len());
+        for margin_account_info in margin_accounts {
+            if redeemed_amount == amount {
+                break;
+            }
+
+            // A margin account passed twice would be redeemed from twice
+            require!(
+                !redeemed_margin_accounts.contains(margin_account_info.key),
+                Errors::DuplicateMarginAccount
+            );
+            redeemed_margin_accounts.push(*margin_account_info.key);
+
+            let margin_account_loader =
+                AccountLoader::<MarginAccount>::try_from(margin_account_info)?;
+            let mut margin_account = margin_account_loader.load_mut()?;
+            require_keys_eq!(
+                margin_account.synthetic_asset,
+                synthetic_asset_key,
+                Errors::InvalidMarginAccount
+            );
+
+            // Unhealthy margin accounts are liquidated instead
+            if !margin_account.has_debt()
+                || !margin_account.verify_healthy(
+                    &synthetic_asset,
+                    &prices,
+                    synthetic_asset.params.maintenance_collateral_ratio_bps,
+                )?
+            {
+                continue;
+            }
+
+            // Redeem from the riskiest margin accounts first
+            let collateral_ratio =
+                margin_account.collateral_ratio_bps(&synthetic_asset, &prices)?;
+            require!(
+                collateral_ratio >= previous_collateral_ratio,
+                Errors::InvalidRedemptionOrder
+            );
+            previous_collateral_ratio = collateral_ratio;
+
+            // Repay the debt with the redeemed synthetic asset, in exchange for collateral.
+            // Only the primary collateral is paid out, so a margin account is redeemed from
+            // up to the value of its primary collateral, leaving other collateral types untouched
+            let (repay_amount, repay_collateral) =
+                margin_account.redemption(&synthetic_asset, &prices, amount - redeemed_amount)?;
+            let normalized_amount = synthetic_asset.repay(repay_amount)?;
+            margin_account.repay(normalized_amount);
+            margin_account.withdraw_collateral(CollateralSlot::Primary, repay_collateral);
+            synthetic_asset.withdraw_collateral(CollateralSlot::Primary, repay_collateral);
+
+            redeemed_amount += repay_amount;
+            collateral_amount += repay_collateral;
+        }
+
+        // Charge the redemption fee out of the collateral redeemed
+        let fee_amount = u64::try_from(
+            u128::from(collateral_amount) * u128::from(synthetic_asset.params.redemption_fee_bps)
+                / u128::from(BASIS_POINTS_DENOMINATOR),
+        )
+        .map_err(|_| Errors::MathOverflow)?;
+
+        // Transfer the redeemed collateral from the vault to the redeemer
+        let signer_seeds: &[&[&[u8]]] = &[&synthetic_asset.signer_seeds()];
+        transfer(
+            ctx.accounts
+                .collateral_transfer_context()
+                .with_signer(signer_seeds),
+            collateral_amount - fee_amount,
+        )?;
+
+        // Transfer the fee from the vault to the treasury
+        transfer(
+            ctx.accounts
+                .fee_transfer_context()
+                .with_signer(signer_seeds),
+            fee_amount,
+        )?;
+
+        // Burn the redeemed synthetic asset from the redeemer token account
+        burn(ctx.accounts.burn_synthetic_context(), redeemed_amount)?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,136 @@
+use crate::{CollateralSlot, Errors, SyntheticAsset};
+use anchor_lang::prelude::*;
+use anchor_spl::token::{burn, transfer, Burn, Mint, Token, TokenAccount, Transfer};
+
+#[derive(Accounts)]
+pub struct RedeemSettlement<'info> {
+    /// The shut down synthetic asset
+    #[account(mut,
+      has_one = collateral_vault,
+      has_one = synthetic_mint,
+      has_one = asset_authority,
+    )]
+    pub synthetic_asset: AccountLoader<'info, SyntheticAsset>,
+    /// The vault of the primary collateral
+    #[account(mut)]
+    pub collateral_vault: Box<Account<'info, TokenAccount>>,
+    /// The synthetic mint of the synthetic asset
+    #[account(mut)]
+    pub synthetic_mint: Box<Account<'info, Mint>>,
+    /// The authority that can transfer vault collateral
+    /// CHECK:
+    pub asset_authority: AccountInfo<'info>,
+
+    /// The holder redeeming synthetic tokens
+    pub holder: Signer<'info>,
+
+    /// The holders account that synthetic tokens will be burned from
+    #[account(mut,
+        token::authority = holder,
+    )]
+    pub synthetic_account: Box<Account<'info, TokenAccount>>,
+    /// The holders account that will receive primary collateral
+    #[account(mut,
+      token::mint = collateral_vault.mint,
+    )]
+    pub collateral_account: Box<Account<'info, TokenAccount>>,
+
+    /// The token program for CPI calls
+    pub token_program: Program<'info, Token>,
+}
+
+impl<'info> RedeemSettlement<'info> {
+    /// CPI context to transfer collateral from a vault to an account of the holder
+    pub fn collateral_transfer_context(
+        &self,
+        vault: AccountInfo<'info>,
+        collateral_account: AccountInfo<'info>,
+    ) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        return CpiContext::new(
+            self.token_program.to_account_info(),
+            Transfer {
+                from: vault,
+                to: collateral_account,
+                authority: self.asset_authority.to_account_info(),
+            },
+        );
+    }
+
+    /// CPI context to burn synthetic tokens from the holders token account
+    pub fn burn_synthetic_context(&self) -> CpiContext<'_, '_, '_, 'info, Burn<'info>> {
+        return CpiContext::new(
+            self.token_program.to_account_info(),
+            Burn {
+                mint: self.synthetic_mint.to_account_info(),
+                from: self.synthetic_account.to_account_info(),
+                authority: self.holder.to_account_info(),
+            },
+        );
+    }
+
+    pub fn process(ctx: Context<'_, '_, '_, 'info, Self>, amount: u64) -> Result<()> {
+        let mut synthetic_asset = ctx.accounts.synthetic_asset.load_mut()?;
+
+        require!(
+            synthetic_asset.is_shutdown(),
+            Errors::SyntheticAssetNotShutdown
+        );
+
+        // Holders share the settlement collateral pro rata, so it must be final
+        require!(
+            synthetic_asset.total_normalized_debt == 0 && synthetic_asset.accrued_fees == 0,
+            Errors::SettlementPending
+        );
+
+        // The vaults of registered collateral with settlement collateral, and the holders accounts
+        // that will receive it, are passed as remaining account pairs in registry order
+        let synthetic_supply = ctx.accounts.synthetic_mint.supply;
+        let mut collateral_accounts = ctx.remaining_accounts.chunks(2);
+        let mut transfers = Vec::new();
+        for slot in synthetic_asset.collateral_slots().collect::<Vec<_>>() {
+            if synthetic_asset.settlement_collateral(slot) == 0 {
+                continue;
+            }
+
+            let (vault, collateral_account) = match slot {
+                CollateralSlot::Primary => (
+                    ctx.accounts.collateral_vault.to_account_info(),
+                    ctx.accounts.collateral_account.to_account_info(),
+                ),
+                CollateralSlot::Registered(index) => {
+                    let accounts = collateral_accounts
+                        .next()
+                        .filter(|accounts| accounts.len() == 2)
+                        .ok_or(Errors::InvalidCollateral)?;
+                    require_keys_eq!(
+                        accounts[0].key(),
+                        synthetic_asset.collateral_types[index].vault,
+                        Errors::InvalidCollateral
+                    );
+                    (accounts[0].clone(), accounts[1].clone())
+                }
+            };
+
+            let share = synthetic_asset.settlement_share(slot, amount, synthetic_supply)?;
+            synthetic_asset.withdraw_settlement_collateral(slot, share);
+            transfers.push((vault, collateral_account, share));
+        }
+        synthetic_asset.retire_debt(amount)?;
+
+        // Transfer the share of the settlement collateral from the vaults to the holder
+        let signer_seeds: &[&[&[u8]]] = &[&synthetic_asset.signer_seeds()];
+        for (vault, collateral_account, share) in transfers {
+            transfer(
+                ctx.accounts
+                    .collateral_transfer_context(vault, collateral_account)
+                    .with_signer(signer_seeds),
+                share,
+            )?;
+        }
+
+        // Burn the redeemed synthetic asset from the holder token account
+        burn(ctx.accounts.burn_synthetic_context(), amount)?;
+
+        Ok(())
+    }
+}
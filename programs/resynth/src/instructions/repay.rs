@@ -0,0 +1,73 @@
+use crate::{Errors, MarginAccount, SyntheticAsset};
+use anchor_lang::prelude::*;
+use anchor_spl::token::{burn, Burn, Mint, Token, TokenAccount};
+
+#[derive(Accounts)]
+pub struct Repay<'info> {
+    /// The synthetic asset account
+    #[account(mut,
+      has_one = synthetic_mint,
+    )]
+    pub synthetic_asset: AccountLoader<'info, SyntheticAsset>,
+    /// The synthetic mint of the synthetic asset
+    #[account(mut)]
+    pub synthetic_mint: Box<Account<'info, Mint>>,
+
+    /// The repayer of debt, who doesn't have to be the margin account owner
+    pub repayer: Signer<'info>,
+
+    /// The margin account whose debt is repaid
+    #[account(mut,
+      has_one = synthetic_asset,
+    )]
+    pub margin_account: AccountLoader<'info, MarginAccount>,
+    /// The repayers account that synthetic tokens will be burned from
+    #[account(mut,
+        token::authority = repayer,
+    )]
+    pub repayer_synthetic_account: Box<Account<'info, TokenAccount>>,
+
+    /// The token program for CPI calls
+    pub token_program: Program<'info, Token>,
+}
+
+impl<'info> Repay<'info> {
+    /// CPI context to burn synthetic tokens from the repayers token account
+    pub fn burn_synthetic_context(&self) -> CpiContext<'_, '_, '_, 'info, Burn<'info>> {
+        return CpiContext::new(
+            self.token_program.to_account_info(),
+            Burn {
+                mint: self.synthetic_mint.to_account_info(),
+                from: self.repayer_synthetic_account.to_account_info(),
+                authority: self.repayer.to_account_info(),
+            },
+        );
+    }
+
+    pub fn process(ctx: Context<Self>, repay_amount: u64) -> Result<()> {
+        let mut synthetic_asset = ctx.accounts.synthetic_asset.load_mut()?;
+        let mut margin_account = ctx.accounts.margin_account.load_mut()?;
+
+        // Debt is settled at the settlement price after a shutdown
+        require!(
+            !synthetic_asset.is_shutdown(),
+            Errors::SyntheticAssetShutdown
+        );
+
+        // Accrue the stability fee before the debt changes
+        synthetic_asset.accrue_stability_fee(Clock::get()?.unix_timestamp)?;
+
+        // Never burn more than the outstanding debt
+        let repay_amount = repay_amount.min(margin_account.debt(&synthetic_asset)?);
+
+        // Repaying only improves health, so no oracle is needed
+        let normalized_amount = synthetic_asset.repay(repay_amount)?;
+        margin_account.repay(normalized_amount);
+        synthetic_asset.verify_minimum_debt(margin_account.debt(&synthetic_asset)?)?;
+
+        // Burn the repaid synthetic asset from the repayer token account
+        burn(ctx.accounts.burn_synthetic_context(), repay_amount)?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,40 @@
+use crate::{seeds, validate_oracle, ProgramConfig, SyntheticAsset};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct SetOracle<'info> {
+    /// The synthetic asset whose oracle is replaced
+    #[account(mut,
+      has_one = admin,
+    )]
+    pub synthetic_asset: AccountLoader<'info, SyntheticAsset>,
+    /// The new oracle price feed of the synthetic asset
+    /// CHECK: Validated as a price account of an allowed oracle program
+    pub synthetic_oracle: AccountInfo<'info>,
+    /// The program-wide config listing the allowed oracle programs
+    #[account(
+      seeds = [
+        seeds::PROGRAM_CONFIG.as_ref(),
+      ],
+      bump,
+    )]
+    pub program_config: AccountLoader<'info, ProgramConfig>,
+
+    /// The admin of the synthetic asset
+    pub admin: Signer<'info>,
+}
+
+impl<'info> SetOracle<'info> {
+    pub fn process(ctx: Context<Self>) -> Result<()> {
+        // Feeds migrate to new accounts, and a feed of another program can't be trusted
+        let synthetic_oracle = &ctx.accounts.synthetic_oracle;
+        validate_oracle(synthetic_oracle, &*ctx.accounts.program_config.load()?)?;
+
+        ctx.accounts
+            .synthetic_asset
+            .load_mut()?
+            .set_oracle(synthetic_oracle.key(), *synthetic_oracle.owner);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,25 @@
+use crate::ProgramConfig;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct SetOraclePrograms<'info> {
+    /// The program-wide config to update
+    #[account(mut,
+      has_one = admin,
+    )]
+    pub program_config: AccountLoader<'info, ProgramConfig>,
+
+    /// The admin of the program config
+    pub admin: Signer<'info>,
+}
+
+impl<'info> SetOraclePrograms<'info> {
+    pub fn process(ctx: Context<Self>, oracle_programs: Vec<Pubkey>) -> Result<()> {
+        // Synthetic assets keep the oracle program they were configured with,
+        // so only new and replaced oracles are held to the new list
+        ctx.accounts
+            .program_config
+            .load_mut()?
+            .set_oracle_programs(&oracle_programs)
+    }
+}
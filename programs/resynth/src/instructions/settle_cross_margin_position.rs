@@ -0,0 +1,88 @@
+use crate::{
+    CollateralSlot, CrossMarginAccount, Errors, OraclePrice, PositionValuation, SyntheticAsset,
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token::{transfer, Token, TokenAccount, Transfer};
+
+#[derive(Accounts)]
+pub struct SettleCrossMarginPosition<'info> {
+    /// The shut down synthetic asset
+    #[account(mut,
+      has_one = collateral_vault,
+    )]
+    pub synthetic_asset: AccountLoader<'info, SyntheticAsset>,
+    /// The vault of the synthetic asset, which receives the settlement collateral
+    #[account(mut)]
+    pub collateral_vault: Box<Account<'info, TokenAccount>>,
+
+    /// The cross-margin account whose position is settled, by anyone
+    #[account(mut,
+      constraint = cross_margin_account.load()?.collateral_mint
+        == synthetic_asset.load()?.collateral_mint @ Errors::InvalidCollateral,
+      constraint = cross_margin_account.load()?.collateral_vault
+        == cross_margin_vault.key() @ Errors::InvalidCollateral,
+    )]
+    pub cross_margin_account: AccountLoader<'info, CrossMarginAccount>,
+    /// The vault of the cross-margin account
+    #[account(mut)]
+    pub cross_margin_vault: Box<Account<'info, TokenAccount>>,
+
+    /// The token program for CPI calls
+    pub token_program: Program<'info, Token>,
+}
+
+impl<'info> SettleCrossMarginPosition<'info> {
+    /// CPI context to transfer collateral from the cross-margin vault to the synthetic asset vault
+    pub fn collateral_transfer_context(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        return CpiContext::new(
+            self.token_program.to_account_info(),
+            Transfer {
+                from: self.cross_margin_vault.to_account_info(),
+                to: self.collateral_vault.to_account_info(),
+                authority: self.cross_margin_account.to_account_info(),
+            },
+        );
+    }
+
+    pub fn process(ctx: Context<Self>) -> Result<()> {
+        let synthetic_asset_key = ctx.accounts.synthetic_asset.key();
+        let mut synthetic_asset = ctx.accounts.synthetic_asset.load_mut()?;
+        let mut cross_margin_account = ctx.accounts.cross_margin_account.load_mut()?;
+
+        require!(
+            synthetic_asset.is_shutdown(),
+            Errors::SyntheticAssetNotShutdown
+        );
+
+        // Find the position being settled
+        let index = cross_margin_account
+            .position_index(&synthetic_asset_key)
+            .ok_or(Errors::InvalidCrossMarginPosition)?;
+
+        // Set aside collateral worth the debt for synthetic holders
+        let settlement_price = OraclePrice {
+            price: synthetic_asset.settlement_price(),
+            health_price: synthetic_asset.settlement_price(),
+        };
+        let valuation = PositionValuation::new(&synthetic_asset, &settlement_price);
+        let collateral_amount = cross_margin_account.settlement_collateral(index, &valuation)?;
+        cross_margin_account.withdraw_collateral(collateral_amount);
+        synthetic_asset.deposit_settlement_collateral(CollateralSlot::Primary, collateral_amount);
+
+        // The debt is settled in full, any shortfall is shared by all synthetic holders
+        let normalized_debt = cross_margin_account.positions[index].normalized_debt;
+        cross_margin_account.repay(index, normalized_debt);
+        synthetic_asset.settle_debt(normalized_debt);
+
+        // Move the settlement collateral into the synthetic asset vault
+        let signer_seeds: &[&[&[u8]]] = &[&cross_margin_account.signer_seeds()];
+        transfer(
+            ctx.accounts
+                .collateral_transfer_context()
+                .with_signer(signer_seeds),
+            collateral_amount,
+        )?;
+
+        Ok(())
+    }
+}
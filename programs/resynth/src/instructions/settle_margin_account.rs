@@ -0,0 +1,61 @@
+use crate::{
+    load_collateral_prices, AssetPrices, Errors, MarginAccount, PriceRequirement, SyntheticAsset,
+};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct SettleMarginAccount<'info> {
+    /// The shut down synthetic asset
+    #[account(mut)]
+    pub synthetic_asset: AccountLoader<'info, SyntheticAsset>,
+
+    /// The margin account whose debt is settled, by anyone
+    #[account(mut,
+      has_one = synthetic_asset,
+    )]
+    pub margin_account: AccountLoader<'info, MarginAccount>,
+}
+
+impl<'info> SettleMarginAccount<'info> {
+    pub fn process(ctx: Context<Self>) -> Result<()> {
+        let mut synthetic_asset = ctx.accounts.synthetic_asset.load_mut()?;
+        let mut margin_account = ctx.accounts.margin_account.load_mut()?;
+
+        require!(
+            synthetic_asset.is_shutdown(),
+            Errors::SyntheticAssetNotShutdown
+        );
+
+        // Registered collateral is only valued when the primary collateral doesn't cover
+        // the debt. Its oracles are then passed as remaining accounts, in registry order.
+        let collateral_prices = if ctx.remaining_accounts.is_empty() {
+            Default::default()
+        } else {
+            load_collateral_prices(
+                ctx.remaining_accounts,
+                &synthetic_asset,
+                PriceRequirement::Trading,
+            )?
+            .map(|collateral_price| collateral_price.price)
+        };
+        let prices = AssetPrices {
+            synthetic_price: synthetic_asset.settlement_price(),
+            collateral_prices,
+            ..Default::default()
+        };
+
+        // Set aside collateral worth the debt for synthetic holders. It stays in the vaults.
+        for (slot, amount) in margin_account.settlement_collateral(&synthetic_asset, &prices)? {
+            margin_account.withdraw_collateral(slot, amount);
+            synthetic_asset.withdraw_collateral(slot, amount);
+            synthetic_asset.deposit_settlement_collateral(slot, amount);
+        }
+
+        // The debt is settled in full, any shortfall is shared by all synthetic holders
+        let normalized_debt = margin_account.normalized_debt;
+        margin_account.repay(normalized_debt);
+        synthetic_asset.settle_debt(normalized_debt);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,39 @@
+use crate::{Errors, SyntheticAsset, SyntheticAssetStatus};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct ShutdownSyntheticAsset<'info> {
+    /// The synthetic asset to shut down
+    #[account(mut,
+      has_one = admin,
+    )]
+    pub synthetic_asset: AccountLoader<'info, SyntheticAsset>,
+
+    /// The admin of the synthetic asset
+    pub admin: Signer<'info>,
+}
+
+impl<'info> ShutdownSyntheticAsset<'info> {
+    pub fn process(ctx: Context<Self>, settlement_price: i64, settlement_expo: i32) -> Result<()> {
+        require!(settlement_price > 0, Errors::InvalidSettlementPrice);
+
+        let mut synthetic_asset = ctx.accounts.synthetic_asset.load_mut()?;
+        require!(
+            !synthetic_asset.is_shutdown(),
+            Errors::SyntheticAssetShutdown
+        );
+
+        // The pool debt is shared by every pooled synthetic asset, so it can't be settled alone
+        require!(!synthetic_asset.is_pooled(), Errors::SyntheticAssetPooled);
+
+        // Accrue the stability fee one last time, debt stops growing after the shutdown
+        synthetic_asset.accrue_stability_fee(Clock::get()?.unix_timestamp)?;
+
+        // The settlement price is provided by the admin, as the oracle may be what failed
+        synthetic_asset.status = SyntheticAssetStatus::Shutdown as u8;
+        synthetic_asset.settlement_price = settlement_price;
+        synthetic_asset.settlement_expo = settlement_expo;
+
+        Ok(())
+    }
+}
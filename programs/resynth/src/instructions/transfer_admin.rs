@@ -0,0 +1,24 @@
+use crate::SyntheticAsset;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct TransferAdmin<'info> {
+    /// The synthetic asset to transfer
+    #[account(mut,
+      has_one = admin,
+    )]
+    pub synthetic_asset: AccountLoader<'info, SyntheticAsset>,
+
+    /// The current admin of the synthetic asset
+    pub admin: Signer<'info>,
+}
+
+impl<'info> TransferAdmin<'info> {
+    pub fn process(ctx: Context<Self>, new_admin: Pubkey) -> Result<()> {
+        // The new admin must accept before the transfer completes,
+        // so a typo can't lock the synthetic asset forever
+        ctx.accounts.synthetic_asset.load_mut()?.pending_admin = new_admin;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,32 @@
+use crate::{load_oracle_price, PriceRequirement, PriceSide, SyntheticAsset};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct UpdateCircuitBreaker<'info> {
+    /// The synthetic asset whose circuit breaker is updated
+    #[account(mut,
+      has_one = synthetic_oracle,
+    )]
+    pub synthetic_asset: AccountLoader<'info, SyntheticAsset>,
+    /// The oracle price feed of the synthetic asset
+    /// CHECK:
+    pub synthetic_oracle: AccountInfo<'info>,
+}
+
+impl<'info> UpdateCircuitBreaker<'info> {
+    pub fn process(ctx: Context<Self>) -> Result<()> {
+        let mut synthetic_asset = ctx.accounts.synthetic_asset.load_mut()?;
+
+        // A failed mint reverts the trip along with everything else, so anyone
+        // can record a bad print here and start the cooldown
+        let price = load_oracle_price(
+            &ctx.accounts.synthetic_oracle,
+            &synthetic_asset,
+            PriceSide::Debt,
+            PriceRequirement::Trading,
+        )?;
+        synthetic_asset.observe_price(&price.price, Clock::get()?.slot);
+
+        Ok(())
+    }
+}
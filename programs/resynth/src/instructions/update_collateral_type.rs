@@ -0,0 +1,36 @@
+use crate::{validate_loan_to_value, CollateralSlot, Errors, SyntheticAsset};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct UpdateCollateralType<'info> {
+    /// The synthetic asset accepting the collateral
+    #[account(mut,
+      has_one = admin,
+    )]
+    pub synthetic_asset: AccountLoader<'info, SyntheticAsset>,
+
+    /// The vault of the collateral to update
+    /// CHECK: Must be the vault of a registered collateral type
+    pub collateral_vault: AccountInfo<'info>,
+
+    /// The admin of the synthetic asset
+    pub admin: Signer<'info>,
+}
+
+impl<'info> UpdateCollateralType<'info> {
+    pub fn process(ctx: Context<Self>, loan_to_value_bps: u64) -> Result<()> {
+        validate_loan_to_value(loan_to_value_bps)?;
+
+        let mut synthetic_asset = ctx.accounts.synthetic_asset.load_mut()?;
+
+        // The primary collateral is always counted at its full value
+        let index = match synthetic_asset.collateral_slot(&ctx.accounts.collateral_vault.key())? {
+            CollateralSlot::Registered(index) => index,
+            CollateralSlot::Primary => return err!(Errors::InvalidCollateral),
+        };
+
+        synthetic_asset.collateral_types[index].loan_to_value_bps = loan_to_value_bps;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,29 @@
+use crate::{SyntheticAsset, SyntheticAssetParams};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct UpdateSyntheticAsset<'info> {
+    /// The synthetic asset to update
+    #[account(mut,
+      has_one = admin,
+    )]
+    pub synthetic_asset: AccountLoader<'info, SyntheticAsset>,
+
+    /// The admin of the synthetic asset
+    pub admin: Signer<'info>,
+}
+
+impl<'info> UpdateSyntheticAsset<'info> {
+    pub fn process(ctx: Context<Self>, params: SyntheticAssetParams) -> Result<()> {
+        params.validate()?;
+
+        let mut synthetic_asset = ctx.accounts.synthetic_asset.load_mut()?;
+
+        // Accrue the stability fee at the previous rate before it changes
+        synthetic_asset.accrue_stability_fee(Clock::get()?.unix_timestamp)?;
+
+        synthetic_asset.params = params;
+
+        Ok(())
+    }
+}
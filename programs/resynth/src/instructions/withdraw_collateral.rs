@@ -0,0 +1,128 @@
+use crate::{load_asset_prices, Errors, MarginAccount, PriceRequirement, SyntheticAsset};
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{transfer, Mint, Token, TokenAccount, Transfer},
+};
+
+#[derive(Accounts)]
+pub struct WithdrawCollateral<'info> {
+    /// The synthetic asset account
+    #[account(mut,
+      has_one = synthetic_oracle,
+      has_one = asset_authority,
+    )]
+    pub synthetic_asset: AccountLoader<'info, SyntheticAsset>,
+    /// The vault that collateral is withdrawn from, of any collateral the synthetic asset accepts
+    #[account(mut,
+      token::mint = collateral_mint,
+    )]
+    pub collateral_vault: Box<Account<'info, TokenAccount>>,
+    /// The mint of the collateral being withdrawn
+    pub collateral_mint: Box<Account<'info, Mint>>,
+    /// The oracle price feed, to determine margin account health
+    /// CHECK:
+    pub synthetic_oracle: AccountInfo<'info>,
+    /// The mint authority that can mint synthetic assets and transfer vault collateral
+    /// CHECK:
+    pub asset_authority: AccountInfo<'info>,
+
+    /// The owner of the margin account
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    /// The margin account of the owner, to track collateral and debt
+    #[account(mut,
+      has_one = owner,
+      has_one = synthetic_asset,
+    )]
+    pub margin_account: AccountLoader<'info, MarginAccount>,
+    /// The owners account that will receive collateral
+    #[account(
+      init_if_needed,
+      payer = owner,
+      associated_token::mint = collateral_mint,
+      associated_token::authority = owner,
+    )]
+    pub collateral_account: Box<Account<'info, TokenAccount>>,
+
+    pub system_program: Program<'info, System>,
+
+    /// The token program for CPI calls
+    pub token_program: Program<'info, Token>,
+
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+impl<'info> WithdrawCollateral<'info> {
+    /// CPI context to transfer collateral from the vault to the owners account
+    pub fn collateral_transfer_context(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        return CpiContext::new(
+            self.token_program.to_account_info(),
+            Transfer {
+                from: self.collateral_vault.to_account_info(),
+                to: self.collateral_account.to_account_info(),
+                authority: self.asset_authority.to_account_info(),
+            },
+        );
+    }
+
+    pub fn process(ctx: Context<Self>, collateral_amount: u64) -> Result<()> {
+        let mut synthetic_asset = ctx.accounts.synthetic_asset.load_mut()?;
+        let mut margin_account = ctx.accounts.margin_account.load_mut()?;
+
+        // Margin accounts of pooled synthetic assets owe debt pool shares instead
+        require!(!synthetic_asset.is_pooled(), Errors::SyntheticAssetPooled);
+
+        // Accrue the stability fee before checking health
+        synthetic_asset.accrue_stability_fee(Clock::get()?.unix_timestamp)?;
+
+        // Find which collateral is being withdrawn
+        let slot = synthetic_asset.collateral_slot(&ctx.accounts.collateral_vault.key())?;
+
+        // After a shutdown, debt must be settled before any collateral is released
+        if synthetic_asset.is_shutdown() {
+            require!(!margin_account.has_debt(), Errors::MarginAccountHasDebt);
+        }
+
+        // Update the margin account balances
+        let previous_margin_account = *margin_account;
+        margin_account.withdraw_collateral(slot, collateral_amount);
+        synthetic_asset.withdraw_collateral(slot, collateral_amount);
+
+        // Verify withdrawing does not make the margin account unhealthy. Margin accounts
+        // without debt are always healthy, so they can withdraw without an oracle.
+        if margin_account.has_debt() {
+            // Unwrap the oracle prices. Registered collateral oracles are passed as remaining accounts
+            let prices = load_asset_prices(
+                &ctx.accounts.synthetic_oracle,
+                ctx.remaining_accounts,
+                &synthetic_asset,
+                PriceRequirement::Trading,
+            )?;
+
+            // Trip the circuit breaker on a bad print, and never withdraw against one
+            let current_slot = Clock::get()?.slot;
+            synthetic_asset.observe_price(&prices.synthetic_price, current_slot);
+            synthetic_asset.verify_price_stable(current_slot)?;
+
+            margin_account.require_healthy(
+                &previous_margin_account,
+                &synthetic_asset,
+                &prices,
+                synthetic_asset.params.initial_collateral_ratio_bps,
+            )?;
+        }
+
+        // Transfer collateral from the vault to the owner
+        let signer_seeds: &[&[&[u8]]] = &[&synthetic_asset.signer_seeds()];
+        transfer(
+            ctx.accounts
+                .collateral_transfer_context()
+                .with_signer(signer_seeds),
+            collateral_amount,
+        )?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,68 @@
+use crate::{seeds, SyntheticAsset};
+use anchor_lang::prelude::*;
+use anchor_spl::token::{transfer, Mint, Token, TokenAccount, Transfer};
+
+#[derive(Accounts)]
+pub struct WithdrawTreasury<'info> {
+    /// The synthetic asset account
+    #[account(
+      has_one = admin,
+      has_one = asset_authority,
+    )]
+    pub synthetic_asset: AccountLoader<'info, SyntheticAsset>,
+    /// The mint authority that can mint synthetic assets and transfer vault collateral
+    /// CHECK:
+    pub asset_authority: AccountInfo<'info>,
+
+    /// The admin of the synthetic asset
+    pub admin: Signer<'info>,
+
+    /// The mint of the fees being withdrawn
+    pub mint: Box<Account<'info, Mint>>,
+    /// The treasury holding fees
+    #[account(mut,
+        seeds = [
+            seeds::TREASURY.as_ref(),
+            synthetic_asset.key().as_ref(),
+            mint.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub treasury: Box<Account<'info, TokenAccount>>,
+    /// The account receiving the fees
+    #[account(mut,
+      token::mint = mint,
+    )]
+    pub destination: Box<Account<'info, TokenAccount>>,
+
+    /// The token program for CPI calls
+    pub token_program: Program<'info, Token>,
+}
+
+impl<'info> WithdrawTreasury<'info> {
+    /// CPI context to transfer fees from the treasury to the destination
+    pub fn treasury_transfer_context(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        return CpiContext::new(
+            self.token_program.to_account_info(),
+            Transfer {
+                from: self.treasury.to_account_info(),
+                to: self.destination.to_account_info(),
+                authority: self.asset_authority.to_account_info(),
+            },
+        );
+    }
+
+    pub fn process(ctx: Context<Self>, amount: u64) -> Result<()> {
+        let synthetic_asset = ctx.accounts.synthetic_asset.load()?;
+
+        let signer_seeds: &[&[&[u8]]] = &[&synthetic_asset.signer_seeds()];
+        transfer(
+            ctx.accounts
+                .treasury_transfer_context()
+                .with_signer(signer_seeds),
+            amount,
+        )?;
+
+        Ok(())
+    }
+}
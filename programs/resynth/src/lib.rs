@@ -2,13 +2,17 @@ use anchor_lang::prelude::*;
 
 mod errors;
 mod instructions;
+mod oracle;
 mod seeds;
 mod state;
+mod valuation;
 
 pub use errors::*;
 pub use instructions::*;
+pub use oracle::*;
 pub use seeds::*;
 pub use state::*;
+pub use valuation::*;
 
 declare_id!("synttWtyx32zPvSm7gioaHUGJ4ZFsZUmnviEvjtoyoa");
 
@@ -16,9 +20,127 @@ declare_id!("synttWtyx32zPvSm7gioaHUGJ4ZFsZUmnviEvjtoyoa");
 pub mod resynth {
     use super::*;
 
-    /// Initialize a new synthetic asset
-    pub fn initialize_synthetic_asset(ctx: Context<InitializeSyntheticAsset>) -> Result<()> {
-        InitializeSyntheticAsset::process(ctx)
+    /// Initialize the program-wide config, allowing the oracle programs of this cluster
+    pub fn initialize_program_config(
+        ctx: Context<InitializeProgramConfig>,
+        oracle_programs: Vec<Pubkey>,
+    ) -> Result<()> {
+        InitializeProgramConfig::process(ctx, oracle_programs)
+    }
+
+    /// Replace the oracle programs allowed to price synthetic assets, as the config admin
+    pub fn set_oracle_programs(
+        ctx: Context<SetOraclePrograms>,
+        oracle_programs: Vec<Pubkey>,
+    ) -> Result<()> {
+        SetOraclePrograms::process(ctx, oracle_programs)
+    }
+
+    /// Initialize a new synthetic asset, derived from its symbol, with a synthetic mint
+    /// of `synthetic_decimals` decimals
+    pub fn initialize_synthetic_asset(
+        ctx: Context<InitializeSyntheticAsset>,
+        symbol: String,
+        params: SyntheticAssetParams,
+        synthetic_decimals: u8,
+    ) -> Result<()> {
+        InitializeSyntheticAsset::process(ctx, symbol, params, synthetic_decimals)
+    }
+
+    /// Update the risk parameters of a synthetic asset
+    pub fn update_synthetic_asset(
+        ctx: Context<UpdateSyntheticAsset>,
+        params: SyntheticAssetParams,
+    ) -> Result<()> {
+        UpdateSyntheticAsset::process(ctx, params)
+    }
+
+    /// Replace the oracle price feed of a synthetic asset, as its admin
+    pub fn set_oracle(ctx: Context<SetOracle>) -> Result<()> {
+        SetOracle::process(ctx)
+    }
+
+    /// Propose a new admin of a synthetic asset
+    pub fn transfer_admin(ctx: Context<TransferAdmin>, new_admin: Pubkey) -> Result<()> {
+        TransferAdmin::process(ctx, new_admin)
+    }
+
+    /// Accept the admin role of a synthetic asset, as the proposed admin
+    pub fn accept_admin(ctx: Context<AcceptAdmin>) -> Result<()> {
+        AcceptAdmin::process(ctx)
+    }
+
+    /// Accept an additional collateral mint for a synthetic asset
+    pub fn add_collateral_type(
+        ctx: Context<AddCollateralType>,
+        loan_to_value_bps: u64,
+    ) -> Result<()> {
+        AddCollateralType::process(ctx, loan_to_value_bps)
+    }
+
+    /// Update the loan to value of an additional collateral mint
+    pub fn update_collateral_type(
+        ctx: Context<UpdateCollateralType>,
+        loan_to_value_bps: u64,
+    ) -> Result<()> {
+        UpdateCollateralType::process(ctx, loan_to_value_bps)
+    }
+
+    /// Record the oracle price of a synthetic asset, tripping its circuit breaker on a bad print.
+    /// Mints and withdrawals rejected by a bad print revert their own trip, so keepers crank
+    /// this to start the cooldown.
+    pub fn update_circuit_breaker(ctx: Context<UpdateCircuitBreaker>) -> Result<()> {
+        UpdateCircuitBreaker::process(ctx)
+    }
+
+    /// Confirm the oracle price of a synthetic asset as its admin, ending a circuit breaker cooldown
+    pub fn confirm_oracle_price(ctx: Context<ConfirmOraclePrice>) -> Result<()> {
+        ConfirmOraclePrice::process(ctx)
+    }
+
+    /// Wind down a synthetic asset, closing it once nothing is owed to or by it
+    /// and every margin account is closed
+    pub fn close_synthetic_asset<'info>(
+        ctx: Context<'_, '_, '_, 'info, CloseSyntheticAsset<'info>>,
+    ) -> Result<()> {
+        CloseSyntheticAsset::process(ctx)
+    }
+
+    /// Shut down a synthetic asset, settling all debt at a final price
+    pub fn shutdown_synthetic_asset(
+        ctx: Context<ShutdownSyntheticAsset>,
+        settlement_price: i64,
+        settlement_expo: i32,
+    ) -> Result<()> {
+        ShutdownSyntheticAsset::process(ctx, settlement_price, settlement_expo)
+    }
+
+    /// Settle the debt of a margin account of a shut down synthetic asset
+    pub fn settle_margin_account(ctx: Context<SettleMarginAccount>) -> Result<()> {
+        SettleMarginAccount::process(ctx)
+    }
+
+    /// Settle the debt of a cross-margin position in a shut down synthetic asset
+    pub fn settle_cross_margin_position(ctx: Context<SettleCrossMarginPosition>) -> Result<()> {
+        SettleCrossMarginPosition::process(ctx)
+    }
+
+    /// Redeem synthetic tokens of a shut down synthetic asset for its settlement collateral
+    pub fn redeem_settlement<'info>(
+        ctx: Context<'_, '_, '_, 'info, RedeemSettlement<'info>>,
+        amount: u64,
+    ) -> Result<()> {
+        RedeemSettlement::process(ctx, amount)
+    }
+
+    /// Initialize the program-wide debt pool
+    pub fn initialize_debt_pool(ctx: Context<InitializeDebtPool>) -> Result<()> {
+        InitializeDebtPool::process(ctx)
+    }
+
+    /// Add a synthetic asset to the debt pool, sharing its debt with all pooled synthetic assets
+    pub fn add_pool_synthetic_asset(ctx: Context<AddPoolSyntheticAsset>) -> Result<()> {
+        AddPoolSyntheticAsset::process(ctx)
     }
 
     /// Initialize a new margin account
@@ -26,6 +148,29 @@ pub mod resynth {
         InitializeMarginAccount::process(ctx)
     }
 
+    /// Close a margin account without debt, sweeping its collateral to the owner
+    pub fn close_margin_account<'info>(
+        ctx: Context<'_, '_, '_, 'info, CloseMarginAccount<'info>>,
+    ) -> Result<()> {
+        CloseMarginAccount::process(ctx)
+    }
+
+    /// Deposit collateral into a margin account, on behalf of its owner
+    pub fn deposit_collateral(
+        ctx: Context<DepositCollateral>,
+        collateral_amount: u64,
+    ) -> Result<()> {
+        DepositCollateral::process(ctx, collateral_amount)
+    }
+
+    /// Withdraw collateral from a margin account, as its owner
+    pub fn withdraw_collateral(
+        ctx: Context<WithdrawCollateral>,
+        collateral_amount: u64,
+    ) -> Result<()> {
+        WithdrawCollateral::process(ctx, collateral_amount)
+    }
+
     /// Mint a synthetic asset
     pub fn mint_synthetic_asset(
         ctx: Context<MintSyntheticAsset>,
@@ -43,4 +188,113 @@ pub mod resynth {
     ) -> Result<()> {
         BurnSyntheticAsset::process(ctx, collateral_amount, burn_amount)
     }
+
+    /// Repay the debt of a margin account, on behalf of its owner
+    pub fn repay(ctx: Context<Repay>, repay_amount: u64) -> Result<()> {
+        Repay::process(ctx, repay_amount)
+    }
+
+    /// Mint the accrued stability fees of a synthetic asset to its treasury
+    pub fn collect_stability_fees(ctx: Context<CollectStabilityFees>) -> Result<()> {
+        CollectStabilityFees::process(ctx)
+    }
+
+    /// Withdraw fees from a treasury of a synthetic asset, as its admin
+    pub fn withdraw_treasury(ctx: Context<WithdrawTreasury>, amount: u64) -> Result<()> {
+        WithdrawTreasury::process(ctx, amount)
+    }
+
+    /// Verify the vaults and synthetic supply of a synthetic asset match its aggregate accounting
+    pub fn audit_synthetic_asset(ctx: Context<AuditSyntheticAsset>) -> Result<()> {
+        AuditSyntheticAsset::process(ctx)
+    }
+
+    /// Mint a pooled synthetic asset, in exchange for a share of the pool debt
+    pub fn issue_pool_debt(
+        ctx: Context<IssuePoolDebt>,
+        collateral_amount: u64,
+        mint_amount: u64,
+    ) -> Result<()> {
+        IssuePoolDebt::process(ctx, collateral_amount, mint_amount)
+    }
+
+    /// Burn a pooled synthetic asset, repaying a share of the pool debt
+    pub fn burn_pool_debt(
+        ctx: Context<BurnPoolDebt>,
+        collateral_amount: u64,
+        burn_amount: u64,
+    ) -> Result<()> {
+        BurnPoolDebt::process(ctx, collateral_amount, burn_amount)
+    }
+
+    /// Exchange a pooled synthetic asset for another at oracle prices
+    pub fn exchange_synthetic_asset(
+        ctx: Context<ExchangeSyntheticAsset>,
+        amount: u64,
+    ) -> Result<()> {
+        ExchangeSyntheticAsset::process(ctx, amount)
+    }
+
+    /// Redeem a synthetic asset for primary collateral at the oracle price, repaying
+    /// the debt of the margin accounts passed in, ordered lowest collateral ratio first
+    pub fn redeem(ctx: Context<Redeem>, amount: u64) -> Result<()> {
+        Redeem::process(ctx, amount)
+    }
+
+    /// Liquidate an unhealthy margin account, repaying debt in exchange for collateral
+    pub fn liquidate_margin_account(
+        ctx: Context<LiquidateMarginAccount>,
+        repay_amount: u64,
+    ) -> Result<()> {
+        LiquidateMarginAccount::process(ctx, repay_amount)
+    }
+
+    /// Liquidate an unhealthy margin account owing debt pool shares
+    pub fn liquidate_pool_account(
+        ctx: Context<LiquidatePoolAccount>,
+        repay_amount: u64,
+    ) -> Result<()> {
+        LiquidatePoolAccount::process(ctx, repay_amount)
+    }
+
+    /// Initialize a cross-margin account, whose collateral backs several synthetic assets
+    pub fn initialize_cross_margin_account(
+        ctx: Context<InitializeCrossMarginAccount>,
+    ) -> Result<()> {
+        InitializeCrossMarginAccount::process(ctx)
+    }
+
+    /// Deposit collateral into a cross-margin account, on behalf of its owner
+    pub fn cross_margin_deposit(
+        ctx: Context<CrossMarginDeposit>,
+        collateral_amount: u64,
+    ) -> Result<()> {
+        CrossMarginDeposit::process(ctx, collateral_amount)
+    }
+
+    /// Withdraw collateral from a cross-margin account, as its owner
+    pub fn cross_margin_withdraw(
+        ctx: Context<CrossMarginWithdraw>,
+        collateral_amount: u64,
+    ) -> Result<()> {
+        CrossMarginWithdraw::process(ctx, collateral_amount)
+    }
+
+    /// Mint a synthetic asset against the collateral of a cross-margin account
+    pub fn cross_margin_mint(ctx: Context<CrossMarginMint>, mint_amount: u64) -> Result<()> {
+        CrossMarginMint::process(ctx, mint_amount)
+    }
+
+    /// Burn a synthetic asset, repaying a position of a cross-margin account
+    pub fn cross_margin_burn(ctx: Context<CrossMarginBurn>, burn_amount: u64) -> Result<()> {
+        CrossMarginBurn::process(ctx, burn_amount)
+    }
+
+    /// Liquidate a position of an unhealthy cross-margin account
+    pub fn liquidate_cross_margin_account(
+        ctx: Context<LiquidateCrossMarginAccount>,
+        repay_amount: u64,
+    ) -> Result<()> {
+        LiquidateCrossMarginAccount::process(ctx, repay_amount)
+    }
 }
@@ -0,0 +1,682 @@
+use anchor_lang::prelude::*;
+use pyth_sdk_solana::{
+    state::{load_price_account, PriceAccount, PriceStatus, PriceType},
+    Price,
+};
+
+use anchor_spl::token::Mint;
+
+use crate::{
+    usd_value, CollateralSlot, CrossMarginAccount, DebtPool, Errors, PoolDebt, PositionValuation,
+    PositionValuations, PricingPolicy, ProgramConfig, SyntheticAsset, SyntheticAssetParams,
+    BASIS_POINTS_DENOMINATOR, MAX_COLLATERAL_TYPES, ONE_DOLLAR,
+};
+
+/// What an oracle price values, which decides the conservative direction
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PriceSide {
+    /// Synthetic debt, which is conservatively valued high
+    Debt,
+    /// Collateral, which is conservatively valued low
+    Collateral,
+}
+
+/// Validate an oracle is a price feed of an allowed oracle program, before a synthetic asset uses it
+pub fn validate_oracle(oracle: &AccountInfo, program_config: &ProgramConfig) -> Result<()> {
+    // A lookalike account of another program could report any price
+    require!(
+        program_config.is_oracle_program(oracle.owner),
+        Errors::InvalidOracleOwner
+    );
+
+    // Loading checks the magic number, version and account type of the feed
+    let data = oracle.try_borrow_data()?;
+    let price_account = load_price_account(&data).map_err(|_| Errors::InvalidOracle)?;
+    require!(
+        price_account.ptype == PriceType::Price,
+        Errors::InvalidOracle
+    );
+
+    Ok(())
+}
+
+/// Which feed statuses an oracle price is accepted in. Equity and FX feeds stop trading
+/// outside market hours, and keep reporting a price that can't be traded against.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PriceRequirement {
+    /// A live price from a trading feed, needed to open or grow exposure
+    Trading,
+    /// The last trading price of a halted feed, within the grace period of the synthetic
+    /// asset, is good enough to deposit collateral, or to repay or liquidate existing debt
+    LastTrading,
+}
+
+/// An oracle price, as tokens change hands at it and as health checks value it
+#[derive(Clone, Copy, Debug, Default)]
+pub struct OraclePrice {
+    /// The aggregate price of the feed. Payouts, seizures, fees and the circuit breaker
+    /// use it, so that nobody is paid for the conservative adjustments of health checks.
+    pub price: Price,
+    /// The price under the pricing policy of the synthetic asset, moved
+    /// confidence intervals against the margin account
+    pub health_price: Price,
+}
+
+/// The oracle prices needed to value margin accounts of a synthetic asset
+#[derive(Clone, Copy, Debug, Default)]
+pub struct AssetPrices {
+    /// The aggregate price of the synthetic asset
+    pub synthetic_price: Price,
+    /// The aggregate prices of the registered collateral types, by registry index
+    pub collateral_prices: [Price; MAX_COLLATERAL_TYPES],
+    /// The debt of the debt pool at aggregate prices, when the synthetic asset shares the debt pool
+    pub pool_debt: PoolDebt,
+    /// The price health checks value the synthetic asset at
+    pub synthetic_health_price: Price,
+    /// The prices health checks value the registered collateral types at, by registry index
+    pub collateral_health_prices: [Price; MAX_COLLATERAL_TYPES],
+    /// The debt of the debt pool at the prices health checks value it at
+    pub health_pool_debt: PoolDebt,
+}
+
+impl AssetPrices {
+    /// The price of a collateral mint of the synthetic asset
+    pub fn collateral_price(&self, slot: CollateralSlot) -> Price {
+        match slot {
+            CollateralSlot::Primary => ONE_DOLLAR,
+            CollateralSlot::Registered(index) => self.collateral_prices[index],
+        }
+    }
+
+    /// The prices health checks value margin accounts at, in place of the aggregate prices
+    pub fn health(&self) -> AssetPrices {
+        AssetPrices {
+            synthetic_price: self.synthetic_health_price,
+            collateral_prices: self.collateral_health_prices,
+            pool_debt: self.health_pool_debt,
+            ..*self
+        }
+    }
+}
+
+/// Load the price of a synthetic asset, and of all its registered collateral types.
+/// The collateral oracles are passed as remaining accounts, in registry order.
+pub fn load_asset_prices(
+    synthetic_oracle: &AccountInfo,
+    collateral_oracles: &[AccountInfo],
+    synthetic_asset: &SyntheticAsset,
+    requirement: PriceRequirement,
+) -> Result<AssetPrices> {
+    let synthetic_price = load_oracle_price(
+        synthetic_oracle,
+        synthetic_asset,
+        PriceSide::Debt,
+        requirement,
+    )?;
+    let collateral_prices =
+        load_collateral_prices(collateral_oracles, synthetic_asset, requirement)?;
+
+    Ok(AssetPrices {
+        synthetic_price: synthetic_price.price,
+        collateral_prices: collateral_prices.map(|collateral_price| collateral_price.price),
+        synthetic_health_price: synthetic_price.health_price,
+        collateral_health_prices: collateral_prices
+            .map(|collateral_price| collateral_price.health_price),
+        ..Default::default()
+    })
+}
+
+/// Load the prices of all registered collateral types of a synthetic asset.
+/// The collateral oracles are passed in registry order.
+pub fn load_collateral_prices(
+    collateral_oracles: &[AccountInfo],
+    synthetic_asset: &SyntheticAsset,
+    requirement: PriceRequirement,
+) -> Result<[OraclePrice; MAX_COLLATERAL_TYPES]> {
+    let mut collateral_prices = [OraclePrice::default(); MAX_COLLATERAL_TYPES];
+
+    let mut collateral_oracles = collateral_oracles.iter();
+    for (index, collateral_type) in synthetic_asset.collateral_types.iter().enumerate() {
+        if !collateral_type.is_registered() {
+            continue;
+        }
+
+        let collateral_oracle = collateral_oracles.next().ok_or(Errors::InvalidOracle)?;
+        require_keys_eq!(
+            collateral_oracle.key(),
+            collateral_type.oracle,
+            Errors::InvalidOracle
+        );
+
+        collateral_prices[index] = load_oracle_price(
+            collateral_oracle,
+            synthetic_asset,
+            PriceSide::Collateral,
+            requirement,
+        )?;
+    }
+
+    Ok(collateral_prices)
+}
+
+/// Load the prices of a synthetic asset sharing a debt pool, and the pool debt.
+/// The remaining accounts are the registered collateral oracles in registry order, followed by
+/// the synthetic asset, synthetic mint and synthetic oracle of every pooled synthetic asset.
+pub fn load_pool_asset_prices<'info>(
+    synthetic_oracle: &AccountInfo,
+    remaining_accounts: &[AccountInfo<'info>],
+    synthetic_asset: &SyntheticAsset,
+    debt_pool: &DebtPool,
+    requirement: PriceRequirement,
+) -> Result<AssetPrices> {
+    let collateral_type_count = synthetic_asset.collateral_type_count();
+    require!(
+        remaining_accounts.len() >= collateral_type_count,
+        Errors::InvalidOracle
+    );
+    let (collateral_oracles, pool_accounts) = remaining_accounts.split_at(collateral_type_count);
+
+    let mut prices = load_asset_prices(
+        synthetic_oracle,
+        collateral_oracles,
+        synthetic_asset,
+        requirement,
+    )?;
+    (prices.pool_debt, prices.health_pool_debt) =
+        load_pool_debt(pool_accounts, debt_pool, requirement)?;
+
+    Ok(prices)
+}
+
+/// Value every pooled synthetic asset in circulation at its aggregate oracle price,
+/// and at the price health checks value it at
+fn load_pool_debt<'info>(
+    pool_accounts: &[AccountInfo<'info>],
+    debt_pool: &DebtPool,
+    requirement: PriceRequirement,
+) -> Result<(PoolDebt, PoolDebt)> {
+    let synthetic_asset_count = debt_pool.synthetic_asset_count();
+    require!(
+        pool_accounts.len() == synthetic_asset_count * 3,
+        Errors::InvalidDebtPool
+    );
+
+    let mut total_debt_value: u128 = 0;
+    let mut total_health_debt_value: u128 = 0;
+    for (accounts, pooled_asset_key) in pool_accounts
+        .chunks(3)
+        .zip(debt_pool.synthetic_assets.iter())
+    {
+        let (asset_info, mint_info, oracle_info) = (&accounts[0], &accounts[1], &accounts[2]);
+        require_keys_eq!(asset_info.key(), *pooled_asset_key, Errors::InvalidDebtPool);
+
+        let pooled_asset_loader = AccountLoader::<SyntheticAsset>::try_from(asset_info)?;
+        let pooled_asset = pooled_asset_loader.load()?;
+        require_keys_eq!(
+            mint_info.key(),
+            pooled_asset.synthetic_mint,
+            Errors::InvalidDebtPool
+        );
+        require_keys_eq!(
+            oracle_info.key(),
+            pooled_asset.synthetic_oracle,
+            Errors::InvalidOracle
+        );
+
+        let synthetic_mint = Account::<Mint>::try_from(mint_info)?;
+        let price = load_oracle_price(oracle_info, &pooled_asset, PriceSide::Debt, requirement)?;
+        let debt_value = usd_value(
+            synthetic_mint.supply,
+            pooled_asset.synthetic_decimals,
+            &price.price,
+        )?;
+        let health_debt_value = usd_value(
+            synthetic_mint.supply,
+            pooled_asset.synthetic_decimals,
+            &price.health_price,
+        )?;
+
+        total_debt_value = total_debt_value
+            .checked_add(debt_value)
+            .ok_or(Errors::MathOverflow)?;
+        total_health_debt_value = total_health_debt_value
+            .checked_add(health_debt_value)
+            .ok_or(Errors::MathOverflow)?;
+    }
+
+    Ok((
+        PoolDebt {
+            total_debt_value,
+            total_debt_shares: debt_pool.total_debt_shares,
+        },
+        PoolDebt {
+            total_debt_value: total_health_debt_value,
+            total_debt_shares: debt_pool.total_debt_shares,
+        },
+    ))
+}
+
+/// Load the valuations of every open position of a cross-margin account.
+/// The synthetic asset and synthetic oracle of every open position are passed as remaining
+/// accounts in position order, except for the `active` synthetic asset the instruction loaded.
+/// The synthetic assets are writable, so that every price read feeds their circuit breakers,
+/// and new exposure is never taken while one of them is cooling down.
+pub fn load_position_valuations(
+    cross_margin_account: &CrossMarginAccount,
+    active: Option<(Pubkey, PositionValuation)>,
+    remaining_accounts: &[AccountInfo],
+    requirement: PriceRequirement,
+) -> Result<PositionValuations> {
+    let mut valuations = PositionValuations::default();
+    let current_time = Clock::get()?.unix_timestamp;
+    let current_slot = Clock::get()?.slot;
+
+    let mut position_accounts = remaining_accounts.chunks(2);
+    for (index, position) in cross_margin_account.positions.iter().enumerate() {
+        if !position.is_open() {
+            continue;
+        }
+
+        if let Some((active_asset, active_valuation)) = active {
+            if position.synthetic_asset == active_asset {
+                valuations[index] = active_valuation;
+                continue;
+            }
+        }
+
+        let accounts = position_accounts
+            .next()
+            .filter(|accounts| accounts.len() == 2)
+            .ok_or(Errors::InvalidCrossMarginPosition)?;
+        let (asset_info, oracle_info) = (&accounts[0], &accounts[1]);
+        require_keys_eq!(
+            asset_info.key(),
+            position.synthetic_asset,
+            Errors::InvalidCrossMarginPosition
+        );
+
+        // Debt is valued with the stability fee accrued up to now, on a copy of the asset
+        let synthetic_asset_loader = AccountLoader::<SyntheticAsset>::try_from(asset_info)?;
+        let mut position_asset = synthetic_asset_loader.load_mut()?;
+        let mut synthetic_asset = *position_asset;
+        synthetic_asset.accrue_stability_fee(current_time)?;
+
+        // Positions are settled at the settlement price after a shutdown
+        require!(
+            !synthetic_asset.is_shutdown(),
+            Errors::SyntheticAssetShutdown
+        );
+
+        require_keys_eq!(
+            oracle_info.key(),
+            synthetic_asset.synthetic_oracle,
+            Errors::InvalidOracle
+        );
+
+        let price = load_oracle_price(oracle_info, &synthetic_asset, PriceSide::Debt, requirement)?;
+        valuations[index] = PositionValuation::new(&synthetic_asset, &price);
+
+        // Trip the circuit breaker on a bad print. Liquidations keep working through a cooldown.
+        position_asset.observe_price(&price.price, current_slot);
+        if requirement == PriceRequirement::Trading {
+            position_asset.verify_price_stable(current_slot)?;
+        }
+    }
+
+    Ok(valuations)
+}
+
+/// Load an oracle price used by a synthetic asset, and the price its pricing policy values
+/// margin accounts at. Every price read goes through here, so that stale, halted or
+/// overly uncertain prices are never used to value a margin account.
+pub fn load_oracle_price(
+    oracle: &AccountInfo,
+    synthetic_asset: &SyntheticAsset,
+    side: PriceSide,
+    requirement: PriceRequirement,
+) -> Result<OraclePrice> {
+    // The owner was validated when the oracle was configured, and must still match
+    require_keys_eq!(
+        *oracle.owner,
+        synthetic_asset.oracle_program(oracle.key)?,
+        Errors::InvalidOracleOwner
+    );
+
+    let data = oracle.try_borrow_data()?;
+    let price_account = load_price_account(&data).map_err(|_| Errors::InvalidOracle)?;
+
+    let current_time = Clock::get()?.unix_timestamp;
+    checked_oracle_price(
+        price_account,
+        oracle.key,
+        current_time,
+        &synthetic_asset.params,
+        side,
+        requirement,
+    )
+}
+
+/// The aggregate price of an oracle at `current_time`, and its price under a pricing policy,
+/// as long as both can be trusted
+fn checked_oracle_price(
+    price_account: &PriceAccount,
+    oracle: &Pubkey,
+    current_time: i64,
+    params: &SyntheticAssetParams,
+    side: PriceSide,
+    requirement: PriceRequirement,
+) -> Result<OraclePrice> {
+    let is_trading = price_account.agg.status == PriceStatus::Trading;
+    let max_oracle_age = max_oracle_age(params, is_trading, requirement)?;
+
+    let price_feed = price_account.to_price_feed(oracle);
+    let spot_price = || {
+        price_feed
+            .get_price_no_older_than(current_time, max_oracle_age)
+            .ok_or(Errors::StaleOracle)
+    };
+    let ema_price = || {
+        price_feed
+            .get_ema_price_no_older_than(current_time, max_oracle_age)
+            .ok_or(Errors::StaleOracle)
+    };
+
+    let aggregate_price = spot_price()?;
+    let policy_price = match PricingPolicy::try_from(params.pricing_policy)? {
+        PricingPolicy::Spot => aggregate_price,
+        PricingPolicy::Ema => ema_price()?,
+        PricingPolicy::Conservative => conservative_price(aggregate_price, ema_price()?, side),
+    };
+    verify_confidence(&aggregate_price, params)?;
+    verify_confidence(&policy_price, params)?;
+
+    // Value against the margin account when publishers disagree, rather than trust the midpoint
+    Ok(OraclePrice {
+        price: aggregate_price,
+        health_price: confidence_adjusted_price(
+            policy_price,
+            side,
+            params.confidence_multiplier_bps,
+        )?,
+    })
+}
+
+/// Verify an oracle price is positive, and its confidence interval narrow enough to trust
+fn verify_confidence(oracle_price: &Price, params: &SyntheticAssetParams) -> Result<()> {
+    require!(oracle_price.price > 0, Errors::InvalidOracle);
+
+    // conf / price <= max_confidence_bps / 10_000, without the division
+    require!(
+        u128::from(oracle_price.conf) * u128::from(BASIS_POINTS_DENOMINATOR)
+            <= u128::from(params.max_oracle_confidence_bps)
+                * u128::from(oracle_price.price.unsigned_abs()),
+        Errors::OracleConfidenceTooWide
+    );
+
+    Ok(())
+}
+
+/// Move a price `confidence_multiplier_bps / 10_000` confidence intervals against a margin account.
+/// Debt is valued at `price + k * conf`, and collateral at `price - k * conf`.
+fn confidence_adjusted_price(
+    oracle_price: Price,
+    side: PriceSide,
+    confidence_multiplier_bps: u64,
+) -> Result<Price> {
+    let adjustment = u128::from(oracle_price.conf) * u128::from(confidence_multiplier_bps)
+        / u128::from(BASIS_POINTS_DENOMINATOR);
+    let adjustment = i64::try_from(adjustment).map_err(|_| Errors::OracleConfidenceTooWide)?;
+
+    let price = match side {
+        PriceSide::Debt => oracle_price.price.checked_add(adjustment),
+        PriceSide::Collateral => oracle_price.price.checked_sub(adjustment),
+    };
+    let price = price.ok_or(Errors::OracleConfidenceTooWide)?;
+    require!(price > 0, Errors::OracleConfidenceTooWide);
+
+    Ok(Price {
+        price,
+        ..oracle_price
+    })
+}
+
+/// How old an oracle price can be, depending on whether its feed is trading.
+/// A halted feed reports its last trading price, published when it stopped trading.
+fn max_oracle_age(
+    params: &SyntheticAssetParams,
+    is_trading: bool,
+    requirement: PriceRequirement,
+) -> Result<u64> {
+    match requirement {
+        _ if is_trading => Ok(params.max_oracle_age),
+        PriceRequirement::Trading => err!(Errors::OracleNotTrading),
+        PriceRequirement::LastTrading => Ok(params.halted_price_grace_period),
+    }
+}
+
+/// The less favorable of two prices of the same feed, for a margin account
+fn conservative_price(spot_price: Price, ema_price: Price, side: PriceSide) -> Price {
+    let spot_is_higher = spot_price.price >= ema_price.price;
+    match side {
+        PriceSide::Debt if spot_is_higher => spot_price,
+        PriceSide::Collateral if !spot_is_higher => spot_price,
+        _ => ema_price,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pyth_sdk_solana::state::PriceInfo;
+
+    fn price(price: i64) -> Price {
+        Price {
+            price,
+            conf: 0,
+            expo: -2,
+            publish_time: 0,
+        }
+    }
+
+    #[test]
+    fn halted_feeds_only_repay_or_liquidate() {
+        let params = SyntheticAssetParams {
+            max_oracle_age: 60,
+            halted_price_grace_period: 3600,
+            ..Default::default()
+        };
+
+        // A trading feed is held to the usual staleness for everything
+        assert_eq!(
+            max_oracle_age(&params, true, PriceRequirement::Trading).unwrap(),
+            60
+        );
+        assert_eq!(
+            max_oracle_age(&params, true, PriceRequirement::LastTrading).unwrap(),
+            60
+        );
+
+        // A halted feed can't mint, but its last trading price liquidates within the grace period
+        assert!(max_oracle_age(&params, false, PriceRequirement::Trading).is_err());
+        assert_eq!(
+            max_oracle_age(&params, false, PriceRequirement::LastTrading).unwrap(),
+            3600
+        );
+    }
+
+    #[test]
+    fn confidence_widens_against_the_margin_account() {
+        let oracle_price = Price {
+            conf: 4,
+            ..price(100)
+        };
+
+        // Two confidence intervals either side of the aggregate price
+        let debt_price = confidence_adjusted_price(oracle_price, PriceSide::Debt, 20_000).unwrap();
+        assert_eq!(debt_price.price, 108);
+        let collateral_price =
+            confidence_adjusted_price(oracle_price, PriceSide::Collateral, 20_000).unwrap();
+        assert_eq!(collateral_price.price, 92);
+
+        // No adjustment trusts the aggregate price
+        let collateral_price =
+            confidence_adjusted_price(oracle_price, PriceSide::Collateral, 0).unwrap();
+        assert_eq!(collateral_price.price, 100);
+
+        // Collateral is never valued at or below zero
+        assert!(confidence_adjusted_price(oracle_price, PriceSide::Collateral, 250_000).is_err());
+    }
+
+    #[test]
+    fn conservative_price_is_against_the_margin_account() {
+        // A wick above the average raises debt, but not collateral
+        assert_eq!(
+            conservative_price(price(110), price(100), PriceSide::Debt).price,
+            110
+        );
+        assert_eq!(
+            conservative_price(price(110), price(100), PriceSide::Collateral).price,
+            100
+        );
+
+        // A wick below the average lowers collateral, but not debt
+        assert_eq!(
+            conservative_price(price(90), price(100), PriceSide::Debt).price,
+            100
+        );
+        assert_eq!(
+            conservative_price(price(90), price(100), PriceSide::Collateral).price,
+            90
+        );
+    }
+
+    /// $100.00 with a $0.10 confidence interval, published at 1_000
+    fn price_account(status: PriceStatus) -> PriceAccount {
+        PriceAccount {
+            expo: -2,
+            timestamp: 1_000,
+            prev_price: 10_000,
+            prev_conf: 10,
+            prev_timestamp: 1_000,
+            agg: PriceInfo {
+                price: 10_000,
+                conf: 10,
+                status,
+                ..Default::default()
+            },
+            ..Default::default()
+        }
+    }
+
+    fn oracle_params() -> SyntheticAssetParams {
+        SyntheticAssetParams {
+            max_oracle_age: 60,
+            max_oracle_confidence_bps: 10,
+            halted_price_grace_period: 3600,
+            ..Default::default()
+        }
+    }
+
+    fn oracle_price(
+        price_account: &PriceAccount,
+        current_time: i64,
+        requirement: PriceRequirement,
+    ) -> Result<Price> {
+        checked_oracle_price(
+            price_account,
+            &Pubkey::default(),
+            current_time,
+            &oracle_params(),
+            PriceSide::Debt,
+            requirement,
+        )
+        .map(|oracle_price| oracle_price.price)
+    }
+
+    #[test]
+    fn fresh_price_is_trusted() {
+        let price = oracle_price(
+            &price_account(PriceStatus::Trading),
+            1_060,
+            PriceRequirement::Trading,
+        )
+        .unwrap();
+        assert_eq!(price.price, 10_000);
+        assert_eq!(price.conf, 10);
+    }
+
+    #[test]
+    fn stale_price_is_rejected() {
+        assert_eq!(
+            oracle_price(
+                &price_account(PriceStatus::Trading),
+                1_061,
+                PriceRequirement::Trading
+            )
+            .unwrap_err(),
+            error!(Errors::StaleOracle)
+        );
+    }
+
+    #[test]
+    fn halted_price_is_rejected() {
+        // The last price is still fresh, but the feed isn't trading
+        assert_eq!(
+            oracle_price(
+                &price_account(PriceStatus::Halted),
+                1_000,
+                PriceRequirement::Trading
+            )
+            .unwrap_err(),
+            error!(Errors::OracleNotTrading)
+        );
+    }
+
+    #[test]
+    fn halted_price_liquidates_within_grace_period() {
+        let halted = price_account(PriceStatus::Halted);
+        let price = oracle_price(&halted, 4_600, PriceRequirement::LastTrading).unwrap();
+        assert_eq!(price.price, 10_000);
+        assert_eq!(
+            oracle_price(&halted, 4_601, PriceRequirement::LastTrading).unwrap_err(),
+            error!(Errors::StaleOracle)
+        );
+    }
+
+    #[test]
+    fn only_health_checks_are_adjusted() {
+        // The EMA of $102.00 is above the aggregate price
+        let mut price_account = price_account(PriceStatus::Trading);
+        price_account.ema_price.val = 10_200;
+        price_account.ema_conf.val = 10;
+        let params = SyntheticAssetParams {
+            pricing_policy: PricingPolicy::Conservative as u64,
+            confidence_multiplier_bps: 20_000,
+            ..oracle_params()
+        };
+
+        // Debt is valued at the EMA plus two confidence intervals, but paid out at the aggregate
+        let oracle_price = checked_oracle_price(
+            &price_account,
+            &Pubkey::default(),
+            1_000,
+            &params,
+            PriceSide::Debt,
+            PriceRequirement::Trading,
+        )
+        .unwrap();
+        assert_eq!(oracle_price.price.price, 10_000);
+        assert_eq!(oracle_price.health_price.price, 10_220);
+    }
+
+    #[test]
+    fn wide_confidence_is_rejected() {
+        // $0.11 of confidence on $100.00 is 11 basis points
+        let mut price_account = price_account(PriceStatus::Trading);
+        price_account.agg.conf = 11;
+        assert_eq!(
+            oracle_price(&price_account, 1_000, PriceRequirement::Trading).unwrap_err(),
+            error!(Errors::OracleConfidenceTooWide)
+        );
+    }
+}
@@ -16,3 +16,18 @@ pub const MINT: &str = "mint";
 
 #[constant]
 pub const MARGIN_ACCOUNT: &str = "margin_account";
+
+/// The treasury token accounts receiving protocol fees
+#[constant]
+pub const TREASURY: &str = "treasury";
+
+/// The program-wide debt pool
+#[constant]
+pub const DEBT_POOL: &str = "debt_pool";
+
+/// The program-wide config
+#[constant]
+pub const PROGRAM_CONFIG: &str = "program_config";
+
+#[constant]
+pub const CROSS_MARGIN_ACCOUNT: &str = "cross_margin_account";
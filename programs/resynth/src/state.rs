@@ -1,5 +1,13 @@
+mod collateral_type;
+mod cross_margin_account;
+mod debt_pool;
 mod margin_account;
+mod program_config;
 mod synthetic_asset;
 
+pub use collateral_type::*;
+pub use cross_margin_account::*;
+pub use debt_pool::*;
 pub use margin_account::*;
+pub use program_config::*;
 pub use synthetic_asset::*;
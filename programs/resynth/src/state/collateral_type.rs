@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+
+use crate::{Errors, BASIS_POINTS_DENOMINATOR};
+
+/// The maximum number of collateral types a synthetic asset accepts,
+/// in addition to its primary collateral
+pub const MAX_COLLATERAL_TYPES: usize = 4;
+
+/// An additional collateral mint accepted by a synthetic asset
+#[zero_copy]
+#[derive(Default)]
+pub struct CollateralType {
+    /// The collateral mint, or the default pubkey if this slot is unused
+    pub mint: Pubkey,
+    /// The vault holding deposits of this collateral
+    pub vault: Pubkey,
+    /// The oracle price feed of this collateral
+    pub oracle: Pubkey,
+    /// The program owning the oracle price feed
+    pub oracle_program: Pubkey,
+    /// The portion of the collateral value counted towards margin account health,
+    /// in basis points
+    pub loan_to_value_bps: u64,
+    /// The collateral deposited by all margin accounts, which the vault must hold
+    pub total_collateral: u64,
+    /// The collateral set aside in the vault for synthetic holders after a shutdown
+    pub settlement_collateral: u64,
+    /// The decimals of the collateral mint
+    pub decimals: u8,
+    pub padding: [u8; 7],
+}
+
+impl CollateralType {
+    pub fn is_registered(&self) -> bool {
+        self.mint != Pubkey::default()
+    }
+}
+
+/// Which of the collateral of a synthetic asset is being used
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CollateralSlot {
+    /// The primary collateral mint of the synthetic asset, valued at $1
+    Primary,
+    /// An additional collateral type, by index into the registry
+    Registered(usize),
+}
+
+/// Validate the loan to value of a collateral type
+pub fn validate_loan_to_value(loan_to_value_bps: u64) -> Result<()> {
+    // Collateral worth nothing should not be registered,
+    // and collateral can't count for more than its value
+    require!(
+        loan_to_value_bps > 0 && loan_to_value_bps <= BASIS_POINTS_DENOMINATOR,
+        Errors::InvalidCollateralParameters
+    );
+
+    Ok(())
+}
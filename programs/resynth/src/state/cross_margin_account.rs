@@ -0,0 +1,428 @@
+use anchor_lang::prelude::*;
+use pyth_sdk_solana::Price;
+
+use crate::{
+    close_factor_amount, debt_amount, seeds, token_amount, usd_value, Errors, OraclePrice,
+    SyntheticAsset, BASIS_POINTS_DENOMINATOR, ONE_DOLLAR,
+};
+
+/// The maximum number of synthetic assets a cross-margin account can borrow
+pub const MAX_CROSS_MARGIN_POSITIONS: usize = 8;
+
+/// The debt of a cross-margin account in one synthetic asset
+#[zero_copy]
+#[derive(Default)]
+pub struct CrossMarginPosition {
+    /// The synthetic asset borrowed, or the default pubkey if this slot is unused
+    pub synthetic_asset: Pubkey,
+    /// The synthetic debt divided by the borrow index of the synthetic asset
+    pub normalized_debt: u64,
+}
+
+impl CrossMarginPosition {
+    pub fn is_open(&self) -> bool {
+        self.synthetic_asset != Pubkey::default()
+    }
+}
+
+/// A margin account whose collateral backs debt in several synthetic assets.
+/// The collateral is a single mint valued at $1, held in a vault owned by the account.
+#[account(zero_copy)]
+pub struct CrossMarginAccount {
+    pub owner: Pubkey,
+    /// The collateral mint, which must be the collateral mint of every synthetic asset borrowed
+    pub collateral_mint: Pubkey,
+    /// The vault holding the collateral, owned by this account
+    pub collateral_vault: Pubkey,
+    pub collateral_deposited: u64,
+    pub positions: [CrossMarginPosition; MAX_CROSS_MARGIN_POSITIONS],
+    pub bump: [u8; 1],
+    /// The decimals of the collateral mint
+    pub collateral_decimals: u8,
+    pub padding: [u8; 6],
+}
+
+/// Which collateral ratio of each synthetic asset the health of a
+/// cross-margin account is checked against
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum HealthCheck {
+    /// The initial collateral ratio, when opening debt or withdrawing collateral
+    Initial,
+    /// The maintenance collateral ratio, to decide whether the account can be liquidated
+    Maintenance,
+}
+
+/// The synthetic asset state and prices needed to value one position
+#[derive(Clone, Copy, Debug, Default)]
+pub struct PositionValuation {
+    pub synthetic_decimals: u8,
+    pub borrow_index: u64,
+    pub initial_collateral_ratio_bps: u64,
+    pub maintenance_collateral_ratio_bps: u64,
+    /// The aggregate price, which debt is repaid and seized at
+    pub price: Price,
+    /// The price health checks value the debt at
+    pub health_price: Price,
+}
+
+impl PositionValuation {
+    pub fn new(synthetic_asset: &SyntheticAsset, price: &OraclePrice) -> Self {
+        Self {
+            synthetic_decimals: synthetic_asset.synthetic_decimals,
+            borrow_index: synthetic_asset.borrow_index,
+            initial_collateral_ratio_bps: synthetic_asset.params.initial_collateral_ratio_bps,
+            maintenance_collateral_ratio_bps: synthetic_asset
+                .params
+                .maintenance_collateral_ratio_bps,
+            price: price.price,
+            health_price: price.health_price,
+        }
+    }
+
+    fn collateral_ratio_bps(&self, health_check: HealthCheck) -> u64 {
+        match health_check {
+            HealthCheck::Initial => self.initial_collateral_ratio_bps,
+            HealthCheck::Maintenance => self.maintenance_collateral_ratio_bps,
+        }
+    }
+}
+
+/// The valuations of the positions of a cross-margin account, by position index
+pub type PositionValuations = [PositionValuation; MAX_CROSS_MARGIN_POSITIONS];
+
+impl CrossMarginAccount {
+    pub fn signer_seeds(&self) -> [&[u8]; 4] {
+        [
+            seeds::CROSS_MARGIN_ACCOUNT.as_ref(),
+            self.collateral_mint.as_ref(),
+            self.owner.as_ref(),
+            self.bump.as_ref(),
+        ]
+    }
+
+    /// Find the position borrowing a synthetic asset
+    pub fn position_index(&self, synthetic_asset: &Pubkey) -> Option<usize> {
+        self.positions
+            .iter()
+            .position(|position| position.synthetic_asset == *synthetic_asset)
+    }
+
+    pub fn deposit_collateral(&mut self, amount: u64) {
+        self.collateral_deposited += amount;
+    }
+
+    pub fn withdraw_collateral(&mut self, amount: u64) {
+        self.collateral_deposited -= amount;
+    }
+
+    /// Pay a fee out of the collateral deposited
+    pub fn charge_fee(&mut self, fee_amount: u64) -> Result<()> {
+        self.collateral_deposited = self
+            .collateral_deposited
+            .checked_sub(fee_amount)
+            .ok_or(Errors::InsufficientCollateralForFee)?;
+
+        Ok(())
+    }
+
+    /// Add normalized debt to the position borrowing `synthetic_asset`,
+    /// opening it in the first unused slot if needed.
+    /// Returns the index of the position.
+    pub fn borrow(&mut self, synthetic_asset: &Pubkey, normalized_amount: u64) -> Result<usize> {
+        let index = match self.position_index(synthetic_asset) {
+            Some(index) => index,
+            None => {
+                let index = self
+                    .position_index(&Pubkey::default())
+                    .ok_or(Errors::CrossMarginPositionsFull)?;
+                self.positions[index].synthetic_asset = *synthetic_asset;
+                index
+            }
+        };
+
+        self.positions[index].normalized_debt += normalized_amount;
+
+        Ok(index)
+    }
+
+    /// Remove normalized debt from a position, closing it once repaid
+    pub fn repay(&mut self, index: usize, normalized_amount: u64) {
+        let position = &mut self.positions[index];
+        position.normalized_debt -= normalized_amount;
+
+        if position.normalized_debt == 0 {
+            *position = CrossMarginPosition::default();
+        }
+    }
+
+    /// The synthetic debt of a position, including the accrued stability fee
+    pub fn debt(&self, index: usize, valuation: &PositionValuation) -> Result<u64> {
+        debt_amount(
+            self.positions[index].normalized_debt,
+            valuation.borrow_index,
+        )
+    }
+
+    /// The USD value of the collateral deposited
+    pub fn collateral_value(&self) -> Result<u128> {
+        usd_value(
+            self.collateral_deposited,
+            self.collateral_decimals,
+            &ONE_DOLLAR,
+        )
+    }
+
+    /// Whether the collateral covers every position by at least
+    /// the collateral ratio of its synthetic asset
+    pub fn verify_healthy(
+        &self,
+        valuations: &PositionValuations,
+        health_check: HealthCheck,
+    ) -> Result<bool> {
+        /*
+         * Each synthetic asset requires its own collateral ratio, so the
+         * formula for isolated margin accounts is summed over all positions
+         *  collateral_value * 10_000 >= sum(debt_value * collateral_ratio_bps)
+         */
+        let collateral_value = self
+            .collateral_value()?
+            .checked_mul(u128::from(BASIS_POINTS_DENOMINATOR))
+            .ok_or(Errors::MathOverflow)?;
+
+        let mut required_value: u128 = 0;
+        for (index, valuation) in valuations.iter().enumerate() {
+            if !self.positions[index].is_open() {
+                continue;
+            }
+
+            let debt_value = usd_value(
+                self.debt(index, valuation)?,
+                valuation.synthetic_decimals,
+                &valuation.health_price,
+            )?;
+
+            required_value = debt_value
+                .checked_mul(u128::from(valuation.collateral_ratio_bps(health_check)))
+                .and_then(|value| value.checked_add(required_value))
+                .ok_or(Errors::MathOverflow)?;
+        }
+
+        Ok(collateral_value >= required_value)
+    }
+
+    /// Verify a change from `previous` leaves the account healthy.
+    /// Changes that only add collateral or only repay debt are always allowed.
+    pub fn require_healthy(
+        &self,
+        previous: &CrossMarginAccount,
+        valuations: &PositionValuations,
+    ) -> Result<()> {
+        let improves_health = self.collateral_deposited >= previous.collateral_deposited
+            && self.positions.iter().zip(previous.positions.iter()).all(
+                |(position, previous_position)| {
+                    position.normalized_debt <= previous_position.normalized_debt
+                },
+            );
+        if improves_health {
+            return Ok(());
+        }
+
+        require!(
+            self.verify_healthy(valuations, HealthCheck::Initial)?,
+            Errors::Undercollateralized
+        );
+
+        Ok(())
+    }
+
+    /// The most synthetic asset a liquidator can repay of a position in a single liquidation
+    pub fn max_liquidation_amount(
+        &self,
+        index: usize,
+        synthetic_asset: &SyntheticAsset,
+        valuation: &PositionValuation,
+    ) -> Result<u64> {
+        close_factor_amount(
+            self.debt(index, valuation)?,
+            synthetic_asset.params.liquidation_close_factor_bps,
+        )
+    }
+
+    /// The collateral taken to settle the debt of a position at the settlement price after a
+    /// shutdown, without a bonus. Debt left uncovered is shared by all synthetic holders.
+    pub fn settlement_collateral(
+        &self,
+        index: usize,
+        valuation: &PositionValuation,
+    ) -> Result<u64> {
+        let debt_value = usd_value(
+            self.debt(index, valuation)?,
+            valuation.synthetic_decimals,
+            &valuation.price,
+        )?;
+
+        // Round up, so that covered debt is always settled in full
+        let mut collateral_amount =
+            token_amount(debt_value, self.collateral_decimals, &ONE_DOLLAR)?;
+        if usd_value(collateral_amount, self.collateral_decimals, &ONE_DOLLAR)? < debt_value {
+            collateral_amount += 1;
+        }
+
+        Ok(collateral_amount.min(self.collateral_deposited))
+    }
+
+    /// The debt repaid and the collateral paid to a liquidator, for repaying up to
+    /// `repay_amount` of a position. The collateral is the oracle value of the repaid debt
+    /// plus the liquidation bonus of its synthetic asset. When that is more than the
+    /// collateral left in the account, all of it is seized and only the debt it covers is repaid.
+    pub fn liquidation_collateral(
+        &self,
+        synthetic_asset: &SyntheticAsset,
+        valuation: &PositionValuation,
+        repay_amount: u64,
+    ) -> Result<(u64, u64)> {
+        let bonus_factor =
+            u128::from(BASIS_POINTS_DENOMINATOR + synthetic_asset.params.liquidation_bonus_bps);
+        let repay_value = usd_value(repay_amount, valuation.synthetic_decimals, &valuation.price)?;
+
+        // The bonus is applied on top of the value of the repaid debt
+        let seized_value = repay_value
+            .checked_mul(bonus_factor)
+            .ok_or(Errors::MathOverflow)?
+            / u128::from(BASIS_POINTS_DENOMINATOR);
+
+        let collateral_amount = token_amount(seized_value, self.collateral_decimals, &ONE_DOLLAR)?;
+        if collateral_amount <= self.collateral_deposited {
+            return Ok((repay_amount, collateral_amount));
+        }
+
+        // The remaining collateral only pays for part of the debt with the bonus on top
+        let covered_value = usd_value(
+            self.collateral_deposited,
+            self.collateral_decimals,
+            &ONE_DOLLAR,
+        )?
+        .checked_mul(u128::from(BASIS_POINTS_DENOMINATOR))
+        .ok_or(Errors::MathOverflow)?
+            / bonus_factor;
+        let covered_amount = token_amount(
+            covered_value,
+            valuation.synthetic_decimals,
+            &valuation.price,
+        )?;
+
+        Ok((covered_amount.min(repay_amount), self.collateral_deposited))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::BORROW_INDEX_ONE;
+
+    fn price(price: i64) -> Price {
+        Price {
+            price,
+            conf: 0,
+            expo: -2,
+            publish_time: 0,
+        }
+    }
+
+    /// $1,800.00 per synthetic token, at 150% and 120% collateral ratios
+    fn gold() -> PositionValuation {
+        PositionValuation {
+            synthetic_decimals: 9,
+            borrow_index: BORROW_INDEX_ONE,
+            initial_collateral_ratio_bps: 15_000,
+            maintenance_collateral_ratio_bps: 12_000,
+            price: price(180_000),
+            health_price: price(180_000),
+        }
+    }
+
+    /// $200.00 per synthetic token, at 200% and 150% collateral ratios
+    fn equity() -> PositionValuation {
+        PositionValuation {
+            synthetic_decimals: 6,
+            borrow_index: BORROW_INDEX_ONE,
+            initial_collateral_ratio_bps: 20_000,
+            maintenance_collateral_ratio_bps: 15_000,
+            price: price(20_000),
+            health_price: price(20_000),
+        }
+    }
+
+    /// An account with `collateral_deposited` of a 6 decimal stablecoin,
+    /// borrowing 0.1 gold ($180) and 1 equity ($200)
+    fn cross_margin_account(collateral_deposited: u64) -> CrossMarginAccount {
+        let mut account = CrossMarginAccount {
+            owner: Pubkey::default(),
+            collateral_mint: Pubkey::default(),
+            collateral_vault: Pubkey::default(),
+            collateral_deposited,
+            positions: Default::default(),
+            bump: [0],
+            collateral_decimals: 6,
+            padding: [0; 6],
+        };
+        account.borrow(&Pubkey::new_unique(), 100_000_000).unwrap();
+        account.borrow(&Pubkey::new_unique(), 1_000_000).unwrap();
+        account
+    }
+
+    fn valuations() -> PositionValuations {
+        let mut valuations = PositionValuations::default();
+        valuations[0] = gold();
+        valuations[1] = equity();
+        valuations
+    }
+
+    #[test]
+    fn health_sums_all_positions() {
+        // $180 at 150% and $200 at 200% requires $670
+        assert!(cross_margin_account(670_000_000)
+            .verify_healthy(&valuations(), HealthCheck::Initial)
+            .unwrap());
+        assert!(!cross_margin_account(669_999_999)
+            .verify_healthy(&valuations(), HealthCheck::Initial)
+            .unwrap());
+
+        // $180 at 120% and $200 at 150% requires $516 to avoid liquidation
+        assert!(cross_margin_account(516_000_000)
+            .verify_healthy(&valuations(), HealthCheck::Maintenance)
+            .unwrap());
+        assert!(!cross_margin_account(515_999_999)
+            .verify_healthy(&valuations(), HealthCheck::Maintenance)
+            .unwrap());
+    }
+
+    #[test]
+    fn repaid_positions_are_closed() {
+        let mut account = cross_margin_account(670_000_000);
+        let equity_asset = account.positions[1].synthetic_asset;
+
+        account.repay(1, 1_000_000);
+        assert!(!account.positions[1].is_open());
+        assert_eq!(account.position_index(&equity_asset), None);
+
+        // Only the gold position is left, $180 at 150% requires $270
+        account.collateral_deposited = 270_000_000;
+        assert!(account
+            .verify_healthy(&valuations(), HealthCheck::Initial)
+            .unwrap());
+    }
+
+    #[test]
+    fn positions_are_limited() {
+        let mut account = cross_margin_account(0);
+        for _ in 2..MAX_CROSS_MARGIN_POSITIONS {
+            account.borrow(&Pubkey::new_unique(), 1).unwrap();
+        }
+
+        assert_eq!(
+            account.borrow(&Pubkey::new_unique(), 1).unwrap_err(),
+            error!(Errors::CrossMarginPositionsFull)
+        );
+    }
+}
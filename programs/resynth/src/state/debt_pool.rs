@@ -0,0 +1,145 @@
+use anchor_lang::prelude::*;
+
+use crate::{ceil_div, Errors};
+
+/// The maximum number of synthetic assets sharing a debt pool
+pub const MAX_POOL_SYNTHETIC_ASSETS: usize = 8;
+
+/// The fixed-point USD value of one debt share, when a pool has no debt
+pub const INITIAL_DEBT_SHARE_VALUE: u128 = 1_000_000;
+
+/// A program-wide pool of debt shared by the synthetic assets that join it.
+/// Margin accounts of pooled synthetic assets hold a share of the USD value of
+/// every pooled synthetic asset in circulation, instead of a fixed synthetic amount.
+#[account(zero_copy)]
+pub struct DebtPool {
+    /// The admin that can add synthetic assets to the pool
+    pub admin: Pubkey,
+    /// The synthetic assets sharing the pool debt, followed by default pubkeys
+    pub synthetic_assets: [Pubkey; MAX_POOL_SYNTHETIC_ASSETS],
+    /// The debt shares held by all margin accounts of the pool
+    pub total_debt_shares: u64,
+}
+
+impl DebtPool {
+    /// The number of synthetic assets sharing the pool debt
+    pub fn synthetic_asset_count(&self) -> usize {
+        self.synthetic_assets
+            .iter()
+            .take_while(|synthetic_asset| **synthetic_asset != Pubkey::default())
+            .count()
+    }
+}
+
+/// The debt of a pool, valued at oracle prices
+#[derive(Clone, Copy, Debug, Default)]
+pub struct PoolDebt {
+    /// The fixed-point USD value of every pooled synthetic asset in circulation
+    pub total_debt_value: u128,
+    /// The debt shares held by all margin accounts of the pool
+    pub total_debt_shares: u64,
+}
+
+impl PoolDebt {
+    /// The fixed-point USD debt of `debt_shares`, rounded up
+    pub fn debt_value(&self, debt_shares: u64) -> Result<u128> {
+        if self.total_debt_shares == 0 {
+            return Ok(0);
+        }
+
+        u128::from(debt_shares)
+            .checked_mul(self.total_debt_value)
+            .map(|value| ceil_div(value, u128::from(self.total_debt_shares)))
+            .ok_or(error!(Errors::MathOverflow))
+    }
+
+    /// The debt shares repaid by `value` of debt, rounded down
+    pub fn repaid_shares(&self, value: u128) -> Result<u64> {
+        if self.total_debt_value == 0 {
+            return Ok(0);
+        }
+
+        let shares = value
+            .checked_mul(u128::from(self.total_debt_shares))
+            .ok_or(Errors::MathOverflow)?
+            / self.total_debt_value;
+
+        u64::try_from(shares).map_err(|_| error!(Errors::MathOverflow))
+    }
+
+    /// Add `value` of new debt to the pool.
+    /// Returns the debt shares issued, rounded up in favor of the pool.
+    pub fn issue(&mut self, value: u128) -> Result<u64> {
+        let shares = if self.total_debt_shares == 0 || self.total_debt_value == 0 {
+            ceil_div(value, INITIAL_DEBT_SHARE_VALUE)
+        } else {
+            value
+                .checked_mul(u128::from(self.total_debt_shares))
+                .map(|shares| ceil_div(shares, self.total_debt_value))
+                .ok_or(Errors::MathOverflow)?
+        };
+        let shares = u64::try_from(shares).map_err(|_| Errors::MathOverflow)?;
+
+        self.total_debt_value += value;
+        self.total_debt_shares += shares;
+
+        Ok(shares)
+    }
+
+    /// Remove `value` of repaid debt and the `shares` it repaid from the pool
+    pub fn repay(&mut self, value: u128, shares: u64) {
+        self.total_debt_value = self.total_debt_value.saturating_sub(value);
+        self.total_debt_shares -= shares;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn first_issue_uses_initial_share_value() {
+        let mut pool_debt = PoolDebt::default();
+
+        // $1,000.00 of debt
+        assert_eq!(
+            pool_debt.issue(1_000_000_000_000_000).unwrap(),
+            1_000_000_000
+        );
+        assert_eq!(
+            pool_debt.debt_value(1_000_000_000).unwrap(),
+            1_000_000_000_000_000
+        );
+    }
+
+    #[test]
+    fn shares_follow_pool_debt_value() {
+        let mut pool_debt = PoolDebt::default();
+        let first_shares = pool_debt.issue(1_000_000_000_000_000).unwrap();
+
+        // The pooled synthetic assets double in value
+        pool_debt.total_debt_value *= 2;
+        assert_eq!(
+            pool_debt.debt_value(first_shares).unwrap(),
+            2_000_000_000_000_000
+        );
+
+        // New debt is issued at the current share value
+        let second_shares = pool_debt.issue(2_000_000_000_000_000).unwrap();
+        assert_eq!(second_shares, first_shares);
+
+        // Repaying half the pool repays half the shares
+        let repaid_shares = pool_debt.repaid_shares(2_000_000_000_000_000).unwrap();
+        assert_eq!(repaid_shares, first_shares);
+        pool_debt.repay(2_000_000_000_000_000, repaid_shares);
+        assert_eq!(pool_debt.total_debt_shares, second_shares);
+    }
+
+    #[test]
+    fn empty_pool_has_no_debt() {
+        let pool_debt = PoolDebt::default();
+
+        assert_eq!(pool_debt.debt_value(1_000).unwrap(), 0);
+        assert_eq!(pool_debt.repaid_shares(1_000).unwrap(), 0);
+    }
+}
@@ -1,83 +1,992 @@
 use anchor_lang::prelude::*;
-use pyth_sdk_solana::Price;
 
-use crate::Errors;
-
-/// The numerator of value of one lamport of collateral
-const COLLATERAL_VALUE_NUMERATOR: u64 = 1;
-/// The denominator of value of one lamport of collateral
-/// Collateral mint is hardcoded to 6 decimals, for now.
-const COLLATERAL_VALUE_DENOMINATOR: u64 = 1_000_000;
-
-const COLLATERAL_RATIO_NUMERATOR: u64 = 15;
-const COLLATERAL_RATIO_DENOMINATOR: u64 = 10;
+use crate::{
+    token_amount, usd_value, AssetPrices, CollateralSlot, Errors, SyntheticAsset,
+    BASIS_POINTS_DENOMINATOR, MAX_COLLATERAL_TYPES,
+};
 
 #[account(zero_copy)]
 pub struct MarginAccount {
     pub owner: Pubkey,
     pub synthetic_asset: Pubkey,
     pub collateral_deposited: u64,
-    pub synthetic_asset_borrowed: u64,
+    /// The synthetic debt divided by the borrow index of the synthetic asset,
+    /// so that it grows with the stability fee
+    pub normalized_debt: u64,
+    /// The share of the debt pool owed, when the synthetic asset shares the debt pool
+    pub debt_shares: u64,
+    /// Deposits of the registered collateral types of the synthetic asset, by registry index
+    pub collateral_type_deposited: [u64; MAX_COLLATERAL_TYPES],
 }
 
 impl MarginAccount {
-    pub fn mint_synthetic_asset(&mut self, collateral_amount: u64, mint_amount: u64) {
+    pub fn mint_synthetic_asset(
+        &mut self,
+        slot: CollateralSlot,
+        collateral_amount: u64,
+        normalized_amount: u64,
+    ) {
         // Overflow checks are implicit because
         // of the cargo.toml parameter overflow-checks = true
-        self.collateral_deposited += collateral_amount;
-        self.synthetic_asset_borrowed += mint_amount;
+        *self.collateral_balance_mut(slot) += collateral_amount;
+        self.normalized_debt += normalized_amount;
     }
 
-    pub fn burn_synthetic_asset(&mut self, collateral_amount: u64, burn_amount: u64) {
+    pub fn burn_synthetic_asset(
+        &mut self,
+        slot: CollateralSlot,
+        collateral_amount: u64,
+        normalized_amount: u64,
+    ) {
         // Overflow checks are implicit because
         // of the cargo.toml parameter overflow-checks = true
-        self.collateral_deposited -= collateral_amount;
-        self.synthetic_asset_borrowed -= burn_amount;
+        *self.collateral_balance_mut(slot) -= collateral_amount;
+        self.normalized_debt -= normalized_amount;
     }
 
-    pub fn verify_healthy(&self, oracle_price: Price) -> Result<bool> {
-        assert!(
-            oracle_price.expo <= 0,
-            "Oracle exponent is positive, which is not yet supported by this code"
-        );
+    pub fn deposit_collateral(&mut self, slot: CollateralSlot, amount: u64) {
+        *self.collateral_balance_mut(slot) += amount;
+    }
+
+    pub fn withdraw_collateral(&mut self, slot: CollateralSlot, amount: u64) {
+        *self.collateral_balance_mut(slot) -= amount;
+    }
+
+    pub fn issue_debt_shares(&mut self, debt_shares: u64) {
+        self.debt_shares += debt_shares;
+    }
+
+    pub fn repay_debt_shares(&mut self, debt_shares: u64) {
+        self.debt_shares -= debt_shares;
+    }
+
+    /// Pay a fee out of the collateral deposited
+    pub fn charge_fee(&mut self, slot: CollateralSlot, fee_amount: u64) -> Result<()> {
+        let balance = self.collateral_balance_mut(slot);
+        *balance = balance
+            .checked_sub(fee_amount)
+            .ok_or(Errors::InsufficientCollateralForFee)?;
+
+        Ok(())
+    }
 
-        let price = u64::try_from(oracle_price.price).map_err(|_| Errors::Undercollateralized)?;
-        let price_expo =
-            u32::try_from(-oracle_price.expo).map_err(|_| Errors::Undercollateralized)?;
+    pub fn repay(&mut self, normalized_amount: u64) {
+        self.normalized_debt -= normalized_amount;
+    }
+
+    /// Whether the margin account owes synthetic debt or debt pool shares
+    pub fn has_debt(&self) -> bool {
+        self.normalized_debt > 0 || self.debt_shares > 0
+    }
+
+    /// The amount deposited of a collateral mint of the synthetic asset
+    pub fn collateral_balance(&self, slot: CollateralSlot) -> u64 {
+        match slot {
+            CollateralSlot::Primary => self.collateral_deposited,
+            CollateralSlot::Registered(index) => self.collateral_type_deposited[index],
+        }
+    }
+
+    fn collateral_balance_mut(&mut self, slot: CollateralSlot) -> &mut u64 {
+        match slot {
+            CollateralSlot::Primary => &mut self.collateral_deposited,
+            CollateralSlot::Registered(index) => &mut self.collateral_type_deposited[index],
+        }
+    }
+
+    /// The USD value of the collateral deposited, counting registered
+    /// collateral types at their loan to value
+    pub fn collateral_value(
+        &self,
+        synthetic_asset: &SyntheticAsset,
+        prices: &AssetPrices,
+    ) -> Result<u128> {
+        let mut collateral_value = usd_value(
+            self.collateral_deposited,
+            synthetic_asset.collateral_decimals,
+            &prices.collateral_price(CollateralSlot::Primary),
+        )?;
+
+        for (index, collateral_type) in synthetic_asset.collateral_types.iter().enumerate() {
+            let amount = self.collateral_type_deposited[index];
+            if amount == 0 {
+                continue;
+            }
+
+            let value = usd_value(
+                amount,
+                collateral_type.decimals,
+                &prices.collateral_prices[index],
+            )?
+            .checked_mul(u128::from(collateral_type.loan_to_value_bps))
+            .ok_or(Errors::MathOverflow)?
+                / u128::from(BASIS_POINTS_DENOMINATOR);
+
+            collateral_value = collateral_value
+                .checked_add(value)
+                .ok_or(Errors::MathOverflow)?;
+        }
+
+        Ok(collateral_value)
+    }
 
+    /// The synthetic debt, including the accrued stability fee
+    pub fn debt(&self, synthetic_asset: &SyntheticAsset) -> Result<u64> {
+        synthetic_asset.debt_amount(self.normalized_debt)
+    }
+
+    /// The USD value of the synthetic debt at the oracle price,
+    /// plus the value of the debt pool shares owed
+    pub fn debt_value(
+        &self,
+        synthetic_asset: &SyntheticAsset,
+        prices: &AssetPrices,
+    ) -> Result<u128> {
+        let debt_value = usd_value(
+            self.debt(synthetic_asset)?,
+            synthetic_asset.synthetic_decimals,
+            &prices.synthetic_price,
+        )?;
+
+        debt_value
+            .checked_add(prices.pool_debt.debt_value(self.debt_shares)?)
+            .ok_or(error!(Errors::MathOverflow))
+    }
+
+    /// Whether the collateral covers the debt by at least `collateral_ratio_bps`, at the prices
+    /// health checks value the account at. Use the initial collateral ratio when opening debt,
+    /// and the maintenance collateral ratio to decide whether the account can be liquidated.
+    pub fn verify_healthy(
+        &self,
+        synthetic_asset: &SyntheticAsset,
+        prices: &AssetPrices,
+        collateral_ratio_bps: u64,
+    ) -> Result<bool> {
         /*
          * The original formula is:
-         *  collateral_value / (oracle_price * minted_amount) >= 1.5
+         *  collateral_value / debt_value >= collateral_ratio
          *
          * This uses floats, which get emulated with integers
          * It also uses division, which is the slowest operation
          *
-         * The formula can be rethought as
-         *  collateral_value >= oracle_price * minted_amount * 1.5
-         *
-         * The fraction can be eliminated with a numerator and denominator
-         *  collateral_value >= oracle_price * minted_amount * 15 / 10
-         *
-         * Some variable must be expanded
-         *  collateral_value = collateral_balance * 15 / 10
-         *
-         * All together, with collateral denominator moved to the other side of the equation
-         *  collateral_balance * collateral_numerator >= oracle_price * minted_amount * collateral_denominator * 15 / 10
+         * Both values are fixed-point USD, and the ratio is in basis points,
+         * so the formula can be rethought as
+         *  collateral_value * 10_000 >= debt_value * collateral_ratio_bps
          *
          * Then the formula works perfectly well with integers :)
          */
+        let prices = &prices.health();
+
+        msg!(&("oracle price ".to_owned() + &prices.synthetic_price.price.to_string()));
+        msg!(&("oracle expo ".to_owned() + &prices.synthetic_price.expo.to_string()));
+
+        let collateral_value = self
+            .collateral_value(synthetic_asset, prices)?
+            .checked_mul(u128::from(BASIS_POINTS_DENOMINATOR))
+            .ok_or(Errors::MathOverflow)?;
+        let debt_value = self
+            .debt_value(synthetic_asset, prices)?
+            .checked_mul(u128::from(collateral_ratio_bps))
+            .ok_or(Errors::MathOverflow)?;
 
-        msg!(&("oracle price ".to_owned() + &price.to_string()));
-        msg!(&("oracle expo ".to_owned() + &oracle_price.expo.to_string()));
+        Ok(collateral_value >= debt_value)
+    }
+
+    /// Verify a change from `previous` leaves the margin account healthy.
+    /// Changes that only add collateral or only repay debt are always allowed,
+    /// so that an unhealthy margin account can still be rescued.
+    pub fn require_healthy(
+        &self,
+        previous: &MarginAccount,
+        synthetic_asset: &SyntheticAsset,
+        prices: &AssetPrices,
+        collateral_ratio_bps: u64,
+    ) -> Result<()> {
+        let improves_health = self.collateral_deposited >= previous.collateral_deposited
+            && self
+                .collateral_type_deposited
+                .iter()
+                .zip(previous.collateral_type_deposited.iter())
+                .all(|(amount, previous_amount)| amount >= previous_amount)
+            && self.normalized_debt <= previous.normalized_debt
+            && self.debt_shares <= previous.debt_shares;
+        if improves_health {
+            return Ok(());
+        }
 
-        let healthy = u128::from(
-            &self.collateral_deposited * COLLATERAL_VALUE_NUMERATOR * COLLATERAL_RATIO_DENOMINATOR,
-        ) * u128::from(10u64.pow(price_expo))
-            >= u128::from(price)
-                * u128::from(self.synthetic_asset_borrowed)
-                * u128::from(COLLATERAL_VALUE_DENOMINATOR)
-                * u128::from(COLLATERAL_RATIO_NUMERATOR);
+        require!(
+            self.verify_healthy(synthetic_asset, prices, collateral_ratio_bps)?,
+            Errors::Undercollateralized
+        );
 
-        Ok(healthy)
+        Ok(())
+    }
+
+    /// The most synthetic debt a liquidator can repay in a single liquidation
+    pub fn max_liquidation_amount(&self, synthetic_asset: &SyntheticAsset) -> Result<u64> {
+        liquidation_limit(self.debt(synthetic_asset)?, synthetic_asset)
+    }
+
+    /// The debt pool shares owed, in the synthetic asset at the oracle price
+    pub fn pool_debt(&self, synthetic_asset: &SyntheticAsset, prices: &AssetPrices) -> Result<u64> {
+        token_amount(
+            prices.pool_debt.debt_value(self.debt_shares)?,
+            synthetic_asset.synthetic_decimals,
+            &prices.synthetic_price,
+        )
+    }
+
+    /// The most synthetic asset a liquidator can burn in a single liquidation
+    /// of debt pool shares, valued at the oracle price
+    pub fn max_pool_liquidation_amount(
+        &self,
+        synthetic_asset: &SyntheticAsset,
+        prices: &AssetPrices,
+    ) -> Result<u64> {
+        liquidation_limit(self.pool_debt(synthetic_asset, prices)?, synthetic_asset)
+    }
+
+    /// The synthetic amount burned and the debt pool shares repaid,
+    /// when burning up to `burn_amount` of the synthetic asset to repay pool debt
+    pub fn pool_repayment(
+        &self,
+        synthetic_asset: &SyntheticAsset,
+        prices: &AssetPrices,
+        burn_amount: u64,
+    ) -> Result<(u64, u64)> {
+        let debt_value = prices.pool_debt.debt_value(self.debt_shares)?;
+        let burn_value = usd_value(
+            burn_amount,
+            synthetic_asset.synthetic_decimals,
+            &prices.synthetic_price,
+        )?;
+
+        if burn_value < debt_value {
+            let repaid_shares = prices.pool_debt.repaid_shares(burn_value)?;
+            return Ok((burn_amount, repaid_shares.min(self.debt_shares)));
+        }
+
+        // Never burn more than the outstanding debt. Rounding down the burn
+        // leaves less than one unit of the synthetic asset unpaid.
+        let burn_amount = token_amount(
+            debt_value,
+            synthetic_asset.synthetic_decimals,
+            &prices.synthetic_price,
+        )?;
+
+        Ok((burn_amount, self.debt_shares))
+    }
+
+    /// The synthetic debt repaid and the collateral paid to a liquidator, for repaying up to
+    /// `repay_amount` of synthetic debt. The collateral is the oracle value of the repaid debt
+    /// plus the liquidation bonus. When that is more than the collateral of that type left in
+    /// the margin account, all of it is seized and only the debt it covers is repaid.
+    pub fn liquidation_collateral(
+        &self,
+        synthetic_asset: &SyntheticAsset,
+        prices: &AssetPrices,
+        slot: CollateralSlot,
+        repay_amount: u64,
+    ) -> Result<(u64, u64)> {
+        let bonus_factor =
+            u128::from(BASIS_POINTS_DENOMINATOR + synthetic_asset.params.liquidation_bonus_bps);
+        let decimals = synthetic_asset.collateral_decimals(slot);
+        let collateral_price = prices.collateral_price(slot);
+
+        let repay_value = usd_value(
+            repay_amount,
+            synthetic_asset.synthetic_decimals,
+            &prices.synthetic_price,
+        )?;
+
+        // The bonus is applied on top of the value of the repaid debt
+        let seized_value = repay_value
+            .checked_mul(bonus_factor)
+            .ok_or(Errors::MathOverflow)?
+            / u128::from(BASIS_POINTS_DENOMINATOR);
+
+        let collateral_amount = token_amount(seized_value, decimals, &collateral_price)?;
+        let balance = self.collateral_balance(slot);
+        if collateral_amount <= balance {
+            return Ok((repay_amount, collateral_amount));
+        }
+
+        // The remaining collateral only pays for part of the debt with the bonus on top
+        let covered_value = usd_value(balance, decimals, &collateral_price)?
+            .checked_mul(u128::from(BASIS_POINTS_DENOMINATOR))
+            .ok_or(Errors::MathOverflow)?
+            / bonus_factor;
+        let covered_amount = token_amount(
+            covered_value,
+            synthetic_asset.synthetic_decimals,
+            &prices.synthetic_price,
+        )?;
+
+        Ok((covered_amount.min(repay_amount), balance))
+    }
+
+    /// The collateral ratio at the prices health checks value the account at, in basis points
+    pub fn collateral_ratio_bps(
+        &self,
+        synthetic_asset: &SyntheticAsset,
+        prices: &AssetPrices,
+    ) -> Result<u128> {
+        let prices = &prices.health();
+        let debt_value = self.debt_value(synthetic_asset, prices)?;
+        if debt_value == 0 {
+            return Ok(u128::MAX);
+        }
+
+        let collateral_value = self
+            .collateral_value(synthetic_asset, prices)?
+            .checked_mul(u128::from(BASIS_POINTS_DENOMINATOR))
+            .ok_or(Errors::MathOverflow)?;
+
+        Ok(collateral_value / debt_value)
+    }
+
+    /// The synthetic debt repaid and the primary collateral paid out when redeeming up to
+    /// `max_amount` of synthetic asset against this margin account, at the oracle price.
+    /// Partial redemptions never leave less than the minimum debt behind.
+    pub fn redemption(
+        &self,
+        synthetic_asset: &SyntheticAsset,
+        prices: &AssetPrices,
+        max_amount: u64,
+    ) -> Result<(u64, u64)> {
+        let debt = self.debt(synthetic_asset)?;
+        let collateral_price = prices.collateral_price(CollateralSlot::Primary);
+
+        // Only the primary collateral is redeemed
+        let collateral_value = usd_value(
+            self.collateral_deposited,
+            synthetic_asset.collateral_decimals,
+            &collateral_price,
+        )?;
+        let covered_amount = token_amount(
+            collateral_value,
+            synthetic_asset.synthetic_decimals,
+            &prices.synthetic_price,
+        )?;
+
+        let mut amount = max_amount.min(debt).min(covered_amount);
+        if amount < debt && debt - amount < synthetic_asset.params.min_debt {
+            amount = debt.saturating_sub(synthetic_asset.params.min_debt);
+        }
+
+        let redeemed_value = usd_value(
+            amount,
+            synthetic_asset.synthetic_decimals,
+            &prices.synthetic_price,
+        )?;
+        let collateral_amount = token_amount(
+            redeemed_value,
+            synthetic_asset.collateral_decimals,
+            &collateral_price,
+        )?;
+
+        Ok((amount, collateral_amount.min(self.collateral_deposited)))
+    }
+
+    /// The collateral taken from each slot to settle the debt at the settlement price after a
+    /// shutdown, without a bonus. Primary collateral is taken first, then registered collateral
+    /// in registry order. Debt left uncovered is shared by all synthetic holders.
+    pub fn settlement_collateral(
+        &self,
+        synthetic_asset: &SyntheticAsset,
+        prices: &AssetPrices,
+    ) -> Result<Vec<(CollateralSlot, u64)>> {
+        let mut remaining_value = usd_value(
+            self.debt(synthetic_asset)?,
+            synthetic_asset.synthetic_decimals,
+            &prices.synthetic_price,
+        )?;
+
+        let mut settlement = Vec::new();
+        for slot in synthetic_asset.collateral_slots() {
+            let balance = self.collateral_balance(slot);
+            if remaining_value == 0 || balance == 0 {
+                continue;
+            }
+
+            // Round up, so that covered debt is always settled in full
+            let decimals = synthetic_asset.collateral_decimals(slot);
+            let price = prices.collateral_price(slot);
+            let mut amount = token_amount(remaining_value, decimals, &price)?;
+            if usd_value(amount, decimals, &price)? < remaining_value {
+                amount += 1;
+            }
+            let amount = amount.min(balance);
+
+            remaining_value = remaining_value.saturating_sub(usd_value(amount, decimals, &price)?);
+            settlement.push((slot, amount));
+        }
+
+        Ok(settlement)
+    }
+}
+
+/// The portion of `debt` repayable under the liquidation close factor.
+/// Rounded up so that dust positions can always be fully liquidated.
+pub(crate) fn close_factor_amount(debt: u64, close_factor_bps: u64) -> Result<u64> {
+    let max_amount = (u128::from(debt) * u128::from(close_factor_bps)
+        + u128::from(BASIS_POINTS_DENOMINATOR - 1))
+        / u128::from(BASIS_POINTS_DENOMINATOR);
+
+    u64::try_from(max_amount).map_err(|_| error!(Errors::MathOverflow))
+}
+
+/// The most of `debt` a liquidator can repay at once. The whole debt can be repaid
+/// when the close factor would leave less than the minimum debt behind.
+fn liquidation_limit(debt: u64, synthetic_asset: &SyntheticAsset) -> Result<u64> {
+    let max_amount =
+        close_factor_amount(debt, synthetic_asset.params.liquidation_close_factor_bps)?;
+    if debt - max_amount < synthetic_asset.params.min_debt {
+        return Ok(debt);
+    }
+
+    Ok(max_amount)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{CollateralType, PoolDebt, BORROW_INDEX_ONE};
+    use pyth_sdk_solana::Price;
+
+    const SOL: CollateralSlot = CollateralSlot::Registered(0);
+
+    const COLLATERAL_RATIO_BPS: u64 = 15_000;
+
+    /// $1,800.00 per synthetic token
+    fn gold_price() -> Price {
+        Price {
+            price: 180_000,
+            conf: 1,
+            expo: -2,
+            publish_time: 0,
+        }
+    }
+
+    /// $20.00 per SOL
+    fn sol_price() -> Price {
+        Price {
+            price: 2_000,
+            conf: 1,
+            expo: -2,
+            publish_time: 0,
+        }
+    }
+
+    /// Synthetic gold with 9 decimals, backed by a 6 decimal stablecoin,
+    /// and by SOL at 50% loan to value
+    fn synthetic_asset() -> SyntheticAsset {
+        let mut synthetic_asset = SyntheticAsset {
+            collateral_decimals: 6,
+            synthetic_decimals: 9,
+            borrow_index: BORROW_INDEX_ONE,
+            ..Default::default()
+        };
+        synthetic_asset.collateral_types[0] = CollateralType {
+            mint: Pubkey::new_unique(),
+            loan_to_value_bps: 5_000,
+            decimals: 9,
+            ..Default::default()
+        };
+        synthetic_asset
+    }
+
+    /// Health checks value at the aggregate prices, as if no confidence adjustment is configured
+    fn prices() -> AssetPrices {
+        let mut prices = AssetPrices {
+            synthetic_price: gold_price(),
+            synthetic_health_price: gold_price(),
+            ..Default::default()
+        };
+        prices.collateral_prices[0] = sol_price();
+        prices.collateral_health_prices[0] = sol_price();
+        prices
+    }
+
+    fn margin_account(collateral_deposited: u64, normalized_debt: u64) -> MarginAccount {
+        MarginAccount {
+            owner: Pubkey::default(),
+            synthetic_asset: Pubkey::default(),
+            collateral_deposited,
+            normalized_debt,
+            debt_shares: 0,
+            collateral_type_deposited: [0; MAX_COLLATERAL_TYPES],
+        }
+    }
+
+    fn mint(previous: &MarginAccount, collateral_amount: u64, mint_amount: u64) -> Result<()> {
+        mint_collateral(
+            previous,
+            CollateralSlot::Primary,
+            collateral_amount,
+            mint_amount,
+        )
+    }
+
+    fn mint_collateral(
+        previous: &MarginAccount,
+        slot: CollateralSlot,
+        collateral_amount: u64,
+        mint_amount: u64,
+    ) -> Result<()> {
+        let mut margin_account = *previous;
+        margin_account.mint_synthetic_asset(slot, collateral_amount, mint_amount);
+        margin_account.require_healthy(
+            previous,
+            &synthetic_asset(),
+            &prices(),
+            COLLATERAL_RATIO_BPS,
+        )
+    }
+
+    fn burn(previous: &MarginAccount, collateral_amount: u64, burn_amount: u64) -> Result<()> {
+        burn_collateral(
+            previous,
+            CollateralSlot::Primary,
+            collateral_amount,
+            burn_amount,
+        )
+    }
+
+    fn burn_collateral(
+        previous: &MarginAccount,
+        slot: CollateralSlot,
+        collateral_amount: u64,
+        burn_amount: u64,
+    ) -> Result<()> {
+        let mut margin_account = *previous;
+        margin_account.burn_synthetic_asset(slot, collateral_amount, burn_amount);
+        margin_account.require_healthy(
+            previous,
+            &synthetic_asset(),
+            &prices(),
+            COLLATERAL_RATIO_BPS,
+        )
+    }
+
+    #[test]
+    fn mint_healthy_position() {
+        // $300 of collateral against $180 of debt, at 150% requires $270
+        assert!(mint(&margin_account(0, 0), 300_000_000, 100_000_000).is_ok());
+    }
+
+    #[test]
+    fn mint_unhealthy_position() {
+        // $200 of collateral against $180 of debt, at 150% requires $270
+        assert_eq!(
+            mint(&margin_account(0, 0), 200_000_000, 100_000_000).unwrap_err(),
+            error!(Errors::Undercollateralized)
+        );
+    }
+
+    #[test]
+    fn mint_more_debt_while_unhealthy() {
+        assert_eq!(
+            mint(&margin_account(200_000_000, 100_000_000), 0, 1).unwrap_err(),
+            error!(Errors::Undercollateralized)
+        );
+    }
+
+    #[test]
+    fn deposit_collateral_while_unhealthy() {
+        // Still below the collateral ratio, but better than before
+        assert!(mint(&margin_account(200_000_000, 100_000_000), 10_000_000, 0).is_ok());
+    }
+
+    #[test]
+    fn repay_debt_while_unhealthy() {
+        // Still below the collateral ratio, but better than before
+        assert!(burn(&margin_account(200_000_000, 100_000_000), 0, 10_000_000).is_ok());
+    }
+
+    #[test]
+    fn withdraw_collateral_while_unhealthy() {
+        assert_eq!(
+            burn(&margin_account(200_000_000, 100_000_000), 1, 0).unwrap_err(),
+            error!(Errors::Undercollateralized)
+        );
+    }
+
+    #[test]
+    fn repay_and_withdraw_healthy() {
+        // $200 of collateral against $90 of debt, at 150% requires $135
+        assert!(burn(
+            &margin_account(300_000_000, 100_000_000),
+            100_000_000,
+            50_000_000
+        )
+        .is_ok());
+    }
+
+    #[test]
+    fn repay_and_withdraw_unhealthy() {
+        // $100 of collateral against $90 of debt, at 150% requires $135
+        assert_eq!(
+            burn(
+                &margin_account(300_000_000, 100_000_000),
+                200_000_000,
+                50_000_000
+            )
+            .unwrap_err(),
+            error!(Errors::Undercollateralized)
+        );
+    }
+
+    #[test]
+    fn mint_against_collateral_type() {
+        // 30 SOL is $600, counted as $300 at 50% loan to value,
+        // against $180 of debt, at 150% requires $270
+        assert!(mint_collateral(&margin_account(0, 0), SOL, 30_000_000_000, 100_000_000).is_ok());
+    }
+
+    #[test]
+    fn mint_against_collateral_type_haircut() {
+        // 20 SOL is $400, counted as $200 at 50% loan to value,
+        // against $180 of debt, at 150% requires $270
+        assert_eq!(
+            mint_collateral(&margin_account(0, 0), SOL, 20_000_000_000, 100_000_000).unwrap_err(),
+            error!(Errors::Undercollateralized)
+        );
+    }
+
+    #[test]
+    fn collateral_types_are_summed() {
+        // $200 of stablecoin and 10 SOL counted as $100,
+        // against $180 of debt, at 150% requires $270
+        let mut previous = margin_account(200_000_000, 0);
+        previous.collateral_type_deposited[0] = 10_000_000_000;
+
+        assert!(mint(&previous, 0, 100_000_000).is_ok());
+    }
+
+    #[test]
+    fn withdraw_collateral_type() {
+        // 30 SOL counted as $300, against $180 of debt, at 150% requires $270
+        let mut previous = margin_account(0, 100_000_000);
+        previous.collateral_type_deposited[0] = 30_000_000_000;
+
+        let mut margin_account = previous;
+        margin_account.withdraw_collateral(SOL, 1_000_000_000);
+        assert!(margin_account
+            .require_healthy(
+                &previous,
+                &synthetic_asset(),
+                &prices(),
+                COLLATERAL_RATIO_BPS
+            )
+            .is_ok());
+
+        let mut margin_account = previous;
+        margin_account.withdraw_collateral(SOL, 4_000_000_000);
+        assert_eq!(
+            margin_account
+                .require_healthy(
+                    &previous,
+                    &synthetic_asset(),
+                    &prices(),
+                    COLLATERAL_RATIO_BPS
+                )
+                .unwrap_err(),
+            error!(Errors::Undercollateralized)
+        );
+    }
+
+    #[test]
+    fn mint_fee_is_reflected_in_health() {
+        let mut synthetic_asset = synthetic_asset();
+        synthetic_asset.params.mint_fee_bps = 100;
+
+        // A 1% fee on $180 of debt is $1.80 of stablecoin, or 0.09 SOL
+        let fee = synthetic_asset
+            .fee_collateral(&prices(), CollateralSlot::Primary, 100_000_000, 100)
+            .unwrap();
+        assert_eq!(fee, 1_800_000);
+        assert_eq!(
+            synthetic_asset
+                .fee_collateral(&prices(), SOL, 100_000_000, 100)
+                .unwrap(),
+            90_000_000
+        );
+
+        // $270 of collateral is exactly enough before the fee, but not after
+        let previous = margin_account(0, 0);
+        let mut margin_account = previous;
+        margin_account.mint_synthetic_asset(CollateralSlot::Primary, 270_000_000, 100_000_000);
+        margin_account
+            .charge_fee(CollateralSlot::Primary, fee)
+            .unwrap();
+        assert_eq!(
+            margin_account
+                .require_healthy(&previous, &synthetic_asset, &prices(), COLLATERAL_RATIO_BPS)
+                .unwrap_err(),
+            error!(Errors::Undercollateralized)
+        );
+    }
+
+    #[test]
+    fn fee_exceeding_collateral() {
+        let mut margin_account = margin_account(1_000_000, 0);
+        assert_eq!(
+            margin_account
+                .charge_fee(CollateralSlot::Primary, 1_000_001)
+                .unwrap_err(),
+            error!(Errors::InsufficientCollateralForFee)
+        );
+    }
+
+    #[test]
+    fn pool_debt_is_reflected_in_health() {
+        // The pool has $1,000 of debt, and the margin account owns a fifth of it
+        let mut prices = prices();
+        prices.health_pool_debt = PoolDebt {
+            total_debt_value: 1_000_000_000_000_000,
+            total_debt_shares: 5_000,
+        };
+        let mut margin_account = margin_account(300_000_000, 0);
+        margin_account.debt_shares = 1_000;
+
+        // $300 of collateral against $200 of debt
+        assert!(margin_account
+            .verify_healthy(&synthetic_asset(), &prices, COLLATERAL_RATIO_BPS)
+            .unwrap());
+
+        // The other pooled synthetic assets rise, so the pool debt rises too
+        prices.health_pool_debt.total_debt_value = 1_500_000_000_000_000;
+        assert!(!margin_account
+            .verify_healthy(&synthetic_asset(), &prices, COLLATERAL_RATIO_BPS)
+            .unwrap());
+    }
+
+    #[test]
+    fn pool_debt_counts_towards_account_limits() {
+        let mut prices = prices();
+        prices.pool_debt = PoolDebt {
+            total_debt_value: 1_000_000_000_000_000,
+            total_debt_shares: 5_000,
+        };
+        let mut margin_account = margin_account(300_000_000, 0);
+        margin_account.debt_shares = 1_000;
+
+        // $200 of pool debt is 0.111 gold
+        let mut synthetic_asset = synthetic_asset();
+        let debt = margin_account.pool_debt(&synthetic_asset, &prices).unwrap();
+        assert_eq!(debt, 111_111_111);
+
+        synthetic_asset.params.max_account_debt = 100_000_000;
+        assert_eq!(
+            synthetic_asset.verify_account_debt(debt).unwrap_err(),
+            error!(Errors::AccountDebtCapExceeded)
+        );
+        synthetic_asset.params.min_debt = 200_000_000;
+        assert_eq!(
+            synthetic_asset.verify_minimum_debt(debt).unwrap_err(),
+            error!(Errors::DebtBelowMinimum)
+        );
+    }
+
+    #[test]
+    fn pool_repayment_is_limited_to_debt() {
+        let mut prices = prices();
+        prices.pool_debt = PoolDebt {
+            total_debt_value: 1_000_000_000_000_000,
+            total_debt_shares: 5_000,
+        };
+        let mut margin_account = margin_account(300_000_000, 0);
+        margin_account.debt_shares = 1_000;
+
+        // $90 of gold repays $90 of the $200 debt
+        assert_eq!(
+            margin_account
+                .pool_repayment(&synthetic_asset(), &prices, 50_000_000)
+                .unwrap(),
+            (50_000_000, 450)
+        );
+
+        // $360 of gold only burns the $200 debt
+        assert_eq!(
+            margin_account
+                .pool_repayment(&synthetic_asset(), &prices, 200_000_000)
+                .unwrap(),
+            (111_111_111, 1_000)
+        );
+    }
+
+    /// Liquidations repay up to half of the debt, with a 5% bonus
+    fn liquidated_synthetic_asset() -> SyntheticAsset {
+        let mut synthetic_asset = synthetic_asset();
+        synthetic_asset.params.liquidation_close_factor_bps = 5_000;
+        synthetic_asset.params.liquidation_bonus_bps = 500;
+        synthetic_asset
+    }
+
+    #[test]
+    fn liquidation_is_limited_by_close_factor() {
+        let mut synthetic_asset = liquidated_synthetic_asset();
+
+        // Rounded up, so that dust debt can always be repaid
+        assert_eq!(
+            margin_account(300_000_000, 1)
+                .max_liquidation_amount(&synthetic_asset)
+                .unwrap(),
+            1
+        );
+
+        let margin_account = margin_account(300_000_000, 150_000_000);
+        assert_eq!(
+            margin_account
+                .max_liquidation_amount(&synthetic_asset)
+                .unwrap(),
+            75_000_000
+        );
+
+        // Half of the debt would leave less than the minimum debt, so all of it can be repaid
+        synthetic_asset.params.min_debt = 100_000_000;
+        assert_eq!(
+            margin_account
+                .max_liquidation_amount(&synthetic_asset)
+                .unwrap(),
+            150_000_000
+        );
+    }
+
+    #[test]
+    fn liquidation_pays_bonus() {
+        let mut margin_account = margin_account(300_000_000, 150_000_000);
+        margin_account.collateral_type_deposited[0] = 10_000_000_000;
+
+        // $135 of gold is paid for with $141.75 of stablecoin, or 7.0875 SOL
+        assert_eq!(
+            margin_account
+                .liquidation_collateral(
+                    &liquidated_synthetic_asset(),
+                    &prices(),
+                    CollateralSlot::Primary,
+                    75_000_000
+                )
+                .unwrap(),
+            (75_000_000, 141_750_000)
+        );
+        assert_eq!(
+            margin_account
+                .liquidation_collateral(&liquidated_synthetic_asset(), &prices(), SOL, 75_000_000)
+                .unwrap(),
+            (75_000_000, 7_087_500_000)
+        );
+    }
+
+    #[test]
+    fn liquidation_pays_at_aggregate_price() {
+        // Publishers disagree, and health checks value the gold at $1,900.00
+        let mut adjusted_prices = prices();
+        adjusted_prices.synthetic_health_price.price = 190_000;
+
+        // $330 of collateral covers $270 of debt at 122%, but $285 only at 116%
+        let margin_account = margin_account(330_000_000, 150_000_000);
+        assert!(margin_account
+            .verify_healthy(&liquidated_synthetic_asset(), &prices(), 12_000)
+            .unwrap());
+        assert!(!margin_account
+            .verify_healthy(&liquidated_synthetic_asset(), &adjusted_prices, 12_000)
+            .unwrap());
+
+        // The liquidator is paid for the debt at $1,800.00, not the adjusted price
+        assert_eq!(
+            margin_account
+                .liquidation_collateral(
+                    &liquidated_synthetic_asset(),
+                    &adjusted_prices,
+                    CollateralSlot::Primary,
+                    75_000_000
+                )
+                .unwrap(),
+            (75_000_000, 141_750_000)
+        );
+    }
+
+    #[test]
+    fn liquidation_is_limited_by_collateral() {
+        // $100 of stablecoin only covers $95.24 of debt with the bonus on top
+        let margin_account = margin_account(100_000_000, 150_000_000);
+        assert_eq!(
+            margin_account
+                .liquidation_collateral(
+                    &liquidated_synthetic_asset(),
+                    &prices(),
+                    CollateralSlot::Primary,
+                    75_000_000
+                )
+                .unwrap(),
+            (52_910_052, 100_000_000)
+        );
+    }
+
+    #[test]
+    fn settle_from_primary_collateral() {
+        let margin_account = margin_account(300_000_000, 100_000_000);
+
+        // $180 of debt is covered by the primary collateral
+        assert_eq!(
+            margin_account
+                .settlement_collateral(&synthetic_asset(), &prices())
+                .unwrap(),
+            vec![(CollateralSlot::Primary, 180_000_000)]
+        );
+    }
+
+    #[test]
+    fn settle_from_registered_collateral() {
+        let mut margin_account = margin_account(100_000_000, 100_000_000);
+        margin_account.collateral_type_deposited[0] = 10_000_000_000;
+
+        // $100 of primary collateral, then $80 of SOL
+        assert_eq!(
+            margin_account
+                .settlement_collateral(&synthetic_asset(), &prices())
+                .unwrap(),
+            vec![
+                (CollateralSlot::Primary, 100_000_000),
+                (CollateralSlot::Registered(0), 4_000_000_000),
+            ]
+        );
+    }
+
+    #[test]
+    fn redeem_at_oracle_price() {
+        let margin_account = margin_account(300_000_000, 100_000_000);
+
+        // 0.05 gold is worth $90
+        assert_eq!(
+            margin_account
+                .redemption(&synthetic_asset(), &prices(), 50_000_000)
+                .unwrap(),
+            (50_000_000, 90_000_000)
+        );
+
+        // Never more than the debt
+        assert_eq!(
+            margin_account
+                .redemption(&synthetic_asset(), &prices(), 1_000_000_000)
+                .unwrap(),
+            (100_000_000, 180_000_000)
+        );
+    }
+
+    #[test]
+    fn redeem_leaves_minimum_debt() {
+        let mut synthetic_asset = synthetic_asset();
+        synthetic_asset.params.min_debt = 80_000_000;
+        let margin_account = margin_account(300_000_000, 100_000_000);
+
+        assert_eq!(
+            margin_account
+                .redemption(&synthetic_asset, &prices(), 50_000_000)
+                .unwrap(),
+            (20_000_000, 36_000_000)
+        );
     }
 }
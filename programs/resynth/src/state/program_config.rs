@@ -0,0 +1,69 @@
+use anchor_lang::prelude::*;
+
+use crate::Errors;
+
+/// The maximum number of oracle programs the program config allows
+pub const MAX_ORACLE_PROGRAMS: usize = 4;
+
+/// Program-wide settings of a deployment. Each cluster has its own config,
+/// so it lists the oracle program IDs deployed to that cluster.
+#[account(zero_copy)]
+pub struct ProgramConfig {
+    /// The admin that can update the program config
+    pub admin: Pubkey,
+    /// The programs whose price accounts can price synthetic assets, followed by default pubkeys
+    pub oracle_programs: [Pubkey; MAX_ORACLE_PROGRAMS],
+}
+
+impl ProgramConfig {
+    /// Replace the allowed oracle programs
+    pub fn set_oracle_programs(&mut self, oracle_programs: &[Pubkey]) -> Result<()> {
+        require!(
+            oracle_programs.len() <= MAX_ORACLE_PROGRAMS
+                && !oracle_programs.contains(&Pubkey::default()),
+            Errors::InvalidOraclePrograms
+        );
+
+        self.oracle_programs = [Pubkey::default(); MAX_ORACLE_PROGRAMS];
+        self.oracle_programs[..oracle_programs.len()].copy_from_slice(oracle_programs);
+
+        Ok(())
+    }
+
+    /// Whether price accounts of a program can price synthetic assets
+    pub fn is_oracle_program(&self, program: &Pubkey) -> bool {
+        *program != Pubkey::default() && self.oracle_programs.contains(program)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn only_listed_oracle_programs_are_allowed() {
+        let mut program_config = ProgramConfig {
+            admin: Pubkey::default(),
+            oracle_programs: [Pubkey::default(); MAX_ORACLE_PROGRAMS],
+        };
+        let pyth = Pubkey::new_unique();
+        let lookalike = Pubkey::new_unique();
+
+        program_config.set_oracle_programs(&[pyth]).unwrap();
+        assert!(program_config.is_oracle_program(&pyth));
+        assert!(!program_config.is_oracle_program(&lookalike));
+
+        // Unused slots never allow accounts owned by the system program
+        assert!(!program_config.is_oracle_program(&Pubkey::default()));
+        assert!(program_config
+            .set_oracle_programs(&[pyth, Pubkey::default()])
+            .is_err());
+        assert!(program_config
+            .set_oracle_programs(&[pyth; MAX_ORACLE_PROGRAMS + 1])
+            .is_err());
+
+        // Replacing the list drops programs that are no longer listed
+        program_config.set_oracle_programs(&[lookalike]).unwrap();
+        assert!(!program_config.is_oracle_program(&pyth));
+    }
+}
@@ -1,16 +1,96 @@
 use anchor_lang::prelude::*;
+use pyth_sdk_solana::Price;
 
-use crate::seeds;
+use crate::{
+    seeds, token_amount, usd_value, AssetPrices, CollateralSlot, CollateralType, Errors,
+    MAX_COLLATERAL_TYPES,
+};
+
+/// The denominator of all parameters expressed in basis points
+pub const BASIS_POINTS_DENOMINATOR: u64 = 10_000;
+
+/// The borrow index before any stability fee has accrued
+pub const BORROW_INDEX_ONE: u64 = 1_000_000_000_000;
+
+/// The maximum length of a synthetic asset symbol, which is a PDA seed
+pub const MAX_SYMBOL_LENGTH: usize = 32;
+
+/// The number of seconds the stability fee is annualized over
+pub const SECONDS_PER_YEAR: u64 = 365 * 24 * 60 * 60;
 
 #[account(zero_copy)]
+#[derive(Default)]
 pub struct SyntheticAsset {
     pub synthetic_asset: Pubkey,
     pub collateral_mint: Pubkey,
     pub collateral_vault: Pubkey,
     pub synthetic_mint: Pubkey,
     pub synthetic_oracle: Pubkey,
+    /// The program owning the synthetic oracle price feed
+    pub oracle_program: Pubkey,
     pub asset_authority: Pubkey,
+    /// The admin that can update parameters of the synthetic asset
+    pub admin: Pubkey,
+    /// The proposed new admin, who must accept before becoming the admin
+    pub pending_admin: Pubkey,
+    /// The debt pool sharing the debt of this synthetic asset,
+    /// or the default pubkey if its margin accounts owe synthetic amounts
+    pub debt_pool: Pubkey,
+    /// The symbol the synthetic asset address is derived from, zero padded.
+    /// Synthetic assets initialized before symbols were derived from their first oracle
+    pub symbol: [u8; MAX_SYMBOL_LENGTH],
+    /// Risk parameters, updatable by the admin
+    pub params: SyntheticAssetParams,
+    /// Collateral accepted in addition to the primary collateral mint
+    pub collateral_types: [CollateralType; MAX_COLLATERAL_TYPES],
+    /// The cumulative stability fee index. Debt is stored divided by this index
+    pub borrow_index: u64,
+    /// The unix timestamp the borrow index was last updated
+    pub last_accrual_time: i64,
+    /// The sum of the normalized debt of all margin accounts
+    pub total_normalized_debt: u64,
+    /// Stability fees accrued but not yet minted to the treasury
+    pub accrued_fees: u64,
+    /// The synthetic debt owed to the protocol, including the accrued stability fee.
+    /// The synthetic supply plus the uncollected fees never exceed it
+    pub total_debt: u64,
+    /// The primary collateral deposited by all margin accounts, which the vault must hold
+    pub total_collateral: u64,
+    /// The number of margin accounts open on the synthetic asset
+    pub margin_account_count: u64,
+    /// The primary collateral set aside in the vault for synthetic holders after a shutdown
+    pub settlement_collateral: u64,
+    /// The final price of the synthetic asset recorded at shutdown, with `settlement_expo`
+    pub settlement_price: i64,
+    /// The last oracle price of the synthetic asset the circuit breaker accepted
+    pub last_price: i64,
+    /// The slot `last_price` was accepted in
+    pub last_price_slot: u64,
+    /// The slot the circuit breaker cooldown ends in, after a price moved too fast
+    pub cooldown_end_slot: u64,
     pub asset_authority_bump: [u8; 1],
+    /// The decimals of the primary collateral mint
+    pub collateral_decimals: u8,
+    /// The decimals of the synthetic mint
+    pub synthetic_decimals: u8,
+    /// The `SyntheticAssetStatus` of the synthetic asset
+    pub status: u8,
+    /// The exponent of the settlement price
+    pub settlement_expo: i32,
+}
+
+/// The lifecycle of a synthetic asset
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum SyntheticAssetStatus {
+    /// Margin accounts can mint the synthetic asset
+    Active = 0,
+    /// Minting is disabled so that holders and margin accounts can exit
+    /// before the synthetic asset is closed
+    WindingDown = 1,
+    /// Minting is frozen and debt is settled at a final price, after which
+    /// holders redeem their synthetic tokens for the settled collateral
+    Shutdown = 2,
 }
 
 impl SyntheticAsset {
@@ -21,4 +101,770 @@ impl SyntheticAsset {
             self.asset_authority_bump.as_ref(),
         ]
     }
+
+    /// Find the collateral held by a vault of this synthetic asset
+    pub fn collateral_slot(&self, vault: &Pubkey) -> Result<CollateralSlot> {
+        if *vault == self.collateral_vault {
+            return Ok(CollateralSlot::Primary);
+        }
+
+        self.collateral_types
+            .iter()
+            .position(|collateral_type| {
+                collateral_type.is_registered() && collateral_type.vault == *vault
+            })
+            .map(CollateralSlot::Registered)
+            .ok_or(error!(Errors::InvalidCollateral))
+    }
+
+    /// The program that must own an oracle price feed of the synthetic asset
+    pub fn oracle_program(&self, oracle: &Pubkey) -> Result<Pubkey> {
+        if *oracle == self.synthetic_oracle {
+            return Ok(self.oracle_program);
+        }
+
+        self.collateral_types
+            .iter()
+            .find(|collateral_type| {
+                collateral_type.is_registered() && collateral_type.oracle == *oracle
+            })
+            .map(|collateral_type| collateral_type.oracle_program)
+            .ok_or(error!(Errors::InvalidOracle))
+    }
+
+    /// Whether margin accounts can mint the synthetic asset
+    pub fn is_active(&self) -> bool {
+        self.status == SyntheticAssetStatus::Active as u8
+    }
+
+    /// Whether the synthetic asset was shut down, settling all debt at the settlement price
+    pub fn is_shutdown(&self) -> bool {
+        self.status == SyntheticAssetStatus::Shutdown as u8
+    }
+
+    /// The final price of the synthetic asset recorded at shutdown
+    pub fn settlement_price(&self) -> Price {
+        Price {
+            price: self.settlement_price,
+            conf: 0,
+            expo: self.settlement_expo,
+            publish_time: 0,
+        }
+    }
+
+    /// Record the aggregate oracle price of the synthetic asset, tripping the circuit breaker
+    /// when it moved further from the last accepted price than the slots since then allow.
+    /// Every print becomes the new reference, so the cooldown only ends on its own
+    /// once the price stops jumping for the whole cooldown.
+    pub fn observe_price(&mut self, price: &Price, slot: u64) {
+        let max_change_bps_per_slot = self.params.max_price_change_bps_per_slot;
+        if max_change_bps_per_slot > 0 && self.last_price > 0 {
+            let elapsed_slots = slot.saturating_sub(self.last_price_slot).max(1);
+            let max_change_bps = u128::from(max_change_bps_per_slot) * u128::from(elapsed_slots);
+
+            // |price - last_price| / last_price > max_change_bps / 10_000, without the division
+            let change = u128::from(price.price.abs_diff(self.last_price));
+            let last_price = u128::from(self.last_price.unsigned_abs());
+            if change * u128::from(BASIS_POINTS_DENOMINATOR) > max_change_bps * last_price {
+                self.cooldown_end_slot =
+                    slot.saturating_add(self.params.circuit_breaker_cooldown_slots);
+            }
+        }
+
+        self.last_price = price.price;
+        self.last_price_slot = slot;
+    }
+
+    /// Verify the circuit breaker is not cooling down, before minting or withdrawing.
+    /// Failing reverts the whole transaction, including a trip it just observed, so a trip
+    /// only persists through the `update_circuit_breaker` crank or an instruction that
+    /// succeeds anyway, like a liquidation.
+    pub fn verify_price_stable(&self, slot: u64) -> Result<()> {
+        require!(
+            slot >= self.cooldown_end_slot,
+            Errors::CircuitBreakerCooldown
+        );
+        Ok(())
+    }
+
+    /// Switch the synthetic asset to a new oracle price feed. The circuit breaker
+    /// has no reference price from the new feed yet, so it accepts its first price.
+    pub fn set_oracle(&mut self, synthetic_oracle: Pubkey, oracle_program: Pubkey) {
+        self.synthetic_oracle = synthetic_oracle;
+        self.oracle_program = oracle_program;
+        self.last_price = 0;
+        self.last_price_slot = 0;
+    }
+
+    /// Accept an oracle price of the synthetic asset as the admin, ending any cooldown
+    pub fn confirm_price(&mut self, price: &Price, slot: u64) {
+        self.last_price = price.price;
+        self.last_price_slot = slot;
+        self.cooldown_end_slot = 0;
+    }
+
+    /// Every collateral the synthetic asset accepts, primary collateral first
+    pub fn collateral_slots(&self) -> impl Iterator<Item = CollateralSlot> {
+        std::iter::once(CollateralSlot::Primary)
+            .chain((0..self.collateral_type_count()).map(CollateralSlot::Registered))
+    }
+
+    /// Whether nothing is owed to or by the synthetic asset anymore, so it can be closed
+    /// without confiscating collateral. Uncollected stability fees are forfeited.
+    pub fn is_wound_down(&self, synthetic_supply: u64) -> bool {
+        synthetic_supply == 0
+            && self.total_normalized_debt == 0
+            && self.total_collateral == 0
+            && self
+                .collateral_types
+                .iter()
+                .all(|collateral_type| collateral_type.total_collateral == 0)
+    }
+
+    /// Whether the synthetic asset shares a debt pool
+    pub fn is_pooled(&self) -> bool {
+        self.debt_pool != Pubkey::default()
+    }
+
+    /// The number of registered collateral types, which are registered in order
+    pub fn collateral_type_count(&self) -> usize {
+        self.collateral_types
+            .iter()
+            .filter(|collateral_type| collateral_type.is_registered())
+            .count()
+    }
+
+    /// Accrue the stability fee on all outstanding debt, up to `current_time`
+    pub fn accrue_stability_fee(&mut self, current_time: i64) -> Result<()> {
+        // Debt stops growing once it is settled at the settlement price
+        let elapsed = current_time.saturating_sub(self.last_accrual_time);
+        if elapsed <= 0 || self.is_shutdown() {
+            return Ok(());
+        }
+
+        /*
+         * The index grows by the annual fee, pro rata to the time elapsed
+         *  borrow_index * (1 + stability_fee_bps / 10_000 * elapsed / seconds_per_year)
+         *
+         * Compounding happens between interactions with the synthetic asset
+         */
+        let index_increase = u128::from(self.borrow_index)
+            .checked_mul(u128::from(self.params.stability_fee_bps))
+            .and_then(|value| value.checked_mul(elapsed as u128))
+            .ok_or(Errors::MathOverflow)?
+            / u128::from(BASIS_POINTS_DENOMINATOR * SECONDS_PER_YEAR);
+        let borrow_index = u64::try_from(u128::from(self.borrow_index) + index_increase)
+            .map_err(|_| Errors::MathOverflow)?;
+
+        // The fees are the growth of the total debt
+        let previous_debt = normalized_to_debt(self.total_normalized_debt, self.borrow_index)?;
+        let current_debt = normalized_to_debt(self.total_normalized_debt, borrow_index)?;
+
+        self.borrow_index = borrow_index;
+        self.last_accrual_time = current_time;
+        self.accrued_fees = self
+            .accrued_fees
+            .checked_add(current_debt - previous_debt)
+            .ok_or(Errors::MathOverflow)?;
+        self.total_debt = self
+            .total_debt
+            .checked_add(current_debt - previous_debt)
+            .ok_or(Errors::MathOverflow)?;
+
+        Ok(())
+    }
+
+    /// The synthetic debt of a normalized debt amount, rounded up
+    pub fn debt_amount(&self, normalized_debt: u64) -> Result<u64> {
+        debt_amount(normalized_debt, self.borrow_index)
+    }
+
+    /// Verify the debt left in a margin account is either repaid in full,
+    /// or large enough to be worth liquidating
+    pub fn verify_minimum_debt(&self, debt: u64) -> Result<()> {
+        require!(
+            debt == 0 || debt >= self.params.min_debt,
+            Errors::DebtBelowMinimum
+        );
+
+        Ok(())
+    }
+
+    /// Verify the debt of a margin account after minting is within the
+    /// per-account cap, and large enough to be worth liquidating
+    pub fn verify_account_debt(&self, debt: u64) -> Result<()> {
+        self.verify_minimum_debt(debt)?;
+        require!(
+            self.params.max_account_debt == 0 || debt <= self.params.max_account_debt,
+            Errors::AccountDebtCapExceeded
+        );
+
+        Ok(())
+    }
+
+    /// Record `amount` of new synthetic debt.
+    /// Returns the normalized debt, rounded up in favor of the protocol.
+    pub fn borrow(&mut self, amount: u64) -> Result<u64> {
+        let normalized_amount = (u128::from(amount) * u128::from(BORROW_INDEX_ONE)
+            + u128::from(self.borrow_index - 1))
+            / u128::from(self.borrow_index);
+        let normalized_amount =
+            u64::try_from(normalized_amount).map_err(|_| Errors::MathOverflow)?;
+
+        self.issue_debt(amount)?;
+        self.total_normalized_debt += normalized_amount;
+
+        Ok(normalized_amount)
+    }
+
+    /// Record `amount` of repaid synthetic debt.
+    /// Returns the normalized debt, rounded down in favor of the protocol.
+    pub fn repay(&mut self, amount: u64) -> Result<u64> {
+        let normalized_amount =
+            u128::from(amount) * u128::from(BORROW_INDEX_ONE) / u128::from(self.borrow_index);
+        let normalized_amount =
+            u64::try_from(normalized_amount).map_err(|_| Errors::MathOverflow)?;
+
+        self.total_normalized_debt -= normalized_amount;
+        self.retire_debt(amount)?;
+
+        Ok(normalized_amount)
+    }
+
+    /// Record `amount` of synthetic asset minted, within the debt ceiling
+    pub fn issue_debt(&mut self, amount: u64) -> Result<()> {
+        require!(self.is_active(), Errors::SyntheticAssetNotActive);
+
+        // The ceiling includes the stability fee accrued on existing debt
+        let total_debt = self
+            .total_debt
+            .checked_add(amount)
+            .ok_or(Errors::MathOverflow)?;
+        require!(
+            total_debt <= self.params.debt_ceiling,
+            Errors::DebtCeilingExceeded
+        );
+
+        self.total_debt = total_debt;
+
+        Ok(())
+    }
+
+    /// Record `amount` of synthetic asset burned
+    pub fn retire_debt(&mut self, amount: u64) -> Result<()> {
+        self.total_debt = self
+            .total_debt
+            .checked_sub(amount)
+            .ok_or(Errors::MathOverflow)?;
+
+        Ok(())
+    }
+
+    /// Record collateral transferred into a vault of this synthetic asset
+    pub fn deposit_collateral(&mut self, slot: CollateralSlot, amount: u64) {
+        match slot {
+            CollateralSlot::Primary => self.total_collateral += amount,
+            CollateralSlot::Registered(index) => {
+                self.collateral_types[index].total_collateral += amount
+            }
+        }
+    }
+
+    /// Record collateral transferred out of a vault of this synthetic asset
+    pub fn withdraw_collateral(&mut self, slot: CollateralSlot, amount: u64) {
+        match slot {
+            CollateralSlot::Primary => self.total_collateral -= amount,
+            CollateralSlot::Registered(index) => {
+                self.collateral_types[index].total_collateral -= amount
+            }
+        }
+    }
+
+    /// Remove normalized debt settled at the settlement price. The synthetic supply
+    /// stays owed until holders redeem it for the settlement collateral.
+    pub fn settle_debt(&mut self, normalized_amount: u64) {
+        self.total_normalized_debt -= normalized_amount;
+    }
+
+    /// Set aside collateral in a vault for synthetic holders
+    pub fn deposit_settlement_collateral(&mut self, slot: CollateralSlot, amount: u64) {
+        match slot {
+            CollateralSlot::Primary => self.settlement_collateral += amount,
+            CollateralSlot::Registered(index) => {
+                self.collateral_types[index].settlement_collateral += amount
+            }
+        }
+    }
+
+    /// Pay out collateral set aside for synthetic holders
+    pub fn withdraw_settlement_collateral(&mut self, slot: CollateralSlot, amount: u64) {
+        match slot {
+            CollateralSlot::Primary => self.settlement_collateral -= amount,
+            CollateralSlot::Registered(index) => {
+                self.collateral_types[index].settlement_collateral -= amount
+            }
+        }
+    }
+
+    /// The collateral set aside in a vault for synthetic holders
+    pub fn settlement_collateral(&self, slot: CollateralSlot) -> u64 {
+        match slot {
+            CollateralSlot::Primary => self.settlement_collateral,
+            CollateralSlot::Registered(index) => self.collateral_types[index].settlement_collateral,
+        }
+    }
+
+    /// The settlement collateral a holder redeeming `amount` out of the
+    /// `synthetic_supply` receives, pro rata and rounded down
+    pub fn settlement_share(
+        &self,
+        slot: CollateralSlot,
+        amount: u64,
+        synthetic_supply: u64,
+    ) -> Result<u64> {
+        let share = u128::from(self.settlement_collateral(slot))
+            .checked_mul(u128::from(amount))
+            .ok_or(Errors::MathOverflow)?
+            / u128::from(synthetic_supply);
+
+        u64::try_from(share).map_err(|_| error!(Errors::MathOverflow))
+    }
+
+    /// The collateral a vault of this synthetic asset must hold,
+    /// for margin accounts and for synthetic holders after a shutdown
+    pub fn owed_collateral(&self, slot: CollateralSlot) -> Result<u64> {
+        self.total_collateral(slot)
+            .checked_add(self.settlement_collateral(slot))
+            .ok_or(error!(Errors::MathOverflow))
+    }
+
+    /// The collateral deposited by all margin accounts in a vault of this synthetic asset
+    pub fn total_collateral(&self, slot: CollateralSlot) -> u64 {
+        match slot {
+            CollateralSlot::Primary => self.total_collateral,
+            CollateralSlot::Registered(index) => self.collateral_types[index].total_collateral,
+        }
+    }
+
+    /// The fee in collateral for minting or burning `synthetic_amount`,
+    /// charged at `fee_bps` of the oracle value of the synthetic asset
+    pub fn fee_collateral(
+        &self,
+        prices: &AssetPrices,
+        slot: CollateralSlot,
+        synthetic_amount: u64,
+        fee_bps: u64,
+    ) -> Result<u64> {
+        let fee_value = usd_value(
+            synthetic_amount,
+            self.synthetic_decimals,
+            &prices.synthetic_price,
+        )?
+        .checked_mul(u128::from(fee_bps))
+        .ok_or(Errors::MathOverflow)?
+            / u128::from(BASIS_POINTS_DENOMINATOR);
+
+        token_amount(
+            fee_value,
+            self.collateral_decimals(slot),
+            &prices.collateral_price(slot),
+        )
+    }
+
+    /// The decimals of a collateral mint of this synthetic asset
+    pub fn collateral_decimals(&self, slot: CollateralSlot) -> u8 {
+        match slot {
+            CollateralSlot::Primary => self.collateral_decimals,
+            CollateralSlot::Registered(index) => self.collateral_types[index].decimals,
+        }
+    }
+}
+
+/// The synthetic debt of a normalized debt amount at `borrow_index`, rounded up
+pub fn debt_amount(normalized_debt: u64, borrow_index: u64) -> Result<u64> {
+    let debt = (u128::from(normalized_debt) * u128::from(borrow_index)
+        + u128::from(BORROW_INDEX_ONE - 1))
+        / u128::from(BORROW_INDEX_ONE);
+
+    u64::try_from(debt).map_err(|_| error!(Errors::MathOverflow))
+}
+
+/// The synthetic debt of a normalized debt amount at `borrow_index`, rounded down
+fn normalized_to_debt(normalized_debt: u64, borrow_index: u64) -> Result<u64> {
+    let debt =
+        u128::from(normalized_debt) * u128::from(borrow_index) / u128::from(BORROW_INDEX_ONE);
+
+    u64::try_from(debt).map_err(|_| error!(Errors::MathOverflow))
+}
+
+/// Which oracle price health checks value margin accounts of a synthetic asset at.
+/// Tokens always change hands at the aggregate price.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PricingPolicy {
+    /// The latest aggregate price
+    Spot = 0,
+    /// The exponentially-weighted moving average price, which a single-slot wick barely moves
+    Ema = 1,
+    /// Whichever of the spot and EMA prices is less favorable to the margin account
+    Conservative = 2,
+}
+
+impl TryFrom<u64> for PricingPolicy {
+    type Error = Error;
+
+    fn try_from(pricing_policy: u64) -> Result<Self> {
+        match pricing_policy {
+            0 => Ok(PricingPolicy::Spot),
+            1 => Ok(PricingPolicy::Ema),
+            2 => Ok(PricingPolicy::Conservative),
+            _ => err!(Errors::InvalidOracleParameters),
+        }
+    }
+}
+
+/// Risk parameters of a synthetic asset
+#[derive(AnchorSerialize, AnchorDeserialize, Copy, Clone, Default)]
+pub struct SyntheticAssetParams {
+    /// The collateral ratio required to mint or withdraw, in basis points
+    pub initial_collateral_ratio_bps: u64,
+    /// The collateral ratio below which a margin account can be liquidated, in basis points
+    pub maintenance_collateral_ratio_bps: u64,
+    /// The collateral bonus paid to liquidators, in basis points of the repaid debt value
+    pub liquidation_bonus_bps: u64,
+    /// The maximum portion of a margin account's debt that can be repaid
+    /// in a single liquidation, in basis points
+    pub liquidation_close_factor_bps: u64,
+    /// The maximum age of an oracle price, in seconds
+    pub max_oracle_age: u64,
+    /// How long the last trading price of a halted feed can still repay or liquidate debt, in seconds
+    pub halted_price_grace_period: u64,
+    /// The maximum oracle confidence interval, in basis points of the price
+    pub max_oracle_confidence_bps: u64,
+    /// The `PricingPolicy` choosing between the spot and EMA oracle prices
+    pub pricing_policy: u64,
+    /// How far the synthetic price can move per slot before the circuit breaker trips,
+    /// in basis points, or zero to disable the circuit breaker
+    pub max_price_change_bps_per_slot: u64,
+    /// How many slots minting and withdrawing stay disabled after the circuit breaker trips
+    pub circuit_breaker_cooldown_slots: u64,
+    /// How many confidence intervals oracle prices are moved against margin accounts, in basis points
+    pub confidence_multiplier_bps: u64,
+    /// The annual stability fee charged on synthetic debt, in basis points
+    pub stability_fee_bps: u64,
+    /// The fee charged in collateral when minting, in basis points of the value minted
+    pub mint_fee_bps: u64,
+    /// The fee charged in collateral when burning, in basis points of the value burned
+    pub burn_fee_bps: u64,
+    /// The fee charged in collateral when redeeming, in basis points of the collateral redeemed
+    pub redemption_fee_bps: u64,
+    /// The maximum total debt of the synthetic asset, including the accrued stability fee
+    pub debt_ceiling: u64,
+    /// The maximum synthetic debt of a single margin account, or zero for no cap
+    pub max_account_debt: u64,
+    /// The minimum synthetic debt of a margin account, so positions stay worth liquidating
+    pub min_debt: u64,
+}
+
+impl SyntheticAssetParams {
+    pub fn validate(&self) -> Result<()> {
+        // Debt must always be overcollateralized, and a freshly minted
+        // position must not be immediately liquidatable
+        require!(
+            self.maintenance_collateral_ratio_bps > BASIS_POINTS_DENOMINATOR,
+            Errors::InvalidCollateralRatios
+        );
+        require!(
+            self.initial_collateral_ratio_bps >= self.maintenance_collateral_ratio_bps,
+            Errors::InvalidCollateralRatios
+        );
+
+        // A liquidation must repay some debt, but never more than all of it
+        require!(
+            self.liquidation_close_factor_bps > 0
+                && self.liquidation_close_factor_bps <= BASIS_POINTS_DENOMINATOR,
+            Errors::InvalidLiquidationParameters
+        );
+        require!(
+            self.liquidation_bonus_bps <= BASIS_POINTS_DENOMINATOR,
+            Errors::InvalidLiquidationParameters
+        );
+
+        // Prices must be recent, and the confidence can't exceed the price itself
+        require!(self.max_oracle_age > 0, Errors::InvalidOracleParameters);
+        require!(
+            self.max_oracle_confidence_bps <= BASIS_POINTS_DENOMINATOR,
+            Errors::InvalidOracleParameters
+        );
+        PricingPolicy::try_from(self.pricing_policy)?;
+
+        // The stability fee can't exceed the debt itself each year
+        require!(
+            self.stability_fee_bps <= BASIS_POINTS_DENOMINATOR,
+            Errors::InvalidFeeParameters
+        );
+        require!(
+            self.mint_fee_bps <= BASIS_POINTS_DENOMINATOR
+                && self.burn_fee_bps <= BASIS_POINTS_DENOMINATOR
+                && self.redemption_fee_bps <= BASIS_POINTS_DENOMINATOR,
+            Errors::InvalidFeeParameters
+        );
+
+        // A margin account must be able to borrow the minimum debt
+        require!(
+            self.max_account_debt == 0 || self.max_account_debt >= self.min_debt,
+            Errors::InvalidDebtLimits
+        );
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A synthetic asset with a 10% stability fee and 1,000 tokens of debt
+    fn synthetic_asset() -> SyntheticAsset {
+        let mut synthetic_asset = SyntheticAsset {
+            borrow_index: BORROW_INDEX_ONE,
+            params: SyntheticAssetParams {
+                stability_fee_bps: 1_000,
+                debt_ceiling: u64::MAX,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        synthetic_asset.borrow(1_000_000_000_000).unwrap();
+        synthetic_asset
+    }
+
+    #[test]
+    fn accrue_one_year() {
+        let mut synthetic_asset = synthetic_asset();
+        synthetic_asset
+            .accrue_stability_fee(SECONDS_PER_YEAR as i64)
+            .unwrap();
+
+        assert_eq!(synthetic_asset.borrow_index, 1_100_000_000_000);
+        assert_eq!(synthetic_asset.accrued_fees, 100_000_000_000);
+        assert_eq!(synthetic_asset.total_debt, 1_100_000_000_000);
+        assert_eq!(
+            synthetic_asset
+                .debt_amount(synthetic_asset.total_normalized_debt)
+                .unwrap(),
+            1_100_000_000_000
+        );
+    }
+
+    #[test]
+    fn accrue_in_the_past_is_ignored() {
+        let mut synthetic_asset = synthetic_asset();
+        synthetic_asset.last_accrual_time = 100;
+        synthetic_asset.accrue_stability_fee(50).unwrap();
+
+        assert_eq!(synthetic_asset.borrow_index, BORROW_INDEX_ONE);
+        assert_eq!(synthetic_asset.accrued_fees, 0);
+    }
+
+    #[test]
+    fn retire_more_than_total_debt() {
+        let mut synthetic_asset = synthetic_asset();
+        assert_eq!(
+            synthetic_asset.retire_debt(1_000_000_000_001).unwrap_err(),
+            error!(Errors::MathOverflow)
+        );
+    }
+
+    #[test]
+    fn repay_full_debt_after_accrual() {
+        let mut synthetic_asset = synthetic_asset();
+        synthetic_asset.accrue_stability_fee(12_345_678).unwrap();
+
+        // Borrowing and repaying the same debt leaves no normalized debt behind
+        let normalized_debt = synthetic_asset.borrow(333_333_333).unwrap();
+        let debt = synthetic_asset.debt_amount(normalized_debt).unwrap();
+        assert!(debt >= 333_333_333);
+        assert_eq!(synthetic_asset.repay(debt).unwrap(), normalized_debt);
+
+        // The supply minted and the fees accrued are all owed, less the supply burned
+        assert_eq!(
+            synthetic_asset.total_debt,
+            1_000_000_000_000 + synthetic_asset.accrued_fees + 333_333_333 - debt
+        );
+    }
+
+    #[test]
+    fn debt_ceiling_includes_accrued_fees() {
+        let mut synthetic_asset = synthetic_asset();
+        synthetic_asset.params.debt_ceiling = 1_200_000_000_000;
+        synthetic_asset
+            .accrue_stability_fee(SECONDS_PER_YEAR as i64)
+            .unwrap();
+
+        // 1,100 tokens of debt after a year leaves room for 100 more
+        assert_eq!(
+            synthetic_asset.borrow(100_000_000_001).unwrap_err(),
+            error!(Errors::DebtCeilingExceeded)
+        );
+        synthetic_asset.borrow(100_000_000_000).unwrap();
+    }
+
+    #[test]
+    fn account_debt_limits() {
+        let mut synthetic_asset = synthetic_asset();
+        synthetic_asset.params.min_debt = 100;
+        synthetic_asset.params.max_account_debt = 1_000;
+
+        synthetic_asset.verify_account_debt(0).unwrap();
+        synthetic_asset.verify_account_debt(1_000).unwrap();
+        assert_eq!(
+            synthetic_asset.verify_account_debt(99).unwrap_err(),
+            error!(Errors::DebtBelowMinimum)
+        );
+        assert_eq!(
+            synthetic_asset.verify_account_debt(1_001).unwrap_err(),
+            error!(Errors::AccountDebtCapExceeded)
+        );
+
+        // Repaying never runs into the per-account cap
+        synthetic_asset.verify_minimum_debt(1_001).unwrap();
+    }
+
+    #[test]
+    fn wound_down_once_nothing_is_owed() {
+        let mut synthetic_asset = synthetic_asset();
+        assert!(!synthetic_asset.is_wound_down(0));
+
+        // Uncollected fees don't keep the synthetic asset open
+        synthetic_asset
+            .accrue_stability_fee(SECONDS_PER_YEAR as i64)
+            .unwrap();
+        let debt = synthetic_asset
+            .debt_amount(synthetic_asset.total_normalized_debt)
+            .unwrap();
+        synthetic_asset.repay(debt).unwrap();
+        assert!(synthetic_asset.is_wound_down(0));
+        assert!(!synthetic_asset.is_wound_down(1));
+
+        // Collateral left in any vault is still owed to margin accounts
+        synthetic_asset.deposit_collateral(CollateralSlot::Registered(2), 1);
+        assert!(!synthetic_asset.is_wound_down(0));
+    }
+
+    #[test]
+    fn winding_down_stops_minting() {
+        let mut synthetic_asset = synthetic_asset();
+        synthetic_asset.status = SyntheticAssetStatus::WindingDown as u8;
+
+        assert_eq!(
+            synthetic_asset.borrow(1).unwrap_err(),
+            error!(Errors::SyntheticAssetNotActive)
+        );
+        synthetic_asset.repay(1).unwrap();
+    }
+
+    #[test]
+    fn shutdown_settles_pro_rata() {
+        let mut synthetic_asset = synthetic_asset();
+        synthetic_asset.status = SyntheticAssetStatus::Shutdown as u8;
+
+        // Debt stops growing after the shutdown
+        synthetic_asset
+            .accrue_stability_fee(SECONDS_PER_YEAR as i64)
+            .unwrap();
+        assert_eq!(synthetic_asset.borrow_index, BORROW_INDEX_ONE);
+
+        // A quarter of the supply redeems a quarter of the settlement collateral
+        synthetic_asset.deposit_settlement_collateral(CollateralSlot::Primary, 1_500_000_000);
+        assert_eq!(
+            synthetic_asset
+                .settlement_share(CollateralSlot::Primary, 250, 1_000)
+                .unwrap(),
+            375_000_000
+        );
+    }
+
+    fn params() -> SyntheticAssetParams {
+        SyntheticAssetParams {
+            initial_collateral_ratio_bps: 15_000,
+            maintenance_collateral_ratio_bps: 12_000,
+            liquidation_bonus_bps: 500,
+            liquidation_close_factor_bps: 5_000,
+            max_oracle_age: 60,
+            max_oracle_confidence_bps: 200,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn valid_collateral_ratios() {
+        assert!(params().validate().is_ok());
+
+        let params = SyntheticAssetParams {
+            initial_collateral_ratio_bps: 12_000,
+            ..params()
+        };
+        assert!(params.validate().is_ok());
+    }
+
+    #[test]
+    fn maintenance_ratio_must_overcollateralize() {
+        let params = SyntheticAssetParams {
+            maintenance_collateral_ratio_bps: 10_000,
+            ..params()
+        };
+        assert_eq!(
+            params.validate().unwrap_err(),
+            error!(Errors::InvalidCollateralRatios)
+        );
+    }
+
+    #[test]
+    fn initial_ratio_must_cover_maintenance_ratio() {
+        let params = SyntheticAssetParams {
+            initial_collateral_ratio_bps: 11_000,
+            ..params()
+        };
+        assert_eq!(
+            params.validate().unwrap_err(),
+            error!(Errors::InvalidCollateralRatios)
+        );
+    }
+
+    #[test]
+    fn circuit_breaker_trips_on_a_bad_print() {
+        let mut synthetic_asset = synthetic_asset();
+        synthetic_asset.params.max_price_change_bps_per_slot = 10;
+        synthetic_asset.params.circuit_breaker_cooldown_slots = 100;
+        let price = |price| Price {
+            price,
+            ..Default::default()
+        };
+
+        // 1% over 10 slots is within 0.1% per slot
+        synthetic_asset.observe_price(&price(10_000), 1);
+        synthetic_asset.observe_price(&price(10_100), 11);
+        synthetic_asset.verify_price_stable(11).unwrap();
+
+        // 1% in a single slot trips the circuit breaker
+        synthetic_asset.observe_price(&price(10_201), 12);
+        assert_eq!(
+            synthetic_asset.verify_price_stable(12).unwrap_err(),
+            error!(Errors::CircuitBreakerCooldown)
+        );
+
+        // Reverting the bad print trips it again, and the cooldown restarts
+        synthetic_asset.observe_price(&price(10_100), 13);
+        assert_eq!(synthetic_asset.cooldown_end_slot, 113);
+
+        // The cooldown ends once the price stops jumping
+        synthetic_asset.observe_price(&price(10_110), 100);
+        assert!(synthetic_asset.verify_price_stable(112).is_err());
+        synthetic_asset.verify_price_stable(113).unwrap();
+
+        // Or when the admin confirms the price
+        synthetic_asset.observe_price(&price(20_000), 151);
+        synthetic_asset.confirm_price(&price(20_000), 152);
+        synthetic_asset.verify_price_stable(152).unwrap();
+    }
 }
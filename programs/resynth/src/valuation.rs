@@ -0,0 +1,136 @@
+use anchor_lang::prelude::*;
+use pyth_sdk_solana::Price;
+
+use crate::Errors;
+
+/// The number of decimals of fixed-point USD values.
+/// Collateral and debt are both converted to USD before being compared.
+pub const USD_DECIMALS: i32 = 12;
+
+/// The price of a token worth exactly $1
+pub const ONE_DOLLAR: Price = Price {
+    price: 1,
+    conf: 0,
+    expo: 0,
+    publish_time: 0,
+};
+
+/// The fixed-point USD value of a token amount, rounded down.
+///
+/// `amount` is in the smallest unit of a mint with `decimals` decimals,
+/// and `price` is the USD price of one whole token.
+pub fn usd_value(amount: u64, decimals: u8, price: &Price) -> Result<u128> {
+    let price_value = u128::try_from(price.price).map_err(|_| Errors::InvalidOracle)?;
+    let value = u128::from(amount)
+        .checked_mul(price_value)
+        .ok_or(Errors::MathOverflow)?;
+
+    rescale(value, usd_exponent(decimals, price.expo)?)
+}
+
+/// The token amount worth a fixed-point USD value, rounded down.
+/// The inverse of `usd_value`.
+pub fn token_amount(usd_value: u128, decimals: u8, price: &Price) -> Result<u64> {
+    let price_value = u128::try_from(price.price).map_err(|_| Errors::InvalidOracle)?;
+    require!(price_value > 0, Errors::InvalidOracle);
+
+    /*
+     * usd_value = amount * price * 10^exponent
+     *
+     * So the amount is
+     *  amount = usd_value * 10^-exponent / price
+     */
+    let scaled_value = rescale(usd_value, -usd_exponent(decimals, price.expo)?)?;
+    let amount = scaled_value / price_value;
+
+    u64::try_from(amount).map_err(|_| error!(Errors::MathOverflow))
+}
+
+/// Divide, rounding up
+pub fn ceil_div(numerator: u128, denominator: u128) -> u128 {
+    let quotient = numerator / denominator;
+    if quotient * denominator < numerator {
+        quotient + 1
+    } else {
+        quotient
+    }
+}
+
+/// The power of ten that converts `token amount * oracle price` to fixed-point USD
+fn usd_exponent(decimals: u8, price_expo: i32) -> Result<i32> {
+    USD_DECIMALS
+        .checked_add(price_expo)
+        .and_then(|exponent| exponent.checked_sub(i32::from(decimals)))
+        .ok_or(error!(Errors::MathOverflow))
+}
+
+/// Multiply by `10^exponent`, rounding down when the exponent is negative
+fn rescale(value: u128, exponent: i32) -> Result<u128> {
+    let scale = 10u128
+        .checked_pow(exponent.unsigned_abs())
+        .ok_or(Errors::MathOverflow)?;
+
+    if exponent >= 0 {
+        value.checked_mul(scale).ok_or(error!(Errors::MathOverflow))
+    } else {
+        Ok(value / scale)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn price(price: i64, expo: i32) -> Price {
+        Price {
+            price,
+            conf: 0,
+            expo,
+            publish_time: 0,
+        }
+    }
+
+    #[test]
+    fn value_of_one_dollar_stablecoin() {
+        // 2.5 tokens of a 6 decimal stablecoin
+        assert_eq!(
+            usd_value(2_500_000, 6, &ONE_DOLLAR).unwrap(),
+            2_500_000_000_000
+        );
+        assert_eq!(
+            usd_value(2_500_000_000, 9, &ONE_DOLLAR).unwrap(),
+            2_500_000_000_000
+        );
+    }
+
+    #[test]
+    fn value_with_negative_and_positive_exponents() {
+        // 0.1 of a 9 decimal token worth $1,800.00
+        assert_eq!(
+            usd_value(100_000_000, 9, &price(180_000, -2)).unwrap(),
+            180_000_000_000_000
+        );
+        // 0.1 of a 9 decimal token worth 18 * 10^2
+        assert_eq!(
+            usd_value(100_000_000, 9, &price(18, 2)).unwrap(),
+            180_000_000_000_000
+        );
+    }
+
+    #[test]
+    fn token_amount_inverts_value() {
+        let gold = price(180_000, -2);
+        let value = usd_value(100_000_000, 9, &gold).unwrap();
+
+        assert_eq!(token_amount(value, 9, &gold).unwrap(), 100_000_000);
+        assert_eq!(token_amount(value, 8, &ONE_DOLLAR).unwrap(), 18_000_000_000);
+    }
+
+    #[test]
+    fn negative_price_is_invalid() {
+        assert_eq!(
+            usd_value(1, 6, &price(-1, 0)).unwrap_err(),
+            error!(Errors::InvalidOracle)
+        );
+    }
+}
@@ -14,8 +14,16 @@ import {
   TransactionSignature,
 } from "@solana/web3.js";
 import { IDL, Resynth } from "../idl/resynth";
-import { MarginAccount, SyntheticAsset } from "../types";
-import { marginAccountPDA, ResynthConfig, syntheticAssetPDA } from "../utils";
+import { MarginAccount, SyntheticAsset, SyntheticAssetParams } from "../types";
+import {
+  marginAccountPDA,
+  programConfigPDA,
+  programDataPDA,
+  ResynthConfig,
+  SYNTH_DECIMALS,
+  syntheticAssetPDA,
+  treasuryPDA,
+} from "../utils";
 import { Context } from "./context";
 import { PythClient } from "./pyth";
 
@@ -82,22 +90,66 @@ export class ResynthClient {
 
   // Instructions -------------------------------------------------------------
 
+  /**
+   * Initialize the program config. Only the upgrade authority of the program can claim it
+   *
+   * @param {PublicKey[]} oraclePrograms The oracle programs trusted on this cluster
+   * @return {Promise<TransactionSignature>}
+   */
+  async initializeProgramConfig(params: {
+    oraclePrograms: PublicKey[];
+  }): Promise<TransactionSignature> {
+    return this.program.methods
+      .initializeProgramConfig(params.oraclePrograms)
+      .accountsStrict({
+        programConfig: programConfigPDA(this.programId),
+        admin: this.context.provider.wallet.publicKey,
+        program: this.programId,
+        programData: programDataPDA(this.programId),
+        payer: this.context.provider.wallet.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc({ commitment: "confirmed", skipPreflight: true });
+  }
+
+  /**
+   * Initialize a synthetic asset. The wallet signs as the program config admin
+   *
+   * @param {string} symbol The symbol the synthetic asset is derived from
+   * @param {SyntheticAssetParams} params The risk parameters of the synthetic asset
+   * @param {PublicKey} collateralMint The collateral mint of the synthetic asset
+   * @param {PublicKey} syntheticOracle The price oracle of the synthetic asset
+   * @param {number} syntheticDecimals The decimals of the synthetic mint, SYNTH_DECIMALS by default
+   * @param {PublicKey} admin The admin of the synthetic asset, the wallet by default
+   * @return {Promise<TransactionSignature>}
+   */
   async initializeSyntheticAsset(params: {
+    symbol: string;
+    params: SyntheticAssetParams;
     collateralMint: PublicKey;
     syntheticOracle: PublicKey;
+    syntheticDecimals?: number;
+    admin?: PublicKey;
   }): Promise<TransactionSignature> {
     let { syntheticAsset, collateralVault, syntheticMint, assetAuthority } =
-      syntheticAssetPDA(this.programId, params.syntheticOracle);
+      syntheticAssetPDA(this.programId, params.symbol);
 
     return this.program.methods
-      .initializeSyntheticAsset()
+      .initializeSyntheticAsset(
+        params.symbol,
+        params.params,
+        params.syntheticDecimals ?? SYNTH_DECIMALS
+      )
       .accountsStrict({
         syntheticAsset: syntheticAsset,
         collateralMint: params.collateralMint,
         collateralVault: collateralVault,
         syntheticMint: syntheticMint,
         syntheticOracle: params.syntheticOracle,
+        programConfig: programConfigPDA(this.programId),
+        configAdmin: this.context.provider.wallet.publicKey,
         assetAuthority: assetAuthority,
+        admin: params.admin ?? this.context.provider.wallet.publicKey,
         payer: this.context.provider.wallet.publicKey,
         tokenProgram: TOKEN_PROGRAM_ID,
         systemProgram: SystemProgram.programId,
@@ -106,22 +158,34 @@ export class ResynthClient {
   }
 
   async initializeSyntheticAssetInstruction(params: {
+    symbol: string;
+    params: SyntheticAssetParams;
     syntheticAsset: PublicKey;
     collateralMint: PublicKey;
     collateralVault: PublicKey;
     syntheticMint: PublicKey;
     syntheticOracle: PublicKey;
+    syntheticDecimals: number;
     assetAuthority: PublicKey;
+    configAdmin: PublicKey;
+    admin: PublicKey;
   }): Promise<TransactionInstruction> {
     return this.program.methods
-      .initializeSyntheticAsset()
+      .initializeSyntheticAsset(
+        params.symbol,
+        params.params,
+        params.syntheticDecimals
+      )
       .accountsStrict({
         syntheticAsset: params.syntheticAsset,
         collateralMint: params.collateralMint,
         collateralVault: params.collateralVault,
         syntheticMint: params.syntheticMint,
         syntheticOracle: params.syntheticOracle,
+        programConfig: programConfigPDA(this.programId),
+        configAdmin: params.configAdmin,
         assetAuthority: params.assetAuthority,
+        admin: params.admin,
         payer: this.context.provider.wallet.publicKey,
         tokenProgram: TOKEN_PROGRAM_ID,
         systemProgram: SystemProgram.programId,
@@ -179,6 +243,7 @@ export class ResynthClient {
    * Mints synthetic assets and provides collateral to the margin account
    *
    * @param {TestUser} owner The owner that receives the synthetic asset
+   * @param {string} symbol The symbol of the synthetic asset
    * @param {PublicKey} syntheticOracle The price oracle of the synthetic asset
    * @param {PublicKey} syntheticAsset The synthetic asset account
    * @param {number} collateralAmount The amount of collateral to provide
@@ -186,6 +251,7 @@ export class ResynthClient {
    * @return {Promise<TransactionSignature>}
    */
   async mintSyntheticAsset(params: {
+    symbol: string;
     collateralAmount: BN;
     mintAmount: BN;
     syntheticOracle: PublicKey;
@@ -196,6 +262,7 @@ export class ResynthClient {
     signers?: Signer[];
   }): Promise<TransactionSignature> {
     const instruction = await this.mintSyntheticAssetInstruction({
+      symbol: params.symbol,
       collateralAmount: params.collateralAmount,
       mintAmount: params.mintAmount,
       syntheticOracle: params.syntheticOracle,
@@ -212,6 +279,7 @@ export class ResynthClient {
   }
 
   async mintSyntheticAssetInstruction(params: {
+    symbol: string;
     collateralAmount: BN;
     mintAmount: BN;
     syntheticOracle: PublicKey;
@@ -221,7 +289,7 @@ export class ResynthClient {
     syntheticAccount?: PublicKey;
   }): Promise<TransactionInstruction> {
     const { syntheticAsset, collateralVault, syntheticMint, assetAuthority } =
-      syntheticAssetPDA(this.programId, params.syntheticOracle);
+      syntheticAssetPDA(this.programId, params.symbol);
 
     const marginAccount = marginAccountPDA(
       this.programId,
@@ -242,11 +310,17 @@ export class ResynthClient {
       .accountsStrict({
         syntheticAsset: syntheticAsset,
         collateralVault: collateralVault,
+        collateralMint: params.collateralMint,
         syntheticMint: syntheticMint,
         syntheticOracle: params.syntheticOracle,
         assetAuthority: assetAuthority,
         owner: params.owner,
         marginAccount: marginAccount,
+        treasury: treasuryPDA(
+          this.programId,
+          syntheticAsset,
+          params.collateralMint
+        ),
         collateralAccount,
         syntheticAccount,
         systemProgram: SystemProgram.programId,
@@ -260,6 +334,7 @@ export class ResynthClient {
    * Burns synthetic assets and retreives collateral from the margin account
    *
    * @param {TestUser} owner The owner that receives the collateral
+   * @param {string} symbol The symbol of the synthetic asset
    * @param {PublicKey} syntheticOracle The price oracle of the synthetic asset
    * @param {PublicKey} syntheticAsset The synthetic asset account
    * @param {number} collateralAmount The amount of collateral to retrieve
@@ -267,6 +342,7 @@ export class ResynthClient {
    * @return {Promise<TransactionSignature>}
    */
   async burnSyntheticAsset(params: {
+    symbol: string;
     collateralAmount: BN;
     burnAmount: BN;
     syntheticOracle: PublicKey;
@@ -277,6 +353,7 @@ export class ResynthClient {
     signers?: Signer[];
   }): Promise<TransactionSignature> {
     const instruction = await this.burnSyntheticAssetInstruction({
+      symbol: params.symbol,
       collateralAmount: params.collateralAmount,
       burnAmount: params.burnAmount,
       syntheticOracle: params.syntheticOracle,
@@ -293,6 +370,7 @@ export class ResynthClient {
   }
 
   async burnSyntheticAssetInstruction(params: {
+    symbol: string;
     collateralAmount: BN;
     burnAmount: BN;
     syntheticOracle: PublicKey;
@@ -302,7 +380,7 @@ export class ResynthClient {
     syntheticAccount?: PublicKey;
   }): Promise<TransactionInstruction> {
     const { syntheticAsset, collateralVault, syntheticMint, assetAuthority } =
-      syntheticAssetPDA(this.programId, params.syntheticOracle);
+      syntheticAssetPDA(this.programId, params.symbol);
 
     const marginAccount = marginAccountPDA(
       this.programId,
@@ -329,6 +407,11 @@ export class ResynthClient {
         assetAuthority: assetAuthority,
         owner: params.owner,
         marginAccount: marginAccount,
+        treasury: treasuryPDA(
+          this.programId,
+          syntheticAsset,
+          params.collateralMint
+        ),
         collateralAccount,
         syntheticAccount,
         systemProgram: SystemProgram.programId,
@@ -337,4 +420,75 @@ export class ResynthClient {
       })
       .instruction();
   }
+
+  /**
+   * Liquidates an unhealthy margin account, repaying its debt in exchange for collateral
+   *
+   * @param {PublicKey} liquidator The liquidator that repays debt and receives collateral
+   * @param {ProgramAccount<SyntheticAsset>} syntheticAsset The synthetic asset of the margin account
+   * @param {PublicKey} marginAccount The unhealthy margin account
+   * @param {BN} repayAmount The amount of synthetic tokens to repay, limited to the close factor
+   * @return {Promise<TransactionSignature>}
+   */
+  async liquidateMarginAccount(params: {
+    repayAmount: BN;
+    liquidator: PublicKey;
+    syntheticAsset: ProgramAccount<SyntheticAsset>;
+    marginAccount: PublicKey;
+    liquidatorCollateralAccount?: PublicKey;
+    liquidatorSyntheticAccount?: PublicKey;
+    signers?: Signer[];
+  }): Promise<TransactionSignature> {
+    const instruction = await this.liquidateMarginAccountInstruction({
+      repayAmount: params.repayAmount,
+      liquidator: params.liquidator,
+      syntheticAsset: params.syntheticAsset,
+      marginAccount: params.marginAccount,
+      liquidatorCollateralAccount: params.liquidatorCollateralAccount,
+      liquidatorSyntheticAccount: params.liquidatorSyntheticAccount,
+    });
+    const transaction = new Transaction().add(instruction);
+    return await this.context.provider.sendAndConfirm(transaction, params.signers, {
+      commitment: "confirmed",
+      skipPreflight: true,
+    });
+  }
+
+  async liquidateMarginAccountInstruction(params: {
+    repayAmount: BN;
+    liquidator: PublicKey;
+    syntheticAsset: ProgramAccount<SyntheticAsset>;
+    marginAccount: PublicKey;
+    liquidatorCollateralAccount?: PublicKey;
+    liquidatorSyntheticAccount?: PublicKey;
+  }): Promise<TransactionInstruction> {
+    const { publicKey: syntheticAsset, account: asset } = params.syntheticAsset;
+
+    const liquidatorCollateralAccount =
+      params.liquidatorCollateralAccount ??
+      getAssociatedTokenAddressSync(asset.collateralMint, params.liquidator);
+
+    const liquidatorSyntheticAccount =
+      params.liquidatorSyntheticAccount ??
+      getAssociatedTokenAddressSync(asset.syntheticMint, params.liquidator);
+
+    return this.program.methods
+      .liquidateMarginAccount(params.repayAmount)
+      .accountsStrict({
+        syntheticAsset: syntheticAsset,
+        collateralVault: asset.collateralVault,
+        collateralMint: asset.collateralMint,
+        syntheticMint: asset.syntheticMint,
+        syntheticOracle: asset.syntheticOracle,
+        assetAuthority: asset.assetAuthority,
+        liquidator: params.liquidator,
+        marginAccount: params.marginAccount,
+        liquidatorCollateralAccount,
+        liquidatorSyntheticAccount,
+        systemProgram: SystemProgram.programId,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_PROGRAM_ID,
+      })
+      .instruction();
+  }
 }
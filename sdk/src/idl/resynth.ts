@@ -26,61 +26,66 @@ export type Resynth = {
       "name": "MARGIN_ACCOUNT",
       "type": "string",
       "value": "\"margin_account\""
+    },
+    {
+      "name": "TREASURY",
+      "type": "string",
+      "value": "\"treasury\""
+    },
+    {
+      "name": "DEBT_POOL",
+      "type": "string",
+      "value": "\"debt_pool\""
+    },
+    {
+      "name": "PROGRAM_CONFIG",
+      "type": "string",
+      "value": "\"program_config\""
+    },
+    {
+      "name": "CROSS_MARGIN_ACCOUNT",
+      "type": "string",
+      "value": "\"cross_margin_account\""
     }
   ],
   "instructions": [
     {
-      "name": "initializeSyntheticAsset",
+      "name": "initializeProgramConfig",
       "docs": [
-        "Initialize a new synthetic asset"
+        "Initialize the program-wide config, allowing the oracle programs of this cluster"
       ],
       "accounts": [
         {
-          "name": "syntheticAsset",
+          "name": "programConfig",
           "isMut": true,
           "isSigner": false,
           "docs": [
-            "The synthetic asset account to initialize"
+            "The program-wide config to initialize"
           ]
         },
         {
-          "name": "collateralMint",
+          "name": "admin",
           "isMut": false,
-          "isSigner": false,
-          "docs": [
-            "The mint to use as collateral for the synthetic asset"
-          ]
-        },
-        {
-          "name": "collateralVault",
-          "isMut": true,
-          "isSigner": false,
-          "docs": [
-            "The vault of hard assets to collateralize the circulating synthetic assets"
-          ]
-        },
-        {
-          "name": "syntheticMint",
-          "isMut": true,
-          "isSigner": false,
+          "isSigner": true,
           "docs": [
-            "The synthetic asset mint"
+            "The admin that can update the program config. The config is a singleton that",
+            "decides which oracles are trusted, so only the upgrade authority can claim it."
           ]
         },
         {
-          "name": "syntheticOracle",
+          "name": "program",
           "isMut": false,
           "isSigner": false,
           "docs": [
-            "The synthetic asset oracle price feed, to determine margin account health"
+            "The resynth program being configured"
           ]
         },
         {
-          "name": "assetAuthority",
+          "name": "programData",
           "isMut": false,
           "isSigner": false,
           "docs": [
-            "The mint authority that can mint synthetic assets and transfer vault collateral"
+            "The program data of the resynth program, naming its upgrade authority"
           ]
         },
         {
@@ -88,15 +93,7 @@ export type Resynth = {
           "isMut": true,
           "isSigner": true,
           "docs": [
-            "The payer of rent for various accounts"
-          ]
-        },
-        {
-          "name": "tokenProgram",
-          "isMut": false,
-          "isSigner": false,
-          "docs": [
-            "The token program to initialize token accounts"
+            "The payer of rent for the program config"
           ]
         },
         {
@@ -108,69 +105,69 @@ export type Resynth = {
           ]
         }
       ],
-      "args": []
+      "args": [
+        {
+          "name": "oraclePrograms",
+          "type": {
+            "vec": "publicKey"
+          }
+        }
+      ]
     },
     {
-      "name": "initializeMarginAccount",
+      "name": "setOraclePrograms",
       "docs": [
-        "Initialize a new margin account"
+        "Replace the oracle programs allowed to price synthetic assets, as the config admin"
       ],
       "accounts": [
         {
-          "name": "payer",
-          "isMut": true,
-          "isSigner": true,
-          "docs": [
-            "The payer of margin account rent"
-          ]
-        },
-        {
-          "name": "owner",
-          "isMut": false,
-          "isSigner": false,
-          "docs": [
-            "The owner of the margin account, who can mint assets"
-          ]
-        },
-        {
-          "name": "syntheticAsset",
-          "isMut": false,
-          "isSigner": false,
-          "docs": [
-            "The synthetic asset the margin account is associated with"
-          ]
-        },
-        {
-          "name": "marginAccount",
+          "name": "programConfig",
           "isMut": true,
           "isSigner": false,
           "docs": [
-            "The margin account to initialize"
+            "The program-wide config to update"
           ]
         },
         {
-          "name": "systemProgram",
+          "name": "admin",
           "isMut": false,
-          "isSigner": false,
+          "isSigner": true,
           "docs": [
-            "System program for CPI"
+            "The admin of the program config"
           ]
         }
       ],
-      "args": []
+      "args": [
+        {
+          "name": "oraclePrograms",
+          "type": {
+            "vec": "publicKey"
+          }
+        }
+      ]
     },
     {
-      "name": "mintSyntheticAsset",
+      "name": "initializeSyntheticAsset",
       "docs": [
-        "Mint a synthetic asset"
+        "Initialize a new synthetic asset, derived from its symbol, with a synthetic mint",
+        "of `synthetic_decimals` decimals"
       ],
       "accounts": [
         {
           "name": "syntheticAsset",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The synthetic asset account to initialize, derived from its symbol so that",
+            "the oracle can be replaced without moving the synthetic asset"
+          ]
+        },
+        {
+          "name": "collateralMint",
           "isMut": false,
           "isSigner": false,
           "docs": [
-            "The synthetic asset account"
+            "The mint to use as collateral for the synthetic asset"
           ]
         },
         {
@@ -178,7 +175,7 @@ export type Resynth = {
           "isMut": true,
           "isSigner": false,
           "docs": [
-            "The vault that is receiving collateral"
+            "The vault of hard assets to collateralize the circulating synthetic assets"
           ]
         },
         {
@@ -186,7 +183,7 @@ export type Resynth = {
           "isMut": true,
           "isSigner": false,
           "docs": [
-            "The synthetic mint of the synthetic asset"
+            "The synthetic asset mint, with the decimals chosen for the asset"
           ]
         },
         {
@@ -194,460 +191,417 @@ export type Resynth = {
           "isMut": false,
           "isSigner": false,
           "docs": [
-            "The oracle price feed, to determine margin account health"
+            "The synthetic asset oracle price feed, to determine margin account health"
           ]
         },
         {
-          "name": "assetAuthority",
+          "name": "programConfig",
           "isMut": false,
           "isSigner": false,
           "docs": [
-            "The mint authority that can mint synthetic assets and transfer vault collateral"
+            "The program-wide config listing the allowed oracle programs"
           ]
         },
         {
-          "name": "owner",
-          "isMut": true,
+          "name": "configAdmin",
+          "isMut": false,
           "isSigner": true,
           "docs": [
-            "The receiver of the synthetic asset"
+            "The admin of the program config. Symbols are first come first served,",
+            "so only the deployment can claim them."
           ]
         },
         {
-          "name": "marginAccount",
-          "isMut": true,
+          "name": "assetAuthority",
+          "isMut": false,
           "isSigner": false,
           "docs": [
-            "The margin account of the owner, to track collateral and debt"
+            "The mint authority that can mint synthetic assets and transfer vault collateral"
           ]
         },
         {
-          "name": "collateralAccount",
-          "isMut": true,
+          "name": "admin",
+          "isMut": false,
           "isSigner": false,
           "docs": [
-            "The owners account that collateral will be transferred from"
+            "The admin that can update the synthetic asset parameters"
           ]
         },
         {
-          "name": "syntheticAccount",
+          "name": "payer",
           "isMut": true,
-          "isSigner": false,
+          "isSigner": true,
           "docs": [
-            "The owners account that will receive synthetic tokens"
+            "The payer of rent for various accounts"
           ]
         },
-        {
-          "name": "systemProgram",
-          "isMut": false,
-          "isSigner": false
-        },
         {
           "name": "tokenProgram",
           "isMut": false,
           "isSigner": false,
           "docs": [
-            "The token program for CPI calls"
+            "The token program to initialize token accounts"
           ]
         },
         {
-          "name": "associatedTokenProgram",
+          "name": "systemProgram",
           "isMut": false,
-          "isSigner": false
+          "isSigner": false,
+          "docs": [
+            "The system program to create accounts"
+          ]
         }
       ],
       "args": [
         {
-          "name": "collateralAmount",
-          "type": "u64"
+          "name": "symbol",
+          "type": "string"
         },
         {
-          "name": "mintAmount",
-          "type": "u64"
+          "name": "params",
+          "type": {
+            "defined": "SyntheticAssetParams"
+          }
+        },
+        {
+          "name": "syntheticDecimals",
+          "type": "u8"
         }
       ]
     },
     {
-      "name": "burnSyntheticAsset",
+      "name": "updateSyntheticAsset",
       "docs": [
-        "Burn a synthetic asset"
+        "Update the risk parameters of a synthetic asset"
       ],
       "accounts": [
         {
           "name": "syntheticAsset",
-          "isMut": false,
+          "isMut": true,
           "isSigner": false,
           "docs": [
-            "The synthetic asset account"
+            "The synthetic asset to update"
           ]
         },
         {
-          "name": "collateralVault",
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "The admin of the synthetic asset"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "params",
+          "type": {
+            "defined": "SyntheticAssetParams"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setOracle",
+      "docs": [
+        "Replace the oracle price feed of a synthetic asset, as its admin"
+      ],
+      "accounts": [
+        {
+          "name": "syntheticAsset",
           "isMut": true,
           "isSigner": false,
           "docs": [
-            "The vault that is receiving collateral"
+            "The synthetic asset whose oracle is replaced"
           ]
         },
         {
-          "name": "collateralMint",
+          "name": "syntheticOracle",
           "isMut": false,
           "isSigner": false,
           "docs": [
-            "The collateral mint of the synthetic asset"
+            "The new oracle price feed of the synthetic asset"
           ]
         },
         {
-          "name": "syntheticMint",
-          "isMut": true,
+          "name": "programConfig",
+          "isMut": false,
           "isSigner": false,
           "docs": [
-            "The synthetic mint of the synthetic asset"
+            "The program-wide config listing the allowed oracle programs"
           ]
         },
         {
-          "name": "syntheticOracle",
+          "name": "admin",
           "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "The admin of the synthetic asset"
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "transferAdmin",
+      "docs": [
+        "Propose a new admin of a synthetic asset"
+      ],
+      "accounts": [
+        {
+          "name": "syntheticAsset",
+          "isMut": true,
           "isSigner": false,
           "docs": [
-            "The oracle price feed, to determine margin account health"
+            "The synthetic asset to transfer"
           ]
         },
         {
-          "name": "assetAuthority",
+          "name": "admin",
           "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "The current admin of the synthetic asset"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "newAdmin",
+          "type": "publicKey"
+        }
+      ]
+    },
+    {
+      "name": "acceptAdmin",
+      "docs": [
+        "Accept the admin role of a synthetic asset, as the proposed admin"
+      ],
+      "accounts": [
+        {
+          "name": "syntheticAsset",
+          "isMut": true,
           "isSigner": false,
           "docs": [
-            "The mint authority that can mint synthetic assets and transfer vault collateral"
+            "The synthetic asset being transferred"
           ]
         },
         {
-          "name": "owner",
-          "isMut": true,
+          "name": "pendingAdmin",
+          "isMut": false,
           "isSigner": true,
           "docs": [
-            "The receiver of the synthetic asset"
+            "The proposed admin of the synthetic asset"
           ]
-        },
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "addCollateralType",
+      "docs": [
+        "Accept an additional collateral mint for a synthetic asset"
+      ],
+      "accounts": [
         {
-          "name": "marginAccount",
+          "name": "syntheticAsset",
           "isMut": true,
           "isSigner": false,
           "docs": [
-            "The margin account of the owner, to track collateral and debt"
+            "The synthetic asset accepting the new collateral"
           ]
         },
         {
-          "name": "collateralAccount",
-          "isMut": true,
+          "name": "collateralMint",
+          "isMut": false,
           "isSigner": false,
           "docs": [
-            "The owners account that collateral will be transferred from"
+            "The mint of the new collateral"
           ]
         },
         {
-          "name": "syntheticAccount",
+          "name": "collateralVault",
           "isMut": true,
           "isSigner": false,
           "docs": [
-            "The owners account that will receive synthetic tokens"
+            "The vault of the new collateral"
           ]
         },
         {
-          "name": "systemProgram",
+          "name": "collateralOracle",
           "isMut": false,
-          "isSigner": false
+          "isSigner": false,
+          "docs": [
+            "The oracle price feed of the new collateral"
+          ]
         },
         {
-          "name": "tokenProgram",
+          "name": "programConfig",
           "isMut": false,
           "isSigner": false,
           "docs": [
-            "The token program for CPI calls"
+            "The program-wide config listing the allowed oracle programs"
           ]
         },
         {
-          "name": "associatedTokenProgram",
+          "name": "assetAuthority",
           "isMut": false,
-          "isSigner": false
-        }
-      ],
-      "args": [
+          "isSigner": false,
+          "docs": [
+            "The mint authority that can mint synthetic assets and transfer vault collateral"
+          ]
+        },
         {
-          "name": "collateralAmount",
-          "type": "u64"
-        },
-        {
-          "name": "burnAmount",
-          "type": "u64"
-        }
-      ]
-    }
-  ],
-  "accounts": [
-    {
-      "name": "marginAccount",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "owner",
-            "type": "publicKey"
-          },
-          {
-            "name": "syntheticAsset",
-            "type": "publicKey"
-          },
-          {
-            "name": "collateralDeposited",
-            "type": "u64"
-          },
-          {
-            "name": "syntheticAssetBorrowed",
-            "type": "u64"
-          }
-        ]
-      }
-    },
-    {
-      "name": "syntheticAsset",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "syntheticAsset",
-            "type": "publicKey"
-          },
-          {
-            "name": "collateralMint",
-            "type": "publicKey"
-          },
-          {
-            "name": "collateralVault",
-            "type": "publicKey"
-          },
-          {
-            "name": "syntheticMint",
-            "type": "publicKey"
-          },
-          {
-            "name": "syntheticOracle",
-            "type": "publicKey"
-          },
-          {
-            "name": "assetAuthority",
-            "type": "publicKey"
-          },
-          {
-            "name": "assetAuthorityBump",
-            "type": {
-              "array": [
-                "u8",
-                1
-              ]
-            }
-          }
-        ]
-      }
-    }
-  ],
-  "errors": [
-    {
-      "code": 6000,
-      "name": "InvalidOracle",
-      "msg": "The oracle account provided is invalid"
-    },
-    {
-      "code": 6001,
-      "name": "StaleOracle",
-      "msg": "The oracle is stale"
-    },
-    {
-      "code": 6002,
-      "name": "Undercollateralized",
-      "msg": "The margin account is undercollateralized"
-    },
-    {
-      "code": 6003,
-      "name": "InvalidCollateralMintDecimals",
-      "msg": "Collateral decimals is rqeuired to be 6 because it's value is hardcoded to $1"
-    }
-  ]
-};
-
-export const IDL: Resynth = {
-  "version": "0.1.0",
-  "name": "resynth",
-  "constants": [
-    {
-      "name": "ASSET",
-      "type": "string",
-      "value": "\"asset\""
-    },
-    {
-      "name": "AUTHORITY",
-      "type": "string",
-      "value": "\"authority\""
-    },
-    {
-      "name": "VAULT",
-      "type": "string",
-      "value": "\"vault\""
-    },
-    {
-      "name": "MINT",
-      "type": "string",
-      "value": "\"mint\""
-    },
-    {
-      "name": "MARGIN_ACCOUNT",
-      "type": "string",
-      "value": "\"margin_account\""
-    }
-  ],
-  "instructions": [
-    {
-      "name": "initializeSyntheticAsset",
-      "docs": [
-        "Initialize a new synthetic asset"
-      ],
-      "accounts": [
-        {
-          "name": "syntheticAsset",
-          "isMut": true,
-          "isSigner": false,
-          "docs": [
-            "The synthetic asset account to initialize"
-          ]
-        },
-        {
-          "name": "collateralMint",
+          "name": "admin",
           "isMut": false,
-          "isSigner": false,
-          "docs": [
-            "The mint to use as collateral for the synthetic asset"
-          ]
-        },
-        {
-          "name": "collateralVault",
-          "isMut": true,
-          "isSigner": false,
+          "isSigner": true,
           "docs": [
-            "The vault of hard assets to collateralize the circulating synthetic assets"
+            "The admin of the synthetic asset"
           ]
         },
         {
-          "name": "syntheticMint",
+          "name": "payer",
           "isMut": true,
-          "isSigner": false,
+          "isSigner": true,
           "docs": [
-            "The synthetic asset mint"
+            "The payer of rent for the vault"
           ]
         },
         {
-          "name": "syntheticOracle",
+          "name": "tokenProgram",
           "isMut": false,
           "isSigner": false,
           "docs": [
-            "The synthetic asset oracle price feed, to determine margin account health"
+            "The token program to initialize token accounts"
           ]
         },
         {
-          "name": "assetAuthority",
+          "name": "systemProgram",
           "isMut": false,
           "isSigner": false,
           "docs": [
-            "The mint authority that can mint synthetic assets and transfer vault collateral"
+            "The system program to create accounts"
           ]
-        },
+        }
+      ],
+      "args": [
         {
-          "name": "payer",
+          "name": "loanToValueBps",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "updateCollateralType",
+      "docs": [
+        "Update the loan to value of an additional collateral mint"
+      ],
+      "accounts": [
+        {
+          "name": "syntheticAsset",
           "isMut": true,
-          "isSigner": true,
+          "isSigner": false,
           "docs": [
-            "The payer of rent for various accounts"
+            "The synthetic asset accepting the collateral"
           ]
         },
         {
-          "name": "tokenProgram",
+          "name": "collateralVault",
           "isMut": false,
           "isSigner": false,
           "docs": [
-            "The token program to initialize token accounts"
+            "The vault of the collateral to update"
           ]
         },
         {
-          "name": "systemProgram",
+          "name": "admin",
           "isMut": false,
-          "isSigner": false,
+          "isSigner": true,
           "docs": [
-            "The system program to create accounts"
+            "The admin of the synthetic asset"
           ]
         }
       ],
-      "args": []
+      "args": [
+        {
+          "name": "loanToValueBps",
+          "type": "u64"
+        }
+      ]
     },
     {
-      "name": "initializeMarginAccount",
+      "name": "updateCircuitBreaker",
       "docs": [
-        "Initialize a new margin account"
+        "Record the oracle price of a synthetic asset, tripping its circuit breaker on a bad print.",
+        "Mints and withdrawals rejected by a bad print revert their own trip, so keepers crank",
+        "this to start the cooldown."
       ],
       "accounts": [
         {
-          "name": "payer",
+          "name": "syntheticAsset",
           "isMut": true,
-          "isSigner": true,
+          "isSigner": false,
           "docs": [
-            "The payer of margin account rent"
+            "The synthetic asset whose circuit breaker is updated"
           ]
         },
         {
-          "name": "owner",
+          "name": "syntheticOracle",
           "isMut": false,
           "isSigner": false,
           "docs": [
-            "The owner of the margin account, who can mint assets"
+            "The oracle price feed of the synthetic asset"
           ]
-        },
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "confirmOraclePrice",
+      "docs": [
+        "Confirm the oracle price of a synthetic asset as its admin, ending a circuit breaker cooldown"
+      ],
+      "accounts": [
         {
           "name": "syntheticAsset",
-          "isMut": false,
+          "isMut": true,
           "isSigner": false,
           "docs": [
-            "The synthetic asset the margin account is associated with"
+            "The synthetic asset whose oracle price is confirmed"
           ]
         },
         {
-          "name": "marginAccount",
-          "isMut": true,
+          "name": "syntheticOracle",
+          "isMut": false,
           "isSigner": false,
           "docs": [
-            "The margin account to initialize"
+            "The oracle price feed of the synthetic asset"
           ]
         },
         {
-          "name": "systemProgram",
+          "name": "admin",
           "isMut": false,
-          "isSigner": false,
+          "isSigner": true,
           "docs": [
-            "System program for CPI"
+            "The admin of the synthetic asset"
           ]
         }
       ],
       "args": []
     },
     {
-      "name": "mintSyntheticAsset",
+      "name": "closeSyntheticAsset",
       "docs": [
-        "Mint a synthetic asset"
+        "Wind down a synthetic asset, closing it once nothing is owed to or by it",
+        "and every margin account is closed"
       ],
       "accounts": [
         {
           "name": "syntheticAsset",
-          "isMut": false,
+          "isMut": true,
           "isSigner": false,
           "docs": [
-            "The synthetic asset account"
+            "The synthetic asset to close"
           ]
         },
         {
@@ -655,23 +609,15 @@ export const IDL: Resynth = {
           "isMut": true,
           "isSigner": false,
           "docs": [
-            "The vault that is receiving collateral"
+            "The vault to close"
           ]
         },
         {
           "name": "syntheticMint",
-          "isMut": true,
-          "isSigner": false,
-          "docs": [
-            "The synthetic mint of the synthetic asset"
-          ]
-        },
-        {
-          "name": "syntheticOracle",
           "isMut": false,
           "isSigner": false,
           "docs": [
-            "The oracle price feed, to determine margin account health"
+            "The synthetic mint, which must have no supply left to close"
           ]
         },
         {
@@ -679,83 +625,107 @@ export const IDL: Resynth = {
           "isMut": false,
           "isSigner": false,
           "docs": [
-            "The mint authority that can mint synthetic assets and transfer vault collateral"
+            "The mint authority that can transfer vault collateral"
           ]
         },
         {
-          "name": "owner",
+          "name": "admin",
           "isMut": true,
           "isSigner": true,
           "docs": [
-            "The receiver of the synthetic asset"
+            "The admin of the synthetic asset, who receives the rent"
           ]
         },
         {
-          "name": "marginAccount",
+          "name": "collateralAccount",
           "isMut": true,
           "isSigner": false,
           "docs": [
-            "The margin account of the owner, to track collateral and debt"
+            "The admins account that receives collateral sent to the vault without a deposit"
           ]
         },
         {
-          "name": "collateralAccount",
-          "isMut": true,
+          "name": "tokenProgram",
+          "isMut": false,
           "isSigner": false,
           "docs": [
-            "The owners account that collateral will be transferred from"
+            "The token program for CPI calls"
           ]
-        },
-        {
-          "name": "syntheticAccount",
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "shutdownSyntheticAsset",
+      "docs": [
+        "Shut down a synthetic asset, settling all debt at a final price"
+      ],
+      "accounts": [
+        {
+          "name": "syntheticAsset",
           "isMut": true,
           "isSigner": false,
           "docs": [
-            "The owners account that will receive synthetic tokens"
+            "The synthetic asset to shut down"
           ]
         },
         {
-          "name": "systemProgram",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "tokenProgram",
+          "name": "admin",
           "isMut": false,
-          "isSigner": false,
+          "isSigner": true,
           "docs": [
-            "The token program for CPI calls"
+            "The admin of the synthetic asset"
           ]
-        },
-        {
-          "name": "associatedTokenProgram",
-          "isMut": false,
-          "isSigner": false
         }
       ],
       "args": [
         {
-          "name": "collateralAmount",
-          "type": "u64"
+          "name": "settlementPrice",
+          "type": "i64"
         },
         {
-          "name": "mintAmount",
-          "type": "u64"
+          "name": "settlementExpo",
+          "type": "i32"
         }
       ]
     },
     {
-      "name": "burnSyntheticAsset",
+      "name": "settleMarginAccount",
       "docs": [
-        "Burn a synthetic asset"
+        "Settle the debt of a margin account of a shut down synthetic asset"
       ],
       "accounts": [
         {
           "name": "syntheticAsset",
-          "isMut": false,
+          "isMut": true,
           "isSigner": false,
           "docs": [
-            "The synthetic asset account"
+            "The shut down synthetic asset"
+          ]
+        },
+        {
+          "name": "marginAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The margin account whose debt is settled, by anyone"
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "settleCrossMarginPosition",
+      "docs": [
+        "Settle the debt of a cross-margin position in a shut down synthetic asset"
+      ],
+      "accounts": [
+        {
+          "name": "syntheticAsset",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The shut down synthetic asset"
           ]
         },
         {
@@ -763,31 +733,64 @@ export const IDL: Resynth = {
           "isMut": true,
           "isSigner": false,
           "docs": [
-            "The vault that is receiving collateral"
+            "The vault of the synthetic asset, which receives the settlement collateral"
           ]
         },
         {
-          "name": "collateralMint",
-          "isMut": false,
+          "name": "crossMarginAccount",
+          "isMut": true,
           "isSigner": false,
           "docs": [
-            "The collateral mint of the synthetic asset"
+            "The cross-margin account whose position is settled, by anyone"
           ]
         },
         {
-          "name": "syntheticMint",
+          "name": "crossMarginVault",
           "isMut": true,
           "isSigner": false,
           "docs": [
-            "The synthetic mint of the synthetic asset"
+            "The vault of the cross-margin account"
           ]
         },
         {
-          "name": "syntheticOracle",
+          "name": "tokenProgram",
           "isMut": false,
           "isSigner": false,
           "docs": [
-            "The oracle price feed, to determine margin account health"
+            "The token program for CPI calls"
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "redeemSettlement",
+      "docs": [
+        "Redeem synthetic tokens of a shut down synthetic asset for its settlement collateral"
+      ],
+      "accounts": [
+        {
+          "name": "syntheticAsset",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The shut down synthetic asset"
+          ]
+        },
+        {
+          "name": "collateralVault",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The vault of the primary collateral"
+          ]
+        },
+        {
+          "name": "syntheticMint",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The synthetic mint of the synthetic asset"
           ]
         },
         {
@@ -795,23 +798,23 @@ export const IDL: Resynth = {
           "isMut": false,
           "isSigner": false,
           "docs": [
-            "The mint authority that can mint synthetic assets and transfer vault collateral"
+            "The authority that can transfer vault collateral"
           ]
         },
         {
-          "name": "owner",
-          "isMut": true,
+          "name": "holder",
+          "isMut": false,
           "isSigner": true,
           "docs": [
-            "The receiver of the synthetic asset"
+            "The holder redeeming synthetic tokens"
           ]
         },
         {
-          "name": "marginAccount",
+          "name": "syntheticAccount",
           "isMut": true,
           "isSigner": false,
           "docs": [
-            "The margin account of the owner, to track collateral and debt"
+            "The holders account that synthetic tokens will be burned from"
           ]
         },
         {
@@ -819,113 +822,6716 @@ export const IDL: Resynth = {
           "isMut": true,
           "isSigner": false,
           "docs": [
-            "The owners account that collateral will be transferred from"
+            "The holders account that will receive primary collateral"
           ]
         },
         {
-          "name": "syntheticAccount",
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The token program for CPI calls"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "initializeDebtPool",
+      "docs": [
+        "Initialize the program-wide debt pool"
+      ],
+      "accounts": [
+        {
+          "name": "debtPool",
           "isMut": true,
           "isSigner": false,
           "docs": [
-            "The owners account that will receive synthetic tokens"
+            "The program-wide debt pool to initialize"
+          ]
+        },
+        {
+          "name": "programConfig",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The program-wide config, whose admin owns the singleton debt pool"
+          ]
+        },
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "The admin that can add synthetic assets to the debt pool"
+          ]
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "The payer of rent for the debt pool"
           ]
         },
         {
           "name": "systemProgram",
           "isMut": false,
-          "isSigner": false
+          "isSigner": false,
+          "docs": [
+            "The system program to create accounts"
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "addPoolSyntheticAsset",
+      "docs": [
+        "Add a synthetic asset to the debt pool, sharing its debt with all pooled synthetic assets"
+      ],
+      "accounts": [
+        {
+          "name": "debtPool",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The debt pool the synthetic asset joins"
+          ]
         },
         {
-          "name": "tokenProgram",
+          "name": "syntheticAsset",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The synthetic asset joining the debt pool"
+          ]
+        },
+        {
+          "name": "syntheticMint",
           "isMut": false,
           "isSigner": false,
           "docs": [
-            "The token program for CPI calls"
+            "The synthetic mint of the synthetic asset"
           ]
         },
         {
-          "name": "associatedTokenProgram",
+          "name": "admin",
           "isMut": false,
-          "isSigner": false
+          "isSigner": true,
+          "docs": [
+            "The admin of both the debt pool and the synthetic asset"
+          ]
         }
       ],
-      "args": [
+      "args": []
+    },
+    {
+      "name": "initializeMarginAccount",
+      "docs": [
+        "Initialize a new margin account"
+      ],
+      "accounts": [
         {
-          "name": "collateralAmount",
-          "type": "u64"
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "The payer of margin account rent"
+          ]
         },
         {
-          "name": "burnAmount",
-          "type": "u64"
+          "name": "owner",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The owner of the margin account, who can mint assets"
+          ]
+        },
+        {
+          "name": "syntheticAsset",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The synthetic asset the margin account is associated with"
+          ]
+        },
+        {
+          "name": "marginAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The margin account to initialize"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "System program for CPI"
+          ]
         }
-      ]
-    }
-  ],
-  "accounts": [
-    {
-      "name": "marginAccount",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "owner",
-            "type": "publicKey"
-          },
-          {
-            "name": "syntheticAsset",
-            "type": "publicKey"
-          },
-          {
-            "name": "collateralDeposited",
-            "type": "u64"
-          },
-          {
-            "name": "syntheticAssetBorrowed",
-            "type": "u64"
-          }
-        ]
-      }
+      ],
+      "args": []
     },
     {
-      "name": "syntheticAsset",
-      "type": {
+      "name": "closeMarginAccount",
+      "docs": [
+        "Close a margin account without debt, sweeping its collateral to the owner"
+      ],
+      "accounts": [
+        {
+          "name": "syntheticAsset",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The synthetic asset account"
+          ]
+        },
+        {
+          "name": "collateralVault",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The vault of the primary collateral"
+          ]
+        },
+        {
+          "name": "assetAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The authority that can transfer vault collateral"
+          ]
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "The owner of the margin account"
+          ]
+        },
+        {
+          "name": "marginAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The margin account to close, which must have no debt"
+          ]
+        },
+        {
+          "name": "collateralAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The owners account that will receive the remaining primary collateral"
+          ]
+        },
+        {
+          "name": "destination",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The receiver of the margin account rent"
+          ]
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The token program for CPI calls"
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "depositCollateral",
+      "docs": [
+        "Deposit collateral into a margin account, on behalf of its owner"
+      ],
+      "accounts": [
+        {
+          "name": "syntheticAsset",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The synthetic asset account"
+          ]
+        },
+        {
+          "name": "collateralVault",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The vault that is receiving collateral, of any collateral the synthetic asset accepts"
+          ]
+        },
+        {
+          "name": "depositor",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "The depositor of collateral, who doesn't have to be the margin account owner"
+          ]
+        },
+        {
+          "name": "marginAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The margin account being credited with collateral"
+          ]
+        },
+        {
+          "name": "depositorCollateralAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The depositors account that collateral will be transferred from"
+          ]
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The token program for CPI calls"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "collateralAmount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "withdrawCollateral",
+      "docs": [
+        "Withdraw collateral from a margin account, as its owner"
+      ],
+      "accounts": [
+        {
+          "name": "syntheticAsset",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The synthetic asset account"
+          ]
+        },
+        {
+          "name": "collateralVault",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The vault that collateral is withdrawn from, of any collateral the synthetic asset accepts"
+          ]
+        },
+        {
+          "name": "collateralMint",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The mint of the collateral being withdrawn"
+          ]
+        },
+        {
+          "name": "syntheticOracle",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The oracle price feed, to determine margin account health"
+          ]
+        },
+        {
+          "name": "assetAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The mint authority that can mint synthetic assets and transfer vault collateral"
+          ]
+        },
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "The owner of the margin account"
+          ]
+        },
+        {
+          "name": "marginAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The margin account of the owner, to track collateral and debt"
+          ]
+        },
+        {
+          "name": "collateralAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The owners account that will receive collateral"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The token program for CPI calls"
+          ]
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "collateralAmount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "mintSyntheticAsset",
+      "docs": [
+        "Mint a synthetic asset"
+      ],
+      "accounts": [
+        {
+          "name": "syntheticAsset",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The synthetic asset account"
+          ]
+        },
+        {
+          "name": "collateralVault",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The vault that is receiving collateral, of any collateral the synthetic asset accepts"
+          ]
+        },
+        {
+          "name": "collateralMint",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The mint of the collateral being deposited"
+          ]
+        },
+        {
+          "name": "syntheticMint",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The synthetic mint of the synthetic asset"
+          ]
+        },
+        {
+          "name": "syntheticOracle",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The oracle price feed, to determine margin account health"
+          ]
+        },
+        {
+          "name": "assetAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The mint authority that can mint synthetic assets and transfer vault collateral"
+          ]
+        },
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "The receiver of the synthetic asset"
+          ]
+        },
+        {
+          "name": "marginAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The margin account of the owner, to track collateral and debt"
+          ]
+        },
+        {
+          "name": "treasury",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The treasury receiving fees, in the collateral of the vault"
+          ]
+        },
+        {
+          "name": "collateralAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The owners account that collateral will be transferred from"
+          ]
+        },
+        {
+          "name": "syntheticAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The owners account that will receive synthetic tokens"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The token program for CPI calls"
+          ]
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "collateralAmount",
+          "type": "u64"
+        },
+        {
+          "name": "mintAmount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "burnSyntheticAsset",
+      "docs": [
+        "Burn a synthetic asset"
+      ],
+      "accounts": [
+        {
+          "name": "syntheticAsset",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The synthetic asset account"
+          ]
+        },
+        {
+          "name": "collateralVault",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The vault that collateral is withdrawn from, of any collateral the synthetic asset accepts"
+          ]
+        },
+        {
+          "name": "collateralMint",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The mint of the collateral being withdrawn"
+          ]
+        },
+        {
+          "name": "syntheticMint",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The synthetic mint of the synthetic asset"
+          ]
+        },
+        {
+          "name": "syntheticOracle",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The oracle price feed, to determine margin account health"
+          ]
+        },
+        {
+          "name": "assetAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The mint authority that can mint synthetic assets and transfer vault collateral"
+          ]
+        },
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "The receiver of the synthetic asset"
+          ]
+        },
+        {
+          "name": "marginAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The margin account of the owner, to track collateral and debt"
+          ]
+        },
+        {
+          "name": "treasury",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The treasury receiving fees, in the collateral of the vault"
+          ]
+        },
+        {
+          "name": "collateralAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The owners account that collateral will be transferred from"
+          ]
+        },
+        {
+          "name": "syntheticAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The owners account that will receive synthetic tokens"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The token program for CPI calls"
+          ]
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "collateralAmount",
+          "type": "u64"
+        },
+        {
+          "name": "burnAmount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "repay",
+      "docs": [
+        "Repay the debt of a margin account, on behalf of its owner"
+      ],
+      "accounts": [
+        {
+          "name": "syntheticAsset",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The synthetic asset account"
+          ]
+        },
+        {
+          "name": "syntheticMint",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The synthetic mint of the synthetic asset"
+          ]
+        },
+        {
+          "name": "repayer",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "The repayer of debt, who doesn't have to be the margin account owner"
+          ]
+        },
+        {
+          "name": "marginAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The margin account whose debt is repaid"
+          ]
+        },
+        {
+          "name": "repayerSyntheticAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The repayers account that synthetic tokens will be burned from"
+          ]
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The token program for CPI calls"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "repayAmount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "collectStabilityFees",
+      "docs": [
+        "Mint the accrued stability fees of a synthetic asset to its treasury"
+      ],
+      "accounts": [
+        {
+          "name": "syntheticAsset",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The synthetic asset account"
+          ]
+        },
+        {
+          "name": "syntheticMint",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The synthetic mint of the synthetic asset"
+          ]
+        },
+        {
+          "name": "assetAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The mint authority that can mint synthetic assets and transfer vault collateral"
+          ]
+        },
+        {
+          "name": "treasury",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The treasury receiving stability fees, in the synthetic asset"
+          ]
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "The payer of rent for the treasury"
+          ]
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The token program for CPI calls"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The system program to create accounts"
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "withdrawTreasury",
+      "docs": [
+        "Withdraw fees from a treasury of a synthetic asset, as its admin"
+      ],
+      "accounts": [
+        {
+          "name": "syntheticAsset",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The synthetic asset account"
+          ]
+        },
+        {
+          "name": "assetAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The mint authority that can mint synthetic assets and transfer vault collateral"
+          ]
+        },
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "The admin of the synthetic asset"
+          ]
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The mint of the fees being withdrawn"
+          ]
+        },
+        {
+          "name": "treasury",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The treasury holding fees"
+          ]
+        },
+        {
+          "name": "destination",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The account receiving the fees"
+          ]
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The token program for CPI calls"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "auditSyntheticAsset",
+      "docs": [
+        "Verify the vaults and synthetic supply of a synthetic asset match its aggregate accounting"
+      ],
+      "accounts": [
+        {
+          "name": "syntheticAsset",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The synthetic asset being audited"
+          ]
+        },
+        {
+          "name": "collateralVault",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The vault of the primary collateral"
+          ]
+        },
+        {
+          "name": "syntheticMint",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The synthetic mint of the synthetic asset"
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "issuePoolDebt",
+      "docs": [
+        "Mint a pooled synthetic asset, in exchange for a share of the pool debt"
+      ],
+      "accounts": [
+        {
+          "name": "syntheticAsset",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The synthetic asset account"
+          ]
+        },
+        {
+          "name": "debtPool",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The debt pool shared by the synthetic asset"
+          ]
+        },
+        {
+          "name": "collateralVault",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The vault that is receiving collateral, of any collateral the synthetic asset accepts"
+          ]
+        },
+        {
+          "name": "collateralMint",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The mint of the collateral being deposited"
+          ]
+        },
+        {
+          "name": "syntheticMint",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The synthetic mint of the synthetic asset"
+          ]
+        },
+        {
+          "name": "syntheticOracle",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The oracle price feed, to determine margin account health"
+          ]
+        },
+        {
+          "name": "assetAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The mint authority that can mint synthetic assets and transfer vault collateral"
+          ]
+        },
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "The receiver of the synthetic asset"
+          ]
+        },
+        {
+          "name": "marginAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The margin account of the owner, to track collateral and debt"
+          ]
+        },
+        {
+          "name": "treasury",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The treasury receiving fees, in the collateral of the vault"
+          ]
+        },
+        {
+          "name": "collateralAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The owners account that collateral will be transferred from"
+          ]
+        },
+        {
+          "name": "syntheticAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The owners account that will receive synthetic tokens"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The token program for CPI calls"
+          ]
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "collateralAmount",
+          "type": "u64"
+        },
+        {
+          "name": "mintAmount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "burnPoolDebt",
+      "docs": [
+        "Burn a pooled synthetic asset, repaying a share of the pool debt"
+      ],
+      "accounts": [
+        {
+          "name": "syntheticAsset",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The synthetic asset account"
+          ]
+        },
+        {
+          "name": "debtPool",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The debt pool shared by the synthetic asset"
+          ]
+        },
+        {
+          "name": "collateralVault",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The vault that collateral is withdrawn from, of any collateral the synthetic asset accepts"
+          ]
+        },
+        {
+          "name": "collateralMint",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The mint of the collateral being withdrawn"
+          ]
+        },
+        {
+          "name": "syntheticMint",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The synthetic mint of the synthetic asset"
+          ]
+        },
+        {
+          "name": "syntheticOracle",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The oracle price feed, to determine margin account health"
+          ]
+        },
+        {
+          "name": "assetAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The mint authority that can mint synthetic assets and transfer vault collateral"
+          ]
+        },
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "The receiver of the synthetic asset"
+          ]
+        },
+        {
+          "name": "marginAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The margin account of the owner, to track collateral and debt"
+          ]
+        },
+        {
+          "name": "treasury",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The treasury receiving fees, in the collateral of the vault"
+          ]
+        },
+        {
+          "name": "collateralAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The owners account that collateral will be transferred from"
+          ]
+        },
+        {
+          "name": "syntheticAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The owners account that will receive synthetic tokens"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The token program for CPI calls"
+          ]
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "collateralAmount",
+          "type": "u64"
+        },
+        {
+          "name": "burnAmount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "exchangeSyntheticAsset",
+      "docs": [
+        "Exchange a pooled synthetic asset for another at oracle prices"
+      ],
+      "accounts": [
+        {
+          "name": "sourceSyntheticAsset",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The synthetic asset being exchanged"
+          ]
+        },
+        {
+          "name": "sourceMint",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The synthetic mint of the synthetic asset being exchanged"
+          ]
+        },
+        {
+          "name": "sourceOracle",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The oracle price feed of the synthetic asset being exchanged"
+          ]
+        },
+        {
+          "name": "targetSyntheticAsset",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The synthetic asset being received"
+          ]
+        },
+        {
+          "name": "targetMint",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The synthetic mint of the synthetic asset being received"
+          ]
+        },
+        {
+          "name": "targetOracle",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The oracle price feed of the synthetic asset being received"
+          ]
+        },
+        {
+          "name": "targetAssetAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The mint authority of the synthetic asset being received"
+          ]
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "The owner of the synthetic assets"
+          ]
+        },
+        {
+          "name": "sourceAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The owners account that synthetic tokens will be burned from"
+          ]
+        },
+        {
+          "name": "targetAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The account that will receive the exchanged synthetic tokens"
+          ]
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The token program for CPI calls"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "redeem",
+      "docs": [
+        "Redeem a synthetic asset for primary collateral at the oracle price, repaying",
+        "the debt of the margin accounts passed in, ordered lowest collateral ratio first"
+      ],
+      "accounts": [
+        {
+          "name": "syntheticAsset",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The synthetic asset account"
+          ]
+        },
+        {
+          "name": "collateralVault",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The vault of the primary collateral, which is paid out"
+          ]
+        },
+        {
+          "name": "collateralMint",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The primary collateral mint"
+          ]
+        },
+        {
+          "name": "syntheticMint",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The synthetic mint of the synthetic asset"
+          ]
+        },
+        {
+          "name": "syntheticOracle",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The oracle price feed, to value the synthetic asset redeemed"
+          ]
+        },
+        {
+          "name": "assetAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The authority that can transfer vault collateral"
+          ]
+        },
+        {
+          "name": "redeemer",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "The holder redeeming synthetic tokens"
+          ]
+        },
+        {
+          "name": "treasury",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The treasury receiving redemption fees, in the primary collateral"
+          ]
+        },
+        {
+          "name": "redeemerCollateralAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The redeemers account that will receive collateral"
+          ]
+        },
+        {
+          "name": "redeemerSyntheticAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The redeemers account that synthetic tokens will be burned from"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The token program for CPI calls"
+          ]
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "liquidateMarginAccount",
+      "docs": [
+        "Liquidate an unhealthy margin account, repaying debt in exchange for collateral"
+      ],
+      "accounts": [
+        {
+          "name": "syntheticAsset",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The synthetic asset account"
+          ]
+        },
+        {
+          "name": "collateralVault",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The vault that collateral is seized from, of any collateral the synthetic asset accepts"
+          ]
+        },
+        {
+          "name": "collateralMint",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The mint of the collateral being seized"
+          ]
+        },
+        {
+          "name": "syntheticMint",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The synthetic mint of the synthetic asset"
+          ]
+        },
+        {
+          "name": "syntheticOracle",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The oracle price feed, to determine margin account health"
+          ]
+        },
+        {
+          "name": "assetAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The mint authority that can mint synthetic assets and transfer vault collateral"
+          ]
+        },
+        {
+          "name": "liquidator",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "The liquidator repaying debt in exchange for collateral"
+          ]
+        },
+        {
+          "name": "marginAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The unhealthy margin account being liquidated"
+          ]
+        },
+        {
+          "name": "liquidatorCollateralAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The liquidators account that will receive seized collateral"
+          ]
+        },
+        {
+          "name": "liquidatorSyntheticAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The liquidators account that synthetic tokens will be burned from"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The token program for CPI calls"
+          ]
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "repayAmount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "liquidatePoolAccount",
+      "docs": [
+        "Liquidate an unhealthy margin account owing debt pool shares"
+      ],
+      "accounts": [
+        {
+          "name": "syntheticAsset",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The synthetic asset account"
+          ]
+        },
+        {
+          "name": "debtPool",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The debt pool shared by the synthetic asset"
+          ]
+        },
+        {
+          "name": "collateralVault",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The vault that collateral is seized from, of any collateral the synthetic asset accepts"
+          ]
+        },
+        {
+          "name": "collateralMint",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The mint of the collateral being seized"
+          ]
+        },
+        {
+          "name": "syntheticMint",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The synthetic mint of the synthetic asset"
+          ]
+        },
+        {
+          "name": "syntheticOracle",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The oracle price feed, to determine margin account health"
+          ]
+        },
+        {
+          "name": "assetAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The mint authority that can mint synthetic assets and transfer vault collateral"
+          ]
+        },
+        {
+          "name": "liquidator",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "The liquidator repaying debt in exchange for collateral"
+          ]
+        },
+        {
+          "name": "marginAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The unhealthy margin account being liquidated, which owes debt pool shares"
+          ]
+        },
+        {
+          "name": "liquidatorCollateralAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The liquidators account that will receive seized collateral"
+          ]
+        },
+        {
+          "name": "liquidatorSyntheticAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The liquidators account that synthetic tokens will be burned from"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The token program for CPI calls"
+          ]
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "repayAmount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "initializeCrossMarginAccount",
+      "docs": [
+        "Initialize a cross-margin account, whose collateral backs several synthetic assets"
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "The payer of cross-margin account rent"
+          ]
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The owner of the cross-margin account, who can mint assets"
+          ]
+        },
+        {
+          "name": "collateralMint",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The collateral mint backing every position of the cross-margin account"
+          ]
+        },
+        {
+          "name": "crossMarginAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The cross-margin account to initialize"
+          ]
+        },
+        {
+          "name": "collateralVault",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The vault holding the collateral of the cross-margin account"
+          ]
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The token program to initialize token accounts"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "System program for CPI"
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "crossMarginDeposit",
+      "docs": [
+        "Deposit collateral into a cross-margin account, on behalf of its owner"
+      ],
+      "accounts": [
+        {
+          "name": "depositor",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "The depositor of collateral, who doesn't have to be the cross-margin account owner"
+          ]
+        },
+        {
+          "name": "crossMarginAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The cross-margin account being credited with collateral"
+          ]
+        },
+        {
+          "name": "collateralVault",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The vault of the cross-margin account"
+          ]
+        },
+        {
+          "name": "depositorCollateralAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The depositors account that collateral will be transferred from"
+          ]
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The token program for CPI calls"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "collateralAmount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "crossMarginWithdraw",
+      "docs": [
+        "Withdraw collateral from a cross-margin account, as its owner"
+      ],
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "The owner of the cross-margin account"
+          ]
+        },
+        {
+          "name": "crossMarginAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The cross-margin account of the owner, to track collateral and debt"
+          ]
+        },
+        {
+          "name": "collateralVault",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The vault of the cross-margin account"
+          ]
+        },
+        {
+          "name": "collateralAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The owners account that will receive collateral"
+          ]
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The token program for CPI calls"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "collateralAmount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "crossMarginMint",
+      "docs": [
+        "Mint a synthetic asset against the collateral of a cross-margin account"
+      ],
+      "accounts": [
+        {
+          "name": "syntheticAsset",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The synthetic asset account"
+          ]
+        },
+        {
+          "name": "collateralMint",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The collateral mint shared by the synthetic asset and the cross-margin account"
+          ]
+        },
+        {
+          "name": "syntheticMint",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The synthetic mint of the synthetic asset"
+          ]
+        },
+        {
+          "name": "syntheticOracle",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The oracle price feed, to determine cross-margin account health"
+          ]
+        },
+        {
+          "name": "assetAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The mint authority that can mint synthetic assets"
+          ]
+        },
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "The receiver of the synthetic asset"
+          ]
+        },
+        {
+          "name": "crossMarginAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The cross-margin account of the owner, to track collateral and debt"
+          ]
+        },
+        {
+          "name": "collateralVault",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The vault of the cross-margin account, which fees are paid from"
+          ]
+        },
+        {
+          "name": "treasury",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The treasury receiving fees, in the collateral of the synthetic asset"
+          ]
+        },
+        {
+          "name": "syntheticAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The owners account that will receive synthetic tokens"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The token program for CPI calls"
+          ]
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "mintAmount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "crossMarginBurn",
+      "docs": [
+        "Burn a synthetic asset, repaying a position of a cross-margin account"
+      ],
+      "accounts": [
+        {
+          "name": "syntheticAsset",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The synthetic asset account"
+          ]
+        },
+        {
+          "name": "collateralMint",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The collateral mint shared by the synthetic asset and the cross-margin account"
+          ]
+        },
+        {
+          "name": "syntheticMint",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The synthetic mint of the synthetic asset"
+          ]
+        },
+        {
+          "name": "syntheticOracle",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The oracle price feed, to value the burn fee"
+          ]
+        },
+        {
+          "name": "assetAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The authority of the synthetic asset, which owns the treasury"
+          ]
+        },
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "The owner of the cross-margin account"
+          ]
+        },
+        {
+          "name": "crossMarginAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The cross-margin account of the owner, to track collateral and debt"
+          ]
+        },
+        {
+          "name": "collateralVault",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The vault of the cross-margin account, which fees are paid from"
+          ]
+        },
+        {
+          "name": "treasury",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The treasury receiving fees, in the collateral of the synthetic asset"
+          ]
+        },
+        {
+          "name": "syntheticAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The owners account that synthetic tokens will be burned from"
+          ]
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The token program for CPI calls"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "burnAmount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "liquidateCrossMarginAccount",
+      "docs": [
+        "Liquidate a position of an unhealthy cross-margin account"
+      ],
+      "accounts": [
+        {
+          "name": "syntheticAsset",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The synthetic asset of the position being repaid"
+          ]
+        },
+        {
+          "name": "collateralMint",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The collateral mint shared by the synthetic asset and the cross-margin account"
+          ]
+        },
+        {
+          "name": "syntheticMint",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The synthetic mint of the synthetic asset"
+          ]
+        },
+        {
+          "name": "syntheticOracle",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The oracle price feed, to determine cross-margin account health"
+          ]
+        },
+        {
+          "name": "liquidator",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "The liquidator repaying debt in exchange for collateral"
+          ]
+        },
+        {
+          "name": "crossMarginAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The unhealthy cross-margin account being liquidated"
+          ]
+        },
+        {
+          "name": "collateralVault",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The vault of the cross-margin account that collateral is seized from"
+          ]
+        },
+        {
+          "name": "liquidatorCollateralAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The liquidators account that will receive seized collateral"
+          ]
+        },
+        {
+          "name": "liquidatorSyntheticAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The liquidators account that synthetic tokens will be burned from"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The token program for CPI calls"
+          ]
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "repayAmount",
+          "type": "u64"
+        }
+      ]
+    }
+  ],
+  "accounts": [
+    {
+      "name": "crossMarginAccount",
+      "docs": [
+        "A margin account whose collateral backs debt in several synthetic assets.",
+        "The collateral is a single mint valued at $1, held in a vault owned by the account."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "owner",
+            "type": "publicKey"
+          },
+          {
+            "name": "collateralMint",
+            "docs": [
+              "The collateral mint, which must be the collateral mint of every synthetic asset borrowed"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "collateralVault",
+            "docs": [
+              "The vault holding the collateral, owned by this account"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "collateralDeposited",
+            "type": "u64"
+          },
+          {
+            "name": "positions",
+            "type": {
+              "array": [
+                {
+                  "defined": "CrossMarginPosition"
+                },
+                8
+              ]
+            }
+          },
+          {
+            "name": "bump",
+            "type": {
+              "array": [
+                "u8",
+                1
+              ]
+            }
+          },
+          {
+            "name": "collateralDecimals",
+            "docs": [
+              "The decimals of the collateral mint"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "padding",
+            "type": {
+              "array": [
+                "u8",
+                6
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "debtPool",
+      "docs": [
+        "A program-wide pool of debt shared by the synthetic assets that join it.",
+        "Margin accounts of pooled synthetic assets hold a share of the USD value of",
+        "every pooled synthetic asset in circulation, instead of a fixed synthetic amount."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "admin",
+            "docs": [
+              "The admin that can add synthetic assets to the pool"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "syntheticAssets",
+            "docs": [
+              "The synthetic assets sharing the pool debt, followed by default pubkeys"
+            ],
+            "type": {
+              "array": [
+                "publicKey",
+                8
+              ]
+            }
+          },
+          {
+            "name": "totalDebtShares",
+            "docs": [
+              "The debt shares held by all margin accounts of the pool"
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "marginAccount",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "owner",
+            "type": "publicKey"
+          },
+          {
+            "name": "syntheticAsset",
+            "type": "publicKey"
+          },
+          {
+            "name": "collateralDeposited",
+            "type": "u64"
+          },
+          {
+            "name": "normalizedDebt",
+            "docs": [
+              "The synthetic debt divided by the borrow index of the synthetic asset,",
+              "so that it grows with the stability fee"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "debtShares",
+            "docs": [
+              "The share of the debt pool owed, when the synthetic asset shares the debt pool"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "collateralTypeDeposited",
+            "docs": [
+              "Deposits of the registered collateral types of the synthetic asset, by registry index"
+            ],
+            "type": {
+              "array": [
+                "u64",
+                4
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "programConfig",
+      "docs": [
+        "Program-wide settings of a deployment. Each cluster has its own config,",
+        "so it lists the oracle program IDs deployed to that cluster."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "admin",
+            "docs": [
+              "The admin that can update the program config"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "oraclePrograms",
+            "docs": [
+              "The programs whose price accounts can price synthetic assets, followed by default pubkeys"
+            ],
+            "type": {
+              "array": [
+                "publicKey",
+                4
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "syntheticAsset",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "syntheticAsset",
+            "type": "publicKey"
+          },
+          {
+            "name": "collateralMint",
+            "type": "publicKey"
+          },
+          {
+            "name": "collateralVault",
+            "type": "publicKey"
+          },
+          {
+            "name": "syntheticMint",
+            "type": "publicKey"
+          },
+          {
+            "name": "syntheticOracle",
+            "type": "publicKey"
+          },
+          {
+            "name": "oracleProgram",
+            "docs": [
+              "The program owning the synthetic oracle price feed"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "assetAuthority",
+            "type": "publicKey"
+          },
+          {
+            "name": "admin",
+            "docs": [
+              "The admin that can update parameters of the synthetic asset"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "pendingAdmin",
+            "docs": [
+              "The proposed new admin, who must accept before becoming the admin"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "debtPool",
+            "docs": [
+              "The debt pool sharing the debt of this synthetic asset,",
+              "or the default pubkey if its margin accounts owe synthetic amounts"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "symbol",
+            "docs": [
+              "The symbol the synthetic asset address is derived from, zero padded.",
+              "Synthetic assets initialized before symbols were derived from their first oracle"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "params",
+            "docs": [
+              "Risk parameters, updatable by the admin"
+            ],
+            "type": {
+              "defined": "SyntheticAssetParams"
+            }
+          },
+          {
+            "name": "collateralTypes",
+            "docs": [
+              "Collateral accepted in addition to the primary collateral mint"
+            ],
+            "type": {
+              "array": [
+                {
+                  "defined": "CollateralType"
+                },
+                4
+              ]
+            }
+          },
+          {
+            "name": "borrowIndex",
+            "docs": [
+              "The cumulative stability fee index. Debt is stored divided by this index"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "lastAccrualTime",
+            "docs": [
+              "The unix timestamp the borrow index was last updated"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "totalNormalizedDebt",
+            "docs": [
+              "The sum of the normalized debt of all margin accounts"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "accruedFees",
+            "docs": [
+              "Stability fees accrued but not yet minted to the treasury"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "totalDebt",
+            "docs": [
+              "The synthetic debt owed to the protocol, including the accrued stability fee.",
+              "The synthetic supply plus the uncollected fees never exceed it"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "totalCollateral",
+            "docs": [
+              "The primary collateral deposited by all margin accounts, which the vault must hold"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "marginAccountCount",
+            "docs": [
+              "The number of margin accounts open on the synthetic asset"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "settlementCollateral",
+            "docs": [
+              "The primary collateral set aside in the vault for synthetic holders after a shutdown"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "settlementPrice",
+            "docs": [
+              "The final price of the synthetic asset recorded at shutdown, with `settlement_expo`"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "lastPrice",
+            "docs": [
+              "The last oracle price of the synthetic asset the circuit breaker accepted"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "lastPriceSlot",
+            "docs": [
+              "The slot `last_price` was accepted in"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "cooldownEndSlot",
+            "docs": [
+              "The slot the circuit breaker cooldown ends in, after a price moved too fast"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "assetAuthorityBump",
+            "type": {
+              "array": [
+                "u8",
+                1
+              ]
+            }
+          },
+          {
+            "name": "collateralDecimals",
+            "docs": [
+              "The decimals of the primary collateral mint"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "syntheticDecimals",
+            "docs": [
+              "The decimals of the synthetic mint"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "status",
+            "docs": [
+              "The `SyntheticAssetStatus` of the synthetic asset"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "settlementExpo",
+            "docs": [
+              "The exponent of the settlement price"
+            ],
+            "type": "i32"
+          }
+        ]
+      }
+    }
+  ],
+  "types": [
+    {
+      "name": "CollateralType",
+      "docs": [
+        "An additional collateral mint accepted by a synthetic asset"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "mint",
+            "docs": [
+              "The collateral mint, or the default pubkey if this slot is unused"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "vault",
+            "docs": [
+              "The vault holding deposits of this collateral"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "oracle",
+            "docs": [
+              "The oracle price feed of this collateral"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "oracleProgram",
+            "docs": [
+              "The program owning the oracle price feed"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "loanToValueBps",
+            "docs": [
+              "The portion of the collateral value counted towards margin account health,",
+              "in basis points"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "totalCollateral",
+            "docs": [
+              "The collateral deposited by all margin accounts, which the vault must hold"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "settlementCollateral",
+            "docs": [
+              "The collateral set aside in the vault for synthetic holders after a shutdown"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "decimals",
+            "docs": [
+              "The decimals of the collateral mint"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "padding",
+            "type": {
+              "array": [
+                "u8",
+                7
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "CrossMarginPosition",
+      "docs": [
+        "The debt of a cross-margin account in one synthetic asset"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "syntheticAsset",
+            "docs": [
+              "The synthetic asset borrowed, or the default pubkey if this slot is unused"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "normalizedDebt",
+            "docs": [
+              "The synthetic debt divided by the borrow index of the synthetic asset"
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SyntheticAssetParams",
+      "docs": [
+        "Risk parameters of a synthetic asset"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "initialCollateralRatioBps",
+            "docs": [
+              "The collateral ratio required to mint or withdraw, in basis points"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "maintenanceCollateralRatioBps",
+            "docs": [
+              "The collateral ratio below which a margin account can be liquidated, in basis points"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "liquidationBonusBps",
+            "docs": [
+              "The collateral bonus paid to liquidators, in basis points of the repaid debt value"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "liquidationCloseFactorBps",
+            "docs": [
+              "The maximum portion of a margin account's debt that can be repaid",
+              "in a single liquidation, in basis points"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "maxOracleAge",
+            "docs": [
+              "The maximum age of an oracle price, in seconds"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "haltedPriceGracePeriod",
+            "docs": [
+              "How long the last trading price of a halted feed can still repay or liquidate debt, in seconds"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "maxOracleConfidenceBps",
+            "docs": [
+              "The maximum oracle confidence interval, in basis points of the price"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "pricingPolicy",
+            "docs": [
+              "The `PricingPolicy` choosing between the spot and EMA oracle prices"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "maxPriceChangeBpsPerSlot",
+            "docs": [
+              "How far the synthetic price can move per slot before the circuit breaker trips,",
+              "in basis points, or zero to disable the circuit breaker"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "circuitBreakerCooldownSlots",
+            "docs": [
+              "How many slots minting and withdrawing stay disabled after the circuit breaker trips"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "confidenceMultiplierBps",
+            "docs": [
+              "How many confidence intervals oracle prices are moved against margin accounts, in basis points"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "stabilityFeeBps",
+            "docs": [
+              "The annual stability fee charged on synthetic debt, in basis points"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "mintFeeBps",
+            "docs": [
+              "The fee charged in collateral when minting, in basis points of the value minted"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "burnFeeBps",
+            "docs": [
+              "The fee charged in collateral when burning, in basis points of the value burned"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "redemptionFeeBps",
+            "docs": [
+              "The fee charged in collateral when redeeming, in basis points of the collateral redeemed"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "debtCeiling",
+            "docs": [
+              "The maximum total debt of the synthetic asset, including the accrued stability fee"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "maxAccountDebt",
+            "docs": [
+              "The maximum synthetic debt of a single margin account, or zero for no cap"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "minDebt",
+            "docs": [
+              "The minimum synthetic debt of a margin account, so positions stay worth liquidating"
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "PriceSide",
+      "docs": [
+        "What an oracle price values, which decides the conservative direction"
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Debt"
+          },
+          {
+            "name": "Collateral"
+          }
+        ]
+      }
+    },
+    {
+      "name": "PriceRequirement",
+      "docs": [
+        "Which feed statuses an oracle price is accepted in. Equity and FX feeds stop trading",
+        "outside market hours, and keep reporting a price that can't be traded against."
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Trading"
+          },
+          {
+            "name": "LastTrading"
+          }
+        ]
+      }
+    },
+    {
+      "name": "CollateralSlot",
+      "docs": [
+        "Which of the collateral of a synthetic asset is being used"
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Primary"
+          },
+          {
+            "name": "Registered",
+            "fields": [
+              {
+                "defined": "usize"
+              }
+            ]
+          }
+        ]
+      }
+    },
+    {
+      "name": "HealthCheck",
+      "docs": [
+        "Which collateral ratio of each synthetic asset the health of a",
+        "cross-margin account is checked against"
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Initial"
+          },
+          {
+            "name": "Maintenance"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SyntheticAssetStatus",
+      "docs": [
+        "The lifecycle of a synthetic asset"
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Active"
+          },
+          {
+            "name": "WindingDown"
+          },
+          {
+            "name": "Shutdown"
+          }
+        ]
+      }
+    },
+    {
+      "name": "PricingPolicy",
+      "docs": [
+        "Which oracle price health checks value margin accounts of a synthetic asset at.",
+        "Tokens always change hands at the aggregate price."
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Spot"
+          },
+          {
+            "name": "Ema"
+          },
+          {
+            "name": "Conservative"
+          }
+        ]
+      }
+    }
+  ],
+  "errors": [
+    {
+      "code": 6000,
+      "name": "InvalidOracle",
+      "msg": "The oracle account provided is invalid"
+    },
+    {
+      "code": 6001,
+      "name": "StaleOracle",
+      "msg": "The oracle is stale"
+    },
+    {
+      "code": 6002,
+      "name": "Undercollateralized",
+      "msg": "The margin account is undercollateralized"
+    },
+    {
+      "code": 6003,
+      "name": "MarginAccountHealthy",
+      "msg": "The margin account is healthy and cannot be liquidated"
+    },
+    {
+      "code": 6004,
+      "name": "InvalidLiquidationParameters",
+      "msg": "The liquidation parameters are invalid"
+    },
+    {
+      "code": 6005,
+      "name": "MathOverflow",
+      "msg": "Math overflow"
+    },
+    {
+      "code": 6006,
+      "name": "OracleNotTrading",
+      "msg": "The oracle price is not currently trading"
+    },
+    {
+      "code": 6007,
+      "name": "OracleConfidenceTooWide",
+      "msg": "The oracle confidence interval is too wide"
+    },
+    {
+      "code": 6008,
+      "name": "InvalidOracleParameters",
+      "msg": "The oracle parameters are invalid"
+    },
+    {
+      "code": 6009,
+      "name": "InvalidCollateralRatios",
+      "msg": "The collateral ratios are invalid"
+    },
+    {
+      "code": 6010,
+      "name": "InvalidCollateral",
+      "msg": "The collateral is not accepted by the synthetic asset"
+    },
+    {
+      "code": 6011,
+      "name": "InvalidCollateralParameters",
+      "msg": "The collateral parameters are invalid"
+    },
+    {
+      "code": 6012,
+      "name": "CollateralTypesFull",
+      "msg": "The synthetic asset accepts the maximum number of collateral types"
+    },
+    {
+      "code": 6013,
+      "name": "InvalidFeeParameters",
+      "msg": "The fee parameters are invalid"
+    },
+    {
+      "code": 6014,
+      "name": "InsufficientCollateralForFee",
+      "msg": "The margin account does not have enough collateral to pay the fee"
+    },
+    {
+      "code": 6015,
+      "name": "InvalidDebtPool",
+      "msg": "The synthetic asset is not in the debt pool"
+    },
+    {
+      "code": 6016,
+      "name": "SyntheticAssetPooled",
+      "msg": "The synthetic asset shares a debt pool"
+    },
+    {
+      "code": 6017,
+      "name": "DebtPoolFull",
+      "msg": "The debt pool has the maximum number of synthetic assets"
+    },
+    {
+      "code": 6018,
+      "name": "OutstandingDebt",
+      "msg": "The synthetic asset has outstanding debt"
+    },
+    {
+      "code": 6019,
+      "name": "CrossMarginPositionsFull",
+      "msg": "The cross-margin account borrows the maximum number of synthetic assets"
+    },
+    {
+      "code": 6020,
+      "name": "InvalidCrossMarginPosition",
+      "msg": "The synthetic asset accounts do not match the cross-margin positions"
+    },
+    {
+      "code": 6021,
+      "name": "DebtCeilingExceeded",
+      "msg": "Minting would exceed the debt ceiling of the synthetic asset"
+    },
+    {
+      "code": 6022,
+      "name": "AccountDebtCapExceeded",
+      "msg": "Minting would exceed the debt cap of a single margin account"
+    },
+    {
+      "code": 6023,
+      "name": "DebtBelowMinimum",
+      "msg": "The margin account debt must be repaid in full or stay above the minimum debt"
+    },
+    {
+      "code": 6024,
+      "name": "InvalidDebtLimits",
+      "msg": "The per-account debt cap must be at least the minimum debt"
+    },
+    {
+      "code": 6025,
+      "name": "CollateralAccountingMismatch",
+      "msg": "A vault holds less collateral than deposited into the synthetic asset"
+    },
+    {
+      "code": 6026,
+      "name": "DebtAccountingMismatch",
+      "msg": "The synthetic supply exceeds the debt owed to the synthetic asset"
+    },
+    {
+      "code": 6027,
+      "name": "MarginAccountHasDebt",
+      "msg": "The margin account has outstanding debt"
+    },
+    {
+      "code": 6028,
+      "name": "SyntheticAssetNotActive",
+      "msg": "The synthetic asset is not active and can't be minted"
+    },
+    {
+      "code": 6029,
+      "name": "SyntheticAssetShutdown",
+      "msg": "The synthetic asset is shut down"
+    },
+    {
+      "code": 6030,
+      "name": "SyntheticAssetNotShutdown",
+      "msg": "The synthetic asset is not shut down"
+    },
+    {
+      "code": 6031,
+      "name": "InvalidSettlementPrice",
+      "msg": "The settlement price must be positive"
+    },
+    {
+      "code": 6032,
+      "name": "SettlementPending",
+      "msg": "All debt must be settled and fees collected before redeeming"
+    },
+    {
+      "code": 6033,
+      "name": "InvalidMarginAccount",
+      "msg": "The margin account belongs to another synthetic asset"
+    },
+    {
+      "code": 6034,
+      "name": "InvalidRedemptionOrder",
+      "msg": "Margin accounts must be passed in order of collateral ratio, lowest first"
+    },
+    {
+      "code": 6035,
+      "name": "CircuitBreakerCooldown",
+      "msg": "The circuit breaker is cooling down after the oracle price moved too fast"
+    },
+    {
+      "code": 6036,
+      "name": "InvalidOracleOwner",
+      "msg": "The oracle account is not owned by an allowed oracle program"
+    },
+    {
+      "code": 6037,
+      "name": "InvalidSymbol",
+      "msg": "Symbols must be between 1 and 32 bytes"
+    },
+    {
+      "code": 6038,
+      "name": "InvalidOraclePrograms",
+      "msg": "At most 4 oracle programs can be allowed, none of them the default pubkey"
+    },
+    {
+      "code": 6039,
+      "name": "InvalidProgramAuthority",
+      "msg": "Only the program upgrade authority can initialize the program config"
+    },
+    {
+      "code": 6040,
+      "name": "DuplicateMarginAccount",
+      "msg": "A margin account can only be redeemed from once per redemption"
+    },
+    {
+      "code": 6041,
+      "name": "MarginAccountsOpen",
+      "msg": "Every margin account must be closed before closing the synthetic asset"
+    }
+  ]
+};
+
+export const IDL: Resynth = {
+  "version": "0.1.0",
+  "name": "resynth",
+  "constants": [
+    {
+      "name": "ASSET",
+      "type": "string",
+      "value": "\"asset\""
+    },
+    {
+      "name": "AUTHORITY",
+      "type": "string",
+      "value": "\"authority\""
+    },
+    {
+      "name": "VAULT",
+      "type": "string",
+      "value": "\"vault\""
+    },
+    {
+      "name": "MINT",
+      "type": "string",
+      "value": "\"mint\""
+    },
+    {
+      "name": "MARGIN_ACCOUNT",
+      "type": "string",
+      "value": "\"margin_account\""
+    },
+    {
+      "name": "TREASURY",
+      "type": "string",
+      "value": "\"treasury\""
+    },
+    {
+      "name": "DEBT_POOL",
+      "type": "string",
+      "value": "\"debt_pool\""
+    },
+    {
+      "name": "PROGRAM_CONFIG",
+      "type": "string",
+      "value": "\"program_config\""
+    },
+    {
+      "name": "CROSS_MARGIN_ACCOUNT",
+      "type": "string",
+      "value": "\"cross_margin_account\""
+    }
+  ],
+  "instructions": [
+    {
+      "name": "initializeProgramConfig",
+      "docs": [
+        "Initialize the program-wide config, allowing the oracle programs of this cluster"
+      ],
+      "accounts": [
+        {
+          "name": "programConfig",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The program-wide config to initialize"
+          ]
+        },
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "The admin that can update the program config. The config is a singleton that",
+            "decides which oracles are trusted, so only the upgrade authority can claim it."
+          ]
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The resynth program being configured"
+          ]
+        },
+        {
+          "name": "programData",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The program data of the resynth program, naming its upgrade authority"
+          ]
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "The payer of rent for the program config"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The system program to create accounts"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "oraclePrograms",
+          "type": {
+            "vec": "publicKey"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setOraclePrograms",
+      "docs": [
+        "Replace the oracle programs allowed to price synthetic assets, as the config admin"
+      ],
+      "accounts": [
+        {
+          "name": "programConfig",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The program-wide config to update"
+          ]
+        },
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "The admin of the program config"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "oraclePrograms",
+          "type": {
+            "vec": "publicKey"
+          }
+        }
+      ]
+    },
+    {
+      "name": "initializeSyntheticAsset",
+      "docs": [
+        "Initialize a new synthetic asset, derived from its symbol, with a synthetic mint",
+        "of `synthetic_decimals` decimals"
+      ],
+      "accounts": [
+        {
+          "name": "syntheticAsset",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The synthetic asset account to initialize, derived from its symbol so that",
+            "the oracle can be replaced without moving the synthetic asset"
+          ]
+        },
+        {
+          "name": "collateralMint",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The mint to use as collateral for the synthetic asset"
+          ]
+        },
+        {
+          "name": "collateralVault",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The vault of hard assets to collateralize the circulating synthetic assets"
+          ]
+        },
+        {
+          "name": "syntheticMint",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The synthetic asset mint, with the decimals chosen for the asset"
+          ]
+        },
+        {
+          "name": "syntheticOracle",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The synthetic asset oracle price feed, to determine margin account health"
+          ]
+        },
+        {
+          "name": "programConfig",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The program-wide config listing the allowed oracle programs"
+          ]
+        },
+        {
+          "name": "configAdmin",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "The admin of the program config. Symbols are first come first served,",
+            "so only the deployment can claim them."
+          ]
+        },
+        {
+          "name": "assetAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The mint authority that can mint synthetic assets and transfer vault collateral"
+          ]
+        },
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The admin that can update the synthetic asset parameters"
+          ]
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "The payer of rent for various accounts"
+          ]
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The token program to initialize token accounts"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The system program to create accounts"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "symbol",
+          "type": "string"
+        },
+        {
+          "name": "params",
+          "type": {
+            "defined": "SyntheticAssetParams"
+          }
+        },
+        {
+          "name": "syntheticDecimals",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "updateSyntheticAsset",
+      "docs": [
+        "Update the risk parameters of a synthetic asset"
+      ],
+      "accounts": [
+        {
+          "name": "syntheticAsset",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The synthetic asset to update"
+          ]
+        },
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "The admin of the synthetic asset"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "params",
+          "type": {
+            "defined": "SyntheticAssetParams"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setOracle",
+      "docs": [
+        "Replace the oracle price feed of a synthetic asset, as its admin"
+      ],
+      "accounts": [
+        {
+          "name": "syntheticAsset",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The synthetic asset whose oracle is replaced"
+          ]
+        },
+        {
+          "name": "syntheticOracle",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The new oracle price feed of the synthetic asset"
+          ]
+        },
+        {
+          "name": "programConfig",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The program-wide config listing the allowed oracle programs"
+          ]
+        },
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "The admin of the synthetic asset"
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "transferAdmin",
+      "docs": [
+        "Propose a new admin of a synthetic asset"
+      ],
+      "accounts": [
+        {
+          "name": "syntheticAsset",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The synthetic asset to transfer"
+          ]
+        },
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "The current admin of the synthetic asset"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "newAdmin",
+          "type": "publicKey"
+        }
+      ]
+    },
+    {
+      "name": "acceptAdmin",
+      "docs": [
+        "Accept the admin role of a synthetic asset, as the proposed admin"
+      ],
+      "accounts": [
+        {
+          "name": "syntheticAsset",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The synthetic asset being transferred"
+          ]
+        },
+        {
+          "name": "pendingAdmin",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "The proposed admin of the synthetic asset"
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "addCollateralType",
+      "docs": [
+        "Accept an additional collateral mint for a synthetic asset"
+      ],
+      "accounts": [
+        {
+          "name": "syntheticAsset",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The synthetic asset accepting the new collateral"
+          ]
+        },
+        {
+          "name": "collateralMint",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The mint of the new collateral"
+          ]
+        },
+        {
+          "name": "collateralVault",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The vault of the new collateral"
+          ]
+        },
+        {
+          "name": "collateralOracle",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The oracle price feed of the new collateral"
+          ]
+        },
+        {
+          "name": "programConfig",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The program-wide config listing the allowed oracle programs"
+          ]
+        },
+        {
+          "name": "assetAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The mint authority that can mint synthetic assets and transfer vault collateral"
+          ]
+        },
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "The admin of the synthetic asset"
+          ]
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "The payer of rent for the vault"
+          ]
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The token program to initialize token accounts"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The system program to create accounts"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "loanToValueBps",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "updateCollateralType",
+      "docs": [
+        "Update the loan to value of an additional collateral mint"
+      ],
+      "accounts": [
+        {
+          "name": "syntheticAsset",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The synthetic asset accepting the collateral"
+          ]
+        },
+        {
+          "name": "collateralVault",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The vault of the collateral to update"
+          ]
+        },
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "The admin of the synthetic asset"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "loanToValueBps",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "updateCircuitBreaker",
+      "docs": [
+        "Record the oracle price of a synthetic asset, tripping its circuit breaker on a bad print.",
+        "Mints and withdrawals rejected by a bad print revert their own trip, so keepers crank",
+        "this to start the cooldown."
+      ],
+      "accounts": [
+        {
+          "name": "syntheticAsset",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The synthetic asset whose circuit breaker is updated"
+          ]
+        },
+        {
+          "name": "syntheticOracle",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The oracle price feed of the synthetic asset"
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "confirmOraclePrice",
+      "docs": [
+        "Confirm the oracle price of a synthetic asset as its admin, ending a circuit breaker cooldown"
+      ],
+      "accounts": [
+        {
+          "name": "syntheticAsset",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The synthetic asset whose oracle price is confirmed"
+          ]
+        },
+        {
+          "name": "syntheticOracle",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The oracle price feed of the synthetic asset"
+          ]
+        },
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "The admin of the synthetic asset"
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "closeSyntheticAsset",
+      "docs": [
+        "Wind down a synthetic asset, closing it once nothing is owed to or by it",
+        "and every margin account is closed"
+      ],
+      "accounts": [
+        {
+          "name": "syntheticAsset",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The synthetic asset to close"
+          ]
+        },
+        {
+          "name": "collateralVault",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The vault to close"
+          ]
+        },
+        {
+          "name": "syntheticMint",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The synthetic mint, which must have no supply left to close"
+          ]
+        },
+        {
+          "name": "assetAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The mint authority that can transfer vault collateral"
+          ]
+        },
+        {
+          "name": "admin",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "The admin of the synthetic asset, who receives the rent"
+          ]
+        },
+        {
+          "name": "collateralAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The admins account that receives collateral sent to the vault without a deposit"
+          ]
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The token program for CPI calls"
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "shutdownSyntheticAsset",
+      "docs": [
+        "Shut down a synthetic asset, settling all debt at a final price"
+      ],
+      "accounts": [
+        {
+          "name": "syntheticAsset",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The synthetic asset to shut down"
+          ]
+        },
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "The admin of the synthetic asset"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "settlementPrice",
+          "type": "i64"
+        },
+        {
+          "name": "settlementExpo",
+          "type": "i32"
+        }
+      ]
+    },
+    {
+      "name": "settleMarginAccount",
+      "docs": [
+        "Settle the debt of a margin account of a shut down synthetic asset"
+      ],
+      "accounts": [
+        {
+          "name": "syntheticAsset",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The shut down synthetic asset"
+          ]
+        },
+        {
+          "name": "marginAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The margin account whose debt is settled, by anyone"
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "settleCrossMarginPosition",
+      "docs": [
+        "Settle the debt of a cross-margin position in a shut down synthetic asset"
+      ],
+      "accounts": [
+        {
+          "name": "syntheticAsset",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The shut down synthetic asset"
+          ]
+        },
+        {
+          "name": "collateralVault",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The vault of the synthetic asset, which receives the settlement collateral"
+          ]
+        },
+        {
+          "name": "crossMarginAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The cross-margin account whose position is settled, by anyone"
+          ]
+        },
+        {
+          "name": "crossMarginVault",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The vault of the cross-margin account"
+          ]
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The token program for CPI calls"
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "redeemSettlement",
+      "docs": [
+        "Redeem synthetic tokens of a shut down synthetic asset for its settlement collateral"
+      ],
+      "accounts": [
+        {
+          "name": "syntheticAsset",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The shut down synthetic asset"
+          ]
+        },
+        {
+          "name": "collateralVault",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The vault of the primary collateral"
+          ]
+        },
+        {
+          "name": "syntheticMint",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The synthetic mint of the synthetic asset"
+          ]
+        },
+        {
+          "name": "assetAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The authority that can transfer vault collateral"
+          ]
+        },
+        {
+          "name": "holder",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "The holder redeeming synthetic tokens"
+          ]
+        },
+        {
+          "name": "syntheticAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The holders account that synthetic tokens will be burned from"
+          ]
+        },
+        {
+          "name": "collateralAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The holders account that will receive primary collateral"
+          ]
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The token program for CPI calls"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "initializeDebtPool",
+      "docs": [
+        "Initialize the program-wide debt pool"
+      ],
+      "accounts": [
+        {
+          "name": "debtPool",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The program-wide debt pool to initialize"
+          ]
+        },
+        {
+          "name": "programConfig",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The program-wide config, whose admin owns the singleton debt pool"
+          ]
+        },
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "The admin that can add synthetic assets to the debt pool"
+          ]
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "The payer of rent for the debt pool"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The system program to create accounts"
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "addPoolSyntheticAsset",
+      "docs": [
+        "Add a synthetic asset to the debt pool, sharing its debt with all pooled synthetic assets"
+      ],
+      "accounts": [
+        {
+          "name": "debtPool",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The debt pool the synthetic asset joins"
+          ]
+        },
+        {
+          "name": "syntheticAsset",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The synthetic asset joining the debt pool"
+          ]
+        },
+        {
+          "name": "syntheticMint",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The synthetic mint of the synthetic asset"
+          ]
+        },
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "The admin of both the debt pool and the synthetic asset"
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "initializeMarginAccount",
+      "docs": [
+        "Initialize a new margin account"
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "The payer of margin account rent"
+          ]
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The owner of the margin account, who can mint assets"
+          ]
+        },
+        {
+          "name": "syntheticAsset",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The synthetic asset the margin account is associated with"
+          ]
+        },
+        {
+          "name": "marginAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The margin account to initialize"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "System program for CPI"
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "closeMarginAccount",
+      "docs": [
+        "Close a margin account without debt, sweeping its collateral to the owner"
+      ],
+      "accounts": [
+        {
+          "name": "syntheticAsset",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The synthetic asset account"
+          ]
+        },
+        {
+          "name": "collateralVault",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The vault of the primary collateral"
+          ]
+        },
+        {
+          "name": "assetAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The authority that can transfer vault collateral"
+          ]
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "The owner of the margin account"
+          ]
+        },
+        {
+          "name": "marginAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The margin account to close, which must have no debt"
+          ]
+        },
+        {
+          "name": "collateralAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The owners account that will receive the remaining primary collateral"
+          ]
+        },
+        {
+          "name": "destination",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The receiver of the margin account rent"
+          ]
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The token program for CPI calls"
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "depositCollateral",
+      "docs": [
+        "Deposit collateral into a margin account, on behalf of its owner"
+      ],
+      "accounts": [
+        {
+          "name": "syntheticAsset",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The synthetic asset account"
+          ]
+        },
+        {
+          "name": "collateralVault",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The vault that is receiving collateral, of any collateral the synthetic asset accepts"
+          ]
+        },
+        {
+          "name": "depositor",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "The depositor of collateral, who doesn't have to be the margin account owner"
+          ]
+        },
+        {
+          "name": "marginAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The margin account being credited with collateral"
+          ]
+        },
+        {
+          "name": "depositorCollateralAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The depositors account that collateral will be transferred from"
+          ]
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The token program for CPI calls"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "collateralAmount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "withdrawCollateral",
+      "docs": [
+        "Withdraw collateral from a margin account, as its owner"
+      ],
+      "accounts": [
+        {
+          "name": "syntheticAsset",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The synthetic asset account"
+          ]
+        },
+        {
+          "name": "collateralVault",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The vault that collateral is withdrawn from, of any collateral the synthetic asset accepts"
+          ]
+        },
+        {
+          "name": "collateralMint",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The mint of the collateral being withdrawn"
+          ]
+        },
+        {
+          "name": "syntheticOracle",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The oracle price feed, to determine margin account health"
+          ]
+        },
+        {
+          "name": "assetAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The mint authority that can mint synthetic assets and transfer vault collateral"
+          ]
+        },
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "The owner of the margin account"
+          ]
+        },
+        {
+          "name": "marginAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The margin account of the owner, to track collateral and debt"
+          ]
+        },
+        {
+          "name": "collateralAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The owners account that will receive collateral"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The token program for CPI calls"
+          ]
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "collateralAmount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "mintSyntheticAsset",
+      "docs": [
+        "Mint a synthetic asset"
+      ],
+      "accounts": [
+        {
+          "name": "syntheticAsset",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The synthetic asset account"
+          ]
+        },
+        {
+          "name": "collateralVault",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The vault that is receiving collateral, of any collateral the synthetic asset accepts"
+          ]
+        },
+        {
+          "name": "collateralMint",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The mint of the collateral being deposited"
+          ]
+        },
+        {
+          "name": "syntheticMint",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The synthetic mint of the synthetic asset"
+          ]
+        },
+        {
+          "name": "syntheticOracle",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The oracle price feed, to determine margin account health"
+          ]
+        },
+        {
+          "name": "assetAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The mint authority that can mint synthetic assets and transfer vault collateral"
+          ]
+        },
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "The receiver of the synthetic asset"
+          ]
+        },
+        {
+          "name": "marginAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The margin account of the owner, to track collateral and debt"
+          ]
+        },
+        {
+          "name": "treasury",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The treasury receiving fees, in the collateral of the vault"
+          ]
+        },
+        {
+          "name": "collateralAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The owners account that collateral will be transferred from"
+          ]
+        },
+        {
+          "name": "syntheticAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The owners account that will receive synthetic tokens"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The token program for CPI calls"
+          ]
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "collateralAmount",
+          "type": "u64"
+        },
+        {
+          "name": "mintAmount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "burnSyntheticAsset",
+      "docs": [
+        "Burn a synthetic asset"
+      ],
+      "accounts": [
+        {
+          "name": "syntheticAsset",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The synthetic asset account"
+          ]
+        },
+        {
+          "name": "collateralVault",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The vault that collateral is withdrawn from, of any collateral the synthetic asset accepts"
+          ]
+        },
+        {
+          "name": "collateralMint",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The mint of the collateral being withdrawn"
+          ]
+        },
+        {
+          "name": "syntheticMint",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The synthetic mint of the synthetic asset"
+          ]
+        },
+        {
+          "name": "syntheticOracle",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The oracle price feed, to determine margin account health"
+          ]
+        },
+        {
+          "name": "assetAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The mint authority that can mint synthetic assets and transfer vault collateral"
+          ]
+        },
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "The receiver of the synthetic asset"
+          ]
+        },
+        {
+          "name": "marginAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The margin account of the owner, to track collateral and debt"
+          ]
+        },
+        {
+          "name": "treasury",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The treasury receiving fees, in the collateral of the vault"
+          ]
+        },
+        {
+          "name": "collateralAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The owners account that collateral will be transferred from"
+          ]
+        },
+        {
+          "name": "syntheticAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The owners account that will receive synthetic tokens"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The token program for CPI calls"
+          ]
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "collateralAmount",
+          "type": "u64"
+        },
+        {
+          "name": "burnAmount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "repay",
+      "docs": [
+        "Repay the debt of a margin account, on behalf of its owner"
+      ],
+      "accounts": [
+        {
+          "name": "syntheticAsset",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The synthetic asset account"
+          ]
+        },
+        {
+          "name": "syntheticMint",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The synthetic mint of the synthetic asset"
+          ]
+        },
+        {
+          "name": "repayer",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "The repayer of debt, who doesn't have to be the margin account owner"
+          ]
+        },
+        {
+          "name": "marginAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The margin account whose debt is repaid"
+          ]
+        },
+        {
+          "name": "repayerSyntheticAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The repayers account that synthetic tokens will be burned from"
+          ]
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The token program for CPI calls"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "repayAmount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "collectStabilityFees",
+      "docs": [
+        "Mint the accrued stability fees of a synthetic asset to its treasury"
+      ],
+      "accounts": [
+        {
+          "name": "syntheticAsset",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The synthetic asset account"
+          ]
+        },
+        {
+          "name": "syntheticMint",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The synthetic mint of the synthetic asset"
+          ]
+        },
+        {
+          "name": "assetAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The mint authority that can mint synthetic assets and transfer vault collateral"
+          ]
+        },
+        {
+          "name": "treasury",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The treasury receiving stability fees, in the synthetic asset"
+          ]
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "The payer of rent for the treasury"
+          ]
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The token program for CPI calls"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The system program to create accounts"
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "withdrawTreasury",
+      "docs": [
+        "Withdraw fees from a treasury of a synthetic asset, as its admin"
+      ],
+      "accounts": [
+        {
+          "name": "syntheticAsset",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The synthetic asset account"
+          ]
+        },
+        {
+          "name": "assetAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The mint authority that can mint synthetic assets and transfer vault collateral"
+          ]
+        },
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "The admin of the synthetic asset"
+          ]
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The mint of the fees being withdrawn"
+          ]
+        },
+        {
+          "name": "treasury",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The treasury holding fees"
+          ]
+        },
+        {
+          "name": "destination",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The account receiving the fees"
+          ]
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The token program for CPI calls"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "auditSyntheticAsset",
+      "docs": [
+        "Verify the vaults and synthetic supply of a synthetic asset match its aggregate accounting"
+      ],
+      "accounts": [
+        {
+          "name": "syntheticAsset",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The synthetic asset being audited"
+          ]
+        },
+        {
+          "name": "collateralVault",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The vault of the primary collateral"
+          ]
+        },
+        {
+          "name": "syntheticMint",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The synthetic mint of the synthetic asset"
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "issuePoolDebt",
+      "docs": [
+        "Mint a pooled synthetic asset, in exchange for a share of the pool debt"
+      ],
+      "accounts": [
+        {
+          "name": "syntheticAsset",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The synthetic asset account"
+          ]
+        },
+        {
+          "name": "debtPool",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The debt pool shared by the synthetic asset"
+          ]
+        },
+        {
+          "name": "collateralVault",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The vault that is receiving collateral, of any collateral the synthetic asset accepts"
+          ]
+        },
+        {
+          "name": "collateralMint",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The mint of the collateral being deposited"
+          ]
+        },
+        {
+          "name": "syntheticMint",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The synthetic mint of the synthetic asset"
+          ]
+        },
+        {
+          "name": "syntheticOracle",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The oracle price feed, to determine margin account health"
+          ]
+        },
+        {
+          "name": "assetAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The mint authority that can mint synthetic assets and transfer vault collateral"
+          ]
+        },
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "The receiver of the synthetic asset"
+          ]
+        },
+        {
+          "name": "marginAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The margin account of the owner, to track collateral and debt"
+          ]
+        },
+        {
+          "name": "treasury",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The treasury receiving fees, in the collateral of the vault"
+          ]
+        },
+        {
+          "name": "collateralAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The owners account that collateral will be transferred from"
+          ]
+        },
+        {
+          "name": "syntheticAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The owners account that will receive synthetic tokens"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The token program for CPI calls"
+          ]
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "collateralAmount",
+          "type": "u64"
+        },
+        {
+          "name": "mintAmount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "burnPoolDebt",
+      "docs": [
+        "Burn a pooled synthetic asset, repaying a share of the pool debt"
+      ],
+      "accounts": [
+        {
+          "name": "syntheticAsset",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The synthetic asset account"
+          ]
+        },
+        {
+          "name": "debtPool",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The debt pool shared by the synthetic asset"
+          ]
+        },
+        {
+          "name": "collateralVault",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The vault that collateral is withdrawn from, of any collateral the synthetic asset accepts"
+          ]
+        },
+        {
+          "name": "collateralMint",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The mint of the collateral being withdrawn"
+          ]
+        },
+        {
+          "name": "syntheticMint",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The synthetic mint of the synthetic asset"
+          ]
+        },
+        {
+          "name": "syntheticOracle",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The oracle price feed, to determine margin account health"
+          ]
+        },
+        {
+          "name": "assetAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The mint authority that can mint synthetic assets and transfer vault collateral"
+          ]
+        },
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "The receiver of the synthetic asset"
+          ]
+        },
+        {
+          "name": "marginAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The margin account of the owner, to track collateral and debt"
+          ]
+        },
+        {
+          "name": "treasury",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The treasury receiving fees, in the collateral of the vault"
+          ]
+        },
+        {
+          "name": "collateralAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The owners account that collateral will be transferred from"
+          ]
+        },
+        {
+          "name": "syntheticAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The owners account that will receive synthetic tokens"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The token program for CPI calls"
+          ]
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "collateralAmount",
+          "type": "u64"
+        },
+        {
+          "name": "burnAmount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "exchangeSyntheticAsset",
+      "docs": [
+        "Exchange a pooled synthetic asset for another at oracle prices"
+      ],
+      "accounts": [
+        {
+          "name": "sourceSyntheticAsset",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The synthetic asset being exchanged"
+          ]
+        },
+        {
+          "name": "sourceMint",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The synthetic mint of the synthetic asset being exchanged"
+          ]
+        },
+        {
+          "name": "sourceOracle",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The oracle price feed of the synthetic asset being exchanged"
+          ]
+        },
+        {
+          "name": "targetSyntheticAsset",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The synthetic asset being received"
+          ]
+        },
+        {
+          "name": "targetMint",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The synthetic mint of the synthetic asset being received"
+          ]
+        },
+        {
+          "name": "targetOracle",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The oracle price feed of the synthetic asset being received"
+          ]
+        },
+        {
+          "name": "targetAssetAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The mint authority of the synthetic asset being received"
+          ]
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "The owner of the synthetic assets"
+          ]
+        },
+        {
+          "name": "sourceAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The owners account that synthetic tokens will be burned from"
+          ]
+        },
+        {
+          "name": "targetAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The account that will receive the exchanged synthetic tokens"
+          ]
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The token program for CPI calls"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "redeem",
+      "docs": [
+        "Redeem a synthetic asset for primary collateral at the oracle price, repaying",
+        "the debt of the margin accounts passed in, ordered lowest collateral ratio first"
+      ],
+      "accounts": [
+        {
+          "name": "syntheticAsset",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The synthetic asset account"
+          ]
+        },
+        {
+          "name": "collateralVault",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The vault of the primary collateral, which is paid out"
+          ]
+        },
+        {
+          "name": "collateralMint",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The primary collateral mint"
+          ]
+        },
+        {
+          "name": "syntheticMint",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The synthetic mint of the synthetic asset"
+          ]
+        },
+        {
+          "name": "syntheticOracle",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The oracle price feed, to value the synthetic asset redeemed"
+          ]
+        },
+        {
+          "name": "assetAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The authority that can transfer vault collateral"
+          ]
+        },
+        {
+          "name": "redeemer",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "The holder redeeming synthetic tokens"
+          ]
+        },
+        {
+          "name": "treasury",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The treasury receiving redemption fees, in the primary collateral"
+          ]
+        },
+        {
+          "name": "redeemerCollateralAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The redeemers account that will receive collateral"
+          ]
+        },
+        {
+          "name": "redeemerSyntheticAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The redeemers account that synthetic tokens will be burned from"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The token program for CPI calls"
+          ]
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "liquidateMarginAccount",
+      "docs": [
+        "Liquidate an unhealthy margin account, repaying debt in exchange for collateral"
+      ],
+      "accounts": [
+        {
+          "name": "syntheticAsset",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The synthetic asset account"
+          ]
+        },
+        {
+          "name": "collateralVault",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The vault that collateral is seized from, of any collateral the synthetic asset accepts"
+          ]
+        },
+        {
+          "name": "collateralMint",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The mint of the collateral being seized"
+          ]
+        },
+        {
+          "name": "syntheticMint",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The synthetic mint of the synthetic asset"
+          ]
+        },
+        {
+          "name": "syntheticOracle",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The oracle price feed, to determine margin account health"
+          ]
+        },
+        {
+          "name": "assetAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The mint authority that can mint synthetic assets and transfer vault collateral"
+          ]
+        },
+        {
+          "name": "liquidator",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "The liquidator repaying debt in exchange for collateral"
+          ]
+        },
+        {
+          "name": "marginAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The unhealthy margin account being liquidated"
+          ]
+        },
+        {
+          "name": "liquidatorCollateralAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The liquidators account that will receive seized collateral"
+          ]
+        },
+        {
+          "name": "liquidatorSyntheticAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The liquidators account that synthetic tokens will be burned from"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The token program for CPI calls"
+          ]
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "repayAmount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "liquidatePoolAccount",
+      "docs": [
+        "Liquidate an unhealthy margin account owing debt pool shares"
+      ],
+      "accounts": [
+        {
+          "name": "syntheticAsset",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The synthetic asset account"
+          ]
+        },
+        {
+          "name": "debtPool",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The debt pool shared by the synthetic asset"
+          ]
+        },
+        {
+          "name": "collateralVault",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The vault that collateral is seized from, of any collateral the synthetic asset accepts"
+          ]
+        },
+        {
+          "name": "collateralMint",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The mint of the collateral being seized"
+          ]
+        },
+        {
+          "name": "syntheticMint",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The synthetic mint of the synthetic asset"
+          ]
+        },
+        {
+          "name": "syntheticOracle",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The oracle price feed, to determine margin account health"
+          ]
+        },
+        {
+          "name": "assetAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The mint authority that can mint synthetic assets and transfer vault collateral"
+          ]
+        },
+        {
+          "name": "liquidator",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "The liquidator repaying debt in exchange for collateral"
+          ]
+        },
+        {
+          "name": "marginAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The unhealthy margin account being liquidated, which owes debt pool shares"
+          ]
+        },
+        {
+          "name": "liquidatorCollateralAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The liquidators account that will receive seized collateral"
+          ]
+        },
+        {
+          "name": "liquidatorSyntheticAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The liquidators account that synthetic tokens will be burned from"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The token program for CPI calls"
+          ]
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "repayAmount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "initializeCrossMarginAccount",
+      "docs": [
+        "Initialize a cross-margin account, whose collateral backs several synthetic assets"
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "The payer of cross-margin account rent"
+          ]
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The owner of the cross-margin account, who can mint assets"
+          ]
+        },
+        {
+          "name": "collateralMint",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The collateral mint backing every position of the cross-margin account"
+          ]
+        },
+        {
+          "name": "crossMarginAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The cross-margin account to initialize"
+          ]
+        },
+        {
+          "name": "collateralVault",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The vault holding the collateral of the cross-margin account"
+          ]
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The token program to initialize token accounts"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "System program for CPI"
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "crossMarginDeposit",
+      "docs": [
+        "Deposit collateral into a cross-margin account, on behalf of its owner"
+      ],
+      "accounts": [
+        {
+          "name": "depositor",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "The depositor of collateral, who doesn't have to be the cross-margin account owner"
+          ]
+        },
+        {
+          "name": "crossMarginAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The cross-margin account being credited with collateral"
+          ]
+        },
+        {
+          "name": "collateralVault",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The vault of the cross-margin account"
+          ]
+        },
+        {
+          "name": "depositorCollateralAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The depositors account that collateral will be transferred from"
+          ]
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The token program for CPI calls"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "collateralAmount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "crossMarginWithdraw",
+      "docs": [
+        "Withdraw collateral from a cross-margin account, as its owner"
+      ],
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "The owner of the cross-margin account"
+          ]
+        },
+        {
+          "name": "crossMarginAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The cross-margin account of the owner, to track collateral and debt"
+          ]
+        },
+        {
+          "name": "collateralVault",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The vault of the cross-margin account"
+          ]
+        },
+        {
+          "name": "collateralAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The owners account that will receive collateral"
+          ]
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The token program for CPI calls"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "collateralAmount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "crossMarginMint",
+      "docs": [
+        "Mint a synthetic asset against the collateral of a cross-margin account"
+      ],
+      "accounts": [
+        {
+          "name": "syntheticAsset",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The synthetic asset account"
+          ]
+        },
+        {
+          "name": "collateralMint",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The collateral mint shared by the synthetic asset and the cross-margin account"
+          ]
+        },
+        {
+          "name": "syntheticMint",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The synthetic mint of the synthetic asset"
+          ]
+        },
+        {
+          "name": "syntheticOracle",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The oracle price feed, to determine cross-margin account health"
+          ]
+        },
+        {
+          "name": "assetAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The mint authority that can mint synthetic assets"
+          ]
+        },
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "The receiver of the synthetic asset"
+          ]
+        },
+        {
+          "name": "crossMarginAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The cross-margin account of the owner, to track collateral and debt"
+          ]
+        },
+        {
+          "name": "collateralVault",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The vault of the cross-margin account, which fees are paid from"
+          ]
+        },
+        {
+          "name": "treasury",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The treasury receiving fees, in the collateral of the synthetic asset"
+          ]
+        },
+        {
+          "name": "syntheticAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The owners account that will receive synthetic tokens"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The token program for CPI calls"
+          ]
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "mintAmount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "crossMarginBurn",
+      "docs": [
+        "Burn a synthetic asset, repaying a position of a cross-margin account"
+      ],
+      "accounts": [
+        {
+          "name": "syntheticAsset",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The synthetic asset account"
+          ]
+        },
+        {
+          "name": "collateralMint",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The collateral mint shared by the synthetic asset and the cross-margin account"
+          ]
+        },
+        {
+          "name": "syntheticMint",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The synthetic mint of the synthetic asset"
+          ]
+        },
+        {
+          "name": "syntheticOracle",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The oracle price feed, to value the burn fee"
+          ]
+        },
+        {
+          "name": "assetAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The authority of the synthetic asset, which owns the treasury"
+          ]
+        },
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "The owner of the cross-margin account"
+          ]
+        },
+        {
+          "name": "crossMarginAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The cross-margin account of the owner, to track collateral and debt"
+          ]
+        },
+        {
+          "name": "collateralVault",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The vault of the cross-margin account, which fees are paid from"
+          ]
+        },
+        {
+          "name": "treasury",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The treasury receiving fees, in the collateral of the synthetic asset"
+          ]
+        },
+        {
+          "name": "syntheticAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The owners account that synthetic tokens will be burned from"
+          ]
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The token program for CPI calls"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "burnAmount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "liquidateCrossMarginAccount",
+      "docs": [
+        "Liquidate a position of an unhealthy cross-margin account"
+      ],
+      "accounts": [
+        {
+          "name": "syntheticAsset",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The synthetic asset of the position being repaid"
+          ]
+        },
+        {
+          "name": "collateralMint",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The collateral mint shared by the synthetic asset and the cross-margin account"
+          ]
+        },
+        {
+          "name": "syntheticMint",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The synthetic mint of the synthetic asset"
+          ]
+        },
+        {
+          "name": "syntheticOracle",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The oracle price feed, to determine cross-margin account health"
+          ]
+        },
+        {
+          "name": "liquidator",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "The liquidator repaying debt in exchange for collateral"
+          ]
+        },
+        {
+          "name": "crossMarginAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The unhealthy cross-margin account being liquidated"
+          ]
+        },
+        {
+          "name": "collateralVault",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The vault of the cross-margin account that collateral is seized from"
+          ]
+        },
+        {
+          "name": "liquidatorCollateralAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The liquidators account that will receive seized collateral"
+          ]
+        },
+        {
+          "name": "liquidatorSyntheticAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The liquidators account that synthetic tokens will be burned from"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The token program for CPI calls"
+          ]
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "repayAmount",
+          "type": "u64"
+        }
+      ]
+    }
+  ],
+  "accounts": [
+    {
+      "name": "crossMarginAccount",
+      "docs": [
+        "A margin account whose collateral backs debt in several synthetic assets.",
+        "The collateral is a single mint valued at $1, held in a vault owned by the account."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "owner",
+            "type": "publicKey"
+          },
+          {
+            "name": "collateralMint",
+            "docs": [
+              "The collateral mint, which must be the collateral mint of every synthetic asset borrowed"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "collateralVault",
+            "docs": [
+              "The vault holding the collateral, owned by this account"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "collateralDeposited",
+            "type": "u64"
+          },
+          {
+            "name": "positions",
+            "type": {
+              "array": [
+                {
+                  "defined": "CrossMarginPosition"
+                },
+                8
+              ]
+            }
+          },
+          {
+            "name": "bump",
+            "type": {
+              "array": [
+                "u8",
+                1
+              ]
+            }
+          },
+          {
+            "name": "collateralDecimals",
+            "docs": [
+              "The decimals of the collateral mint"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "padding",
+            "type": {
+              "array": [
+                "u8",
+                6
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "debtPool",
+      "docs": [
+        "A program-wide pool of debt shared by the synthetic assets that join it.",
+        "Margin accounts of pooled synthetic assets hold a share of the USD value of",
+        "every pooled synthetic asset in circulation, instead of a fixed synthetic amount."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "admin",
+            "docs": [
+              "The admin that can add synthetic assets to the pool"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "syntheticAssets",
+            "docs": [
+              "The synthetic assets sharing the pool debt, followed by default pubkeys"
+            ],
+            "type": {
+              "array": [
+                "publicKey",
+                8
+              ]
+            }
+          },
+          {
+            "name": "totalDebtShares",
+            "docs": [
+              "The debt shares held by all margin accounts of the pool"
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "marginAccount",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "owner",
+            "type": "publicKey"
+          },
+          {
+            "name": "syntheticAsset",
+            "type": "publicKey"
+          },
+          {
+            "name": "collateralDeposited",
+            "type": "u64"
+          },
+          {
+            "name": "normalizedDebt",
+            "docs": [
+              "The synthetic debt divided by the borrow index of the synthetic asset,",
+              "so that it grows with the stability fee"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "debtShares",
+            "docs": [
+              "The share of the debt pool owed, when the synthetic asset shares the debt pool"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "collateralTypeDeposited",
+            "docs": [
+              "Deposits of the registered collateral types of the synthetic asset, by registry index"
+            ],
+            "type": {
+              "array": [
+                "u64",
+                4
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "programConfig",
+      "docs": [
+        "Program-wide settings of a deployment. Each cluster has its own config,",
+        "so it lists the oracle program IDs deployed to that cluster."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "admin",
+            "docs": [
+              "The admin that can update the program config"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "oraclePrograms",
+            "docs": [
+              "The programs whose price accounts can price synthetic assets, followed by default pubkeys"
+            ],
+            "type": {
+              "array": [
+                "publicKey",
+                4
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "syntheticAsset",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "syntheticAsset",
+            "type": "publicKey"
+          },
+          {
+            "name": "collateralMint",
+            "type": "publicKey"
+          },
+          {
+            "name": "collateralVault",
+            "type": "publicKey"
+          },
+          {
+            "name": "syntheticMint",
+            "type": "publicKey"
+          },
+          {
+            "name": "syntheticOracle",
+            "type": "publicKey"
+          },
+          {
+            "name": "oracleProgram",
+            "docs": [
+              "The program owning the synthetic oracle price feed"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "assetAuthority",
+            "type": "publicKey"
+          },
+          {
+            "name": "admin",
+            "docs": [
+              "The admin that can update parameters of the synthetic asset"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "pendingAdmin",
+            "docs": [
+              "The proposed new admin, who must accept before becoming the admin"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "debtPool",
+            "docs": [
+              "The debt pool sharing the debt of this synthetic asset,",
+              "or the default pubkey if its margin accounts owe synthetic amounts"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "symbol",
+            "docs": [
+              "The symbol the synthetic asset address is derived from, zero padded.",
+              "Synthetic assets initialized before symbols were derived from their first oracle"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "params",
+            "docs": [
+              "Risk parameters, updatable by the admin"
+            ],
+            "type": {
+              "defined": "SyntheticAssetParams"
+            }
+          },
+          {
+            "name": "collateralTypes",
+            "docs": [
+              "Collateral accepted in addition to the primary collateral mint"
+            ],
+            "type": {
+              "array": [
+                {
+                  "defined": "CollateralType"
+                },
+                4
+              ]
+            }
+          },
+          {
+            "name": "borrowIndex",
+            "docs": [
+              "The cumulative stability fee index. Debt is stored divided by this index"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "lastAccrualTime",
+            "docs": [
+              "The unix timestamp the borrow index was last updated"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "totalNormalizedDebt",
+            "docs": [
+              "The sum of the normalized debt of all margin accounts"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "accruedFees",
+            "docs": [
+              "Stability fees accrued but not yet minted to the treasury"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "totalDebt",
+            "docs": [
+              "The synthetic debt owed to the protocol, including the accrued stability fee.",
+              "The synthetic supply plus the uncollected fees never exceed it"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "totalCollateral",
+            "docs": [
+              "The primary collateral deposited by all margin accounts, which the vault must hold"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "marginAccountCount",
+            "docs": [
+              "The number of margin accounts open on the synthetic asset"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "settlementCollateral",
+            "docs": [
+              "The primary collateral set aside in the vault for synthetic holders after a shutdown"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "settlementPrice",
+            "docs": [
+              "The final price of the synthetic asset recorded at shutdown, with `settlement_expo`"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "lastPrice",
+            "docs": [
+              "The last oracle price of the synthetic asset the circuit breaker accepted"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "lastPriceSlot",
+            "docs": [
+              "The slot `last_price` was accepted in"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "cooldownEndSlot",
+            "docs": [
+              "The slot the circuit breaker cooldown ends in, after a price moved too fast"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "assetAuthorityBump",
+            "type": {
+              "array": [
+                "u8",
+                1
+              ]
+            }
+          },
+          {
+            "name": "collateralDecimals",
+            "docs": [
+              "The decimals of the primary collateral mint"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "syntheticDecimals",
+            "docs": [
+              "The decimals of the synthetic mint"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "status",
+            "docs": [
+              "The `SyntheticAssetStatus` of the synthetic asset"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "settlementExpo",
+            "docs": [
+              "The exponent of the settlement price"
+            ],
+            "type": "i32"
+          }
+        ]
+      }
+    }
+  ],
+  "types": [
+    {
+      "name": "CollateralType",
+      "docs": [
+        "An additional collateral mint accepted by a synthetic asset"
+      ],
+      "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "syntheticAsset",
+            "name": "mint",
+            "docs": [
+              "The collateral mint, or the default pubkey if this slot is unused"
+            ],
             "type": "publicKey"
           },
           {
-            "name": "collateralMint",
+            "name": "vault",
+            "docs": [
+              "The vault holding deposits of this collateral"
+            ],
             "type": "publicKey"
           },
           {
-            "name": "collateralVault",
+            "name": "oracle",
+            "docs": [
+              "The oracle price feed of this collateral"
+            ],
             "type": "publicKey"
           },
           {
-            "name": "syntheticMint",
+            "name": "oracleProgram",
+            "docs": [
+              "The program owning the oracle price feed"
+            ],
             "type": "publicKey"
           },
           {
-            "name": "syntheticOracle",
-            "type": "publicKey"
+            "name": "loanToValueBps",
+            "docs": [
+              "The portion of the collateral value counted towards margin account health,",
+              "in basis points"
+            ],
+            "type": "u64"
           },
           {
-            "name": "assetAuthority",
-            "type": "publicKey"
+            "name": "totalCollateral",
+            "docs": [
+              "The collateral deposited by all margin accounts, which the vault must hold"
+            ],
+            "type": "u64"
           },
           {
-            "name": "assetAuthorityBump",
+            "name": "settlementCollateral",
+            "docs": [
+              "The collateral set aside in the vault for synthetic holders after a shutdown"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "decimals",
+            "docs": [
+              "The decimals of the collateral mint"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "padding",
             "type": {
               "array": [
                 "u8",
-                1
+                7
               ]
             }
           }
         ]
       }
+    },
+    {
+      "name": "CrossMarginPosition",
+      "docs": [
+        "The debt of a cross-margin account in one synthetic asset"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "syntheticAsset",
+            "docs": [
+              "The synthetic asset borrowed, or the default pubkey if this slot is unused"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "normalizedDebt",
+            "docs": [
+              "The synthetic debt divided by the borrow index of the synthetic asset"
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SyntheticAssetParams",
+      "docs": [
+        "Risk parameters of a synthetic asset"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "initialCollateralRatioBps",
+            "docs": [
+              "The collateral ratio required to mint or withdraw, in basis points"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "maintenanceCollateralRatioBps",
+            "docs": [
+              "The collateral ratio below which a margin account can be liquidated, in basis points"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "liquidationBonusBps",
+            "docs": [
+              "The collateral bonus paid to liquidators, in basis points of the repaid debt value"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "liquidationCloseFactorBps",
+            "docs": [
+              "The maximum portion of a margin account's debt that can be repaid",
+              "in a single liquidation, in basis points"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "maxOracleAge",
+            "docs": [
+              "The maximum age of an oracle price, in seconds"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "haltedPriceGracePeriod",
+            "docs": [
+              "How long the last trading price of a halted feed can still repay or liquidate debt, in seconds"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "maxOracleConfidenceBps",
+            "docs": [
+              "The maximum oracle confidence interval, in basis points of the price"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "pricingPolicy",
+            "docs": [
+              "The `PricingPolicy` choosing between the spot and EMA oracle prices"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "maxPriceChangeBpsPerSlot",
+            "docs": [
+              "How far the synthetic price can move per slot before the circuit breaker trips,",
+              "in basis points, or zero to disable the circuit breaker"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "circuitBreakerCooldownSlots",
+            "docs": [
+              "How many slots minting and withdrawing stay disabled after the circuit breaker trips"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "confidenceMultiplierBps",
+            "docs": [
+              "How many confidence intervals oracle prices are moved against margin accounts, in basis points"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "stabilityFeeBps",
+            "docs": [
+              "The annual stability fee charged on synthetic debt, in basis points"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "mintFeeBps",
+            "docs": [
+              "The fee charged in collateral when minting, in basis points of the value minted"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "burnFeeBps",
+            "docs": [
+              "The fee charged in collateral when burning, in basis points of the value burned"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "redemptionFeeBps",
+            "docs": [
+              "The fee charged in collateral when redeeming, in basis points of the collateral redeemed"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "debtCeiling",
+            "docs": [
+              "The maximum total debt of the synthetic asset, including the accrued stability fee"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "maxAccountDebt",
+            "docs": [
+              "The maximum synthetic debt of a single margin account, or zero for no cap"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "minDebt",
+            "docs": [
+              "The minimum synthetic debt of a margin account, so positions stay worth liquidating"
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "PriceSide",
+      "docs": [
+        "What an oracle price values, which decides the conservative direction"
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Debt"
+          },
+          {
+            "name": "Collateral"
+          }
+        ]
+      }
+    },
+    {
+      "name": "PriceRequirement",
+      "docs": [
+        "Which feed statuses an oracle price is accepted in. Equity and FX feeds stop trading",
+        "outside market hours, and keep reporting a price that can't be traded against."
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Trading"
+          },
+          {
+            "name": "LastTrading"
+          }
+        ]
+      }
+    },
+    {
+      "name": "CollateralSlot",
+      "docs": [
+        "Which of the collateral of a synthetic asset is being used"
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Primary"
+          },
+          {
+            "name": "Registered",
+            "fields": [
+              {
+                "defined": "usize"
+              }
+            ]
+          }
+        ]
+      }
+    },
+    {
+      "name": "HealthCheck",
+      "docs": [
+        "Which collateral ratio of each synthetic asset the health of a",
+        "cross-margin account is checked against"
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Initial"
+          },
+          {
+            "name": "Maintenance"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SyntheticAssetStatus",
+      "docs": [
+        "The lifecycle of a synthetic asset"
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Active"
+          },
+          {
+            "name": "WindingDown"
+          },
+          {
+            "name": "Shutdown"
+          }
+        ]
+      }
+    },
+    {
+      "name": "PricingPolicy",
+      "docs": [
+        "Which oracle price health checks value margin accounts of a synthetic asset at.",
+        "Tokens always change hands at the aggregate price."
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Spot"
+          },
+          {
+            "name": "Ema"
+          },
+          {
+            "name": "Conservative"
+          }
+        ]
+      }
     }
   ],
   "errors": [
@@ -946,8 +7552,198 @@ export const IDL: Resynth = {
     },
     {
       "code": 6003,
-      "name": "InvalidCollateralMintDecimals",
-      "msg": "Collateral decimals is rqeuired to be 6 because it's value is hardcoded to $1"
+      "name": "MarginAccountHealthy",
+      "msg": "The margin account is healthy and cannot be liquidated"
+    },
+    {
+      "code": 6004,
+      "name": "InvalidLiquidationParameters",
+      "msg": "The liquidation parameters are invalid"
+    },
+    {
+      "code": 6005,
+      "name": "MathOverflow",
+      "msg": "Math overflow"
+    },
+    {
+      "code": 6006,
+      "name": "OracleNotTrading",
+      "msg": "The oracle price is not currently trading"
+    },
+    {
+      "code": 6007,
+      "name": "OracleConfidenceTooWide",
+      "msg": "The oracle confidence interval is too wide"
+    },
+    {
+      "code": 6008,
+      "name": "InvalidOracleParameters",
+      "msg": "The oracle parameters are invalid"
+    },
+    {
+      "code": 6009,
+      "name": "InvalidCollateralRatios",
+      "msg": "The collateral ratios are invalid"
+    },
+    {
+      "code": 6010,
+      "name": "InvalidCollateral",
+      "msg": "The collateral is not accepted by the synthetic asset"
+    },
+    {
+      "code": 6011,
+      "name": "InvalidCollateralParameters",
+      "msg": "The collateral parameters are invalid"
+    },
+    {
+      "code": 6012,
+      "name": "CollateralTypesFull",
+      "msg": "The synthetic asset accepts the maximum number of collateral types"
+    },
+    {
+      "code": 6013,
+      "name": "InvalidFeeParameters",
+      "msg": "The fee parameters are invalid"
+    },
+    {
+      "code": 6014,
+      "name": "InsufficientCollateralForFee",
+      "msg": "The margin account does not have enough collateral to pay the fee"
+    },
+    {
+      "code": 6015,
+      "name": "InvalidDebtPool",
+      "msg": "The synthetic asset is not in the debt pool"
+    },
+    {
+      "code": 6016,
+      "name": "SyntheticAssetPooled",
+      "msg": "The synthetic asset shares a debt pool"
+    },
+    {
+      "code": 6017,
+      "name": "DebtPoolFull",
+      "msg": "The debt pool has the maximum number of synthetic assets"
+    },
+    {
+      "code": 6018,
+      "name": "OutstandingDebt",
+      "msg": "The synthetic asset has outstanding debt"
+    },
+    {
+      "code": 6019,
+      "name": "CrossMarginPositionsFull",
+      "msg": "The cross-margin account borrows the maximum number of synthetic assets"
+    },
+    {
+      "code": 6020,
+      "name": "InvalidCrossMarginPosition",
+      "msg": "The synthetic asset accounts do not match the cross-margin positions"
+    },
+    {
+      "code": 6021,
+      "name": "DebtCeilingExceeded",
+      "msg": "Minting would exceed the debt ceiling of the synthetic asset"
+    },
+    {
+      "code": 6022,
+      "name": "AccountDebtCapExceeded",
+      "msg": "Minting would exceed the debt cap of a single margin account"
+    },
+    {
+      "code": 6023,
+      "name": "DebtBelowMinimum",
+      "msg": "The margin account debt must be repaid in full or stay above the minimum debt"
+    },
+    {
+      "code": 6024,
+      "name": "InvalidDebtLimits",
+      "msg": "The per-account debt cap must be at least the minimum debt"
+    },
+    {
+      "code": 6025,
+      "name": "CollateralAccountingMismatch",
+      "msg": "A vault holds less collateral than deposited into the synthetic asset"
+    },
+    {
+      "code": 6026,
+      "name": "DebtAccountingMismatch",
+      "msg": "The synthetic supply exceeds the debt owed to the synthetic asset"
+    },
+    {
+      "code": 6027,
+      "name": "MarginAccountHasDebt",
+      "msg": "The margin account has outstanding debt"
+    },
+    {
+      "code": 6028,
+      "name": "SyntheticAssetNotActive",
+      "msg": "The synthetic asset is not active and can't be minted"
+    },
+    {
+      "code": 6029,
+      "name": "SyntheticAssetShutdown",
+      "msg": "The synthetic asset is shut down"
+    },
+    {
+      "code": 6030,
+      "name": "SyntheticAssetNotShutdown",
+      "msg": "The synthetic asset is not shut down"
+    },
+    {
+      "code": 6031,
+      "name": "InvalidSettlementPrice",
+      "msg": "The settlement price must be positive"
+    },
+    {
+      "code": 6032,
+      "name": "SettlementPending",
+      "msg": "All debt must be settled and fees collected before redeeming"
+    },
+    {
+      "code": 6033,
+      "name": "InvalidMarginAccount",
+      "msg": "The margin account belongs to another synthetic asset"
+    },
+    {
+      "code": 6034,
+      "name": "InvalidRedemptionOrder",
+      "msg": "Margin accounts must be passed in order of collateral ratio, lowest first"
+    },
+    {
+      "code": 6035,
+      "name": "CircuitBreakerCooldown",
+      "msg": "The circuit breaker is cooling down after the oracle price moved too fast"
+    },
+    {
+      "code": 6036,
+      "name": "InvalidOracleOwner",
+      "msg": "The oracle account is not owned by an allowed oracle program"
+    },
+    {
+      "code": 6037,
+      "name": "InvalidSymbol",
+      "msg": "Symbols must be between 1 and 32 bytes"
+    },
+    {
+      "code": 6038,
+      "name": "InvalidOraclePrograms",
+      "msg": "At most 4 oracle programs can be allowed, none of them the default pubkey"
+    },
+    {
+      "code": 6039,
+      "name": "InvalidProgramAuthority",
+      "msg": "Only the program upgrade authority can initialize the program config"
+    },
+    {
+      "code": 6040,
+      "name": "DuplicateMarginAccount",
+      "msg": "A margin account can only be redeemed from once per redemption"
+    },
+    {
+      "code": 6041,
+      "name": "MarginAccountsOpen",
+      "msg": "Every margin account must be closed before closing the synthetic asset"
     }
   ]
 };
@@ -1,7 +1,11 @@
 import NodeWallet from "@coral-xyz/anchor/dist/cjs/nodewallet";
 import { Connection, Keypair, PublicKey } from "@solana/web3.js";
 import { Context, ResynthClient } from "../client";
-import { syntheticAssetPDA } from "../utils";
+import {
+  DEFAULT_SYNTHETIC_ASSET_PARAMS,
+  SYNTH_DECIMALS,
+  syntheticAssetPDA,
+} from "../utils";
 
 // Run with ts-node -T ..
 
@@ -15,10 +19,7 @@ async function main() {
   const syntheticAssets = Object.entries(oracles).map((entry) => {
     return {
       ...entry[1],
-      ...syntheticAssetPDA(
-        new PublicKey(config.resynthProgramId),
-        new PublicKey(entry[1])
-      ),
+      ...syntheticAssetPDA(new PublicKey(config.resynthProgramId), entry[0]),
       oracle: new PublicKey(entry[1].oracle),
       oracleSymbol: entry[0],
     };
@@ -39,8 +40,11 @@ async function main() {
         `- ${keys.syntheticAsset}`
     );
     await client.initializeSyntheticAsset({
+      symbol: keys.oracleSymbol,
+      params: DEFAULT_SYNTHETIC_ASSET_PARAMS,
       collateralMint: new PublicKey(config.tokens.USDC.mint),
       syntheticOracle: keys.oracle,
+      syntheticDecimals: SYNTH_DECIMALS,
     });
   }
 }
@@ -8,14 +8,42 @@ export const AUTHORITY: string = "authority";
 export const VAULT: string = "vault";
 export const MINT: string = "mint";
 export const MARGIN_ACCOUNT: string = "margin_account";
+export const TREASURY: string = "treasury";
+export const DEBT_POOL: string = "debt_pool";
+export const PROGRAM_CONFIG: string = "program_config";
+export const CROSS_MARGIN_ACCOUNT: string = "cross_margin_account";
 
 // Accounts -----------------------------------------------------------------
 
+export type CrossMarginAccount = {
+  owner: PublicKey;
+  collateralMint: PublicKey;
+  collateralVault: PublicKey;
+  collateralDeposited: BN;
+  positions: CrossMarginPosition[];
+  bump: number[];
+  collateralDecimals: number;
+  padding: number[];
+};
+
+export type DebtPool = {
+  admin: PublicKey;
+  syntheticAssets: PublicKey[];
+  totalDebtShares: BN;
+};
+
 export type MarginAccount = {
   owner: PublicKey;
   syntheticAsset: PublicKey;
   collateralDeposited: BN;
-  syntheticAssetBorrowed: BN;
+  normalizedDebt: BN;
+  debtShares: BN;
+  collateralTypeDeposited: BN[];
+};
+
+export type ProgramConfig = {
+  admin: PublicKey;
+  oraclePrograms: PublicKey[];
 };
 
 export type SyntheticAsset = {
@@ -24,8 +52,69 @@ export type SyntheticAsset = {
   collateralVault: PublicKey;
   syntheticMint: PublicKey;
   syntheticOracle: PublicKey;
+  oracleProgram: PublicKey;
   assetAuthority: PublicKey;
+  admin: PublicKey;
+  pendingAdmin: PublicKey;
+  debtPool: PublicKey;
+  symbol: number[];
+  params: SyntheticAssetParams;
+  collateralTypes: CollateralType[];
+  borrowIndex: BN;
+  lastAccrualTime: BN;
+  totalNormalizedDebt: BN;
+  accruedFees: BN;
+  totalDebt: BN;
+  totalCollateral: BN;
+  marginAccountCount: BN;
+  settlementCollateral: BN;
+  settlementPrice: BN;
+  lastPrice: BN;
+  lastPriceSlot: BN;
+  cooldownEndSlot: BN;
   assetAuthorityBump: number[];
+  collateralDecimals: number;
+  syntheticDecimals: number;
+  status: number;
+  settlementExpo: number;
+};
+
+export type CollateralType = {
+  mint: PublicKey;
+  vault: PublicKey;
+  oracle: PublicKey;
+  oracleProgram: PublicKey;
+  loanToValueBps: BN;
+  totalCollateral: BN;
+  settlementCollateral: BN;
+  decimals: number;
+  padding: number[];
+};
+
+export type CrossMarginPosition = {
+  syntheticAsset: PublicKey;
+  normalizedDebt: BN;
+};
+
+export type SyntheticAssetParams = {
+  initialCollateralRatioBps: BN;
+  maintenanceCollateralRatioBps: BN;
+  liquidationBonusBps: BN;
+  liquidationCloseFactorBps: BN;
+  maxOracleAge: BN;
+  haltedPriceGracePeriod: BN;
+  maxOracleConfidenceBps: BN;
+  pricingPolicy: BN;
+  maxPriceChangeBpsPerSlot: BN;
+  circuitBreakerCooldownSlots: BN;
+  confidenceMultiplierBps: BN;
+  stabilityFeeBps: BN;
+  mintFeeBps: BN;
+  burnFeeBps: BN;
+  redemptionFeeBps: BN;
+  debtCeiling: BN;
+  maxAccountDebt: BN;
+  minDebt: BN;
 };
 
 // Errors -------------------------------------------------------------------
@@ -46,11 +135,212 @@ export class ResynthError {
     code: 6002,
     message: "The margin account is undercollateralized",
   };
-  static readonly InvalidCollateralMintDecimals = {
-    name: "InvalidCollateralMintDecimals",
+  static readonly MarginAccountHealthy = {
+    name: "MarginAccountHealthy",
     code: 6003,
+    message: "The margin account is healthy and cannot be liquidated",
+  };
+  static readonly InvalidLiquidationParameters = {
+    name: "InvalidLiquidationParameters",
+    code: 6004,
+    message: "The liquidation parameters are invalid",
+  };
+  static readonly MathOverflow = {
+    name: "MathOverflow",
+    code: 6005,
+    message: "Math overflow",
+  };
+  static readonly OracleNotTrading = {
+    name: "OracleNotTrading",
+    code: 6006,
+    message: "The oracle price is not currently trading",
+  };
+  static readonly OracleConfidenceTooWide = {
+    name: "OracleConfidenceTooWide",
+    code: 6007,
+    message: "The oracle confidence interval is too wide",
+  };
+  static readonly InvalidOracleParameters = {
+    name: "InvalidOracleParameters",
+    code: 6008,
+    message: "The oracle parameters are invalid",
+  };
+  static readonly InvalidCollateralRatios = {
+    name: "InvalidCollateralRatios",
+    code: 6009,
+    message: "The collateral ratios are invalid",
+  };
+  static readonly InvalidCollateral = {
+    name: "InvalidCollateral",
+    code: 6010,
+    message: "The collateral is not accepted by the synthetic asset",
+  };
+  static readonly InvalidCollateralParameters = {
+    name: "InvalidCollateralParameters",
+    code: 6011,
+    message: "The collateral parameters are invalid",
+  };
+  static readonly CollateralTypesFull = {
+    name: "CollateralTypesFull",
+    code: 6012,
+    message:
+      "The synthetic asset accepts the maximum number of collateral types",
+  };
+  static readonly InvalidFeeParameters = {
+    name: "InvalidFeeParameters",
+    code: 6013,
+    message: "The fee parameters are invalid",
+  };
+  static readonly InsufficientCollateralForFee = {
+    name: "InsufficientCollateralForFee",
+    code: 6014,
+    message:
+      "The margin account does not have enough collateral to pay the fee",
+  };
+  static readonly InvalidDebtPool = {
+    name: "InvalidDebtPool",
+    code: 6015,
+    message: "The synthetic asset is not in the debt pool",
+  };
+  static readonly SyntheticAssetPooled = {
+    name: "SyntheticAssetPooled",
+    code: 6016,
+    message: "The synthetic asset shares a debt pool",
+  };
+  static readonly DebtPoolFull = {
+    name: "DebtPoolFull",
+    code: 6017,
+    message: "The debt pool has the maximum number of synthetic assets",
+  };
+  static readonly OutstandingDebt = {
+    name: "OutstandingDebt",
+    code: 6018,
+    message: "The synthetic asset has outstanding debt",
+  };
+  static readonly CrossMarginPositionsFull = {
+    name: "CrossMarginPositionsFull",
+    code: 6019,
+    message:
+      "The cross-margin account borrows the maximum number of synthetic assets",
+  };
+  static readonly InvalidCrossMarginPosition = {
+    name: "InvalidCrossMarginPosition",
+    code: 6020,
+    message:
+      "The synthetic asset accounts do not match the cross-margin positions",
+  };
+  static readonly DebtCeilingExceeded = {
+    name: "DebtCeilingExceeded",
+    code: 6021,
+    message: "Minting would exceed the debt ceiling of the synthetic asset",
+  };
+  static readonly AccountDebtCapExceeded = {
+    name: "AccountDebtCapExceeded",
+    code: 6022,
+    message: "Minting would exceed the debt cap of a single margin account",
+  };
+  static readonly DebtBelowMinimum = {
+    name: "DebtBelowMinimum",
+    code: 6023,
+    message:
+      "The margin account debt must be repaid in full or stay above the minimum debt",
+  };
+  static readonly InvalidDebtLimits = {
+    name: "InvalidDebtLimits",
+    code: 6024,
+    message: "The per-account debt cap must be at least the minimum debt",
+  };
+  static readonly CollateralAccountingMismatch = {
+    name: "CollateralAccountingMismatch",
+    code: 6025,
+    message:
+      "A vault holds less collateral than deposited into the synthetic asset",
+  };
+  static readonly DebtAccountingMismatch = {
+    name: "DebtAccountingMismatch",
+    code: 6026,
+    message:
+      "The synthetic supply exceeds the debt owed to the synthetic asset",
+  };
+  static readonly MarginAccountHasDebt = {
+    name: "MarginAccountHasDebt",
+    code: 6027,
+    message: "The margin account has outstanding debt",
+  };
+  static readonly SyntheticAssetNotActive = {
+    name: "SyntheticAssetNotActive",
+    code: 6028,
+    message: "The synthetic asset is not active and can't be minted",
+  };
+  static readonly SyntheticAssetShutdown = {
+    name: "SyntheticAssetShutdown",
+    code: 6029,
+    message: "The synthetic asset is shut down",
+  };
+  static readonly SyntheticAssetNotShutdown = {
+    name: "SyntheticAssetNotShutdown",
+    code: 6030,
+    message: "The synthetic asset is not shut down",
+  };
+  static readonly InvalidSettlementPrice = {
+    name: "InvalidSettlementPrice",
+    code: 6031,
+    message: "The settlement price must be positive",
+  };
+  static readonly SettlementPending = {
+    name: "SettlementPending",
+    code: 6032,
+    message: "All debt must be settled and fees collected before redeeming",
+  };
+  static readonly InvalidMarginAccount = {
+    name: "InvalidMarginAccount",
+    code: 6033,
+    message: "The margin account belongs to another synthetic asset",
+  };
+  static readonly InvalidRedemptionOrder = {
+    name: "InvalidRedemptionOrder",
+    code: 6034,
+    message:
+      "Margin accounts must be passed in order of collateral ratio, lowest first",
+  };
+  static readonly CircuitBreakerCooldown = {
+    name: "CircuitBreakerCooldown",
+    code: 6035,
+    message:
+      "The circuit breaker is cooling down after the oracle price moved too fast",
+  };
+  static readonly InvalidOracleOwner = {
+    name: "InvalidOracleOwner",
+    code: 6036,
+    message: "The oracle account is not owned by an allowed oracle program",
+  };
+  static readonly InvalidSymbol = {
+    name: "InvalidSymbol",
+    code: 6037,
+    message: "Symbols must be between 1 and 32 bytes",
+  };
+  static readonly InvalidOraclePrograms = {
+    name: "InvalidOraclePrograms",
+    code: 6038,
+    message:
+      "At most 4 oracle programs can be allowed, none of them the default pubkey",
+  };
+  static readonly InvalidProgramAuthority = {
+    name: "InvalidProgramAuthority",
+    code: 6039,
+    message:
+      "Only the program upgrade authority can initialize the program config",
+  };
+  static readonly DuplicateMarginAccount = {
+    name: "DuplicateMarginAccount",
+    code: 6040,
+    message: "A margin account can only be redeemed from once per redemption",
+  };
+  static readonly MarginAccountsOpen = {
+    name: "MarginAccountsOpen",
+    code: 6041,
     message:
-      "Collateral decimals is rqeuired to be 6 because it's value is hardcoded to $1",
+      "Every margin account must be closed before closing the synthetic asset",
   };
 
   static fromErrorCode(errorCode: number): any {
@@ -62,7 +352,83 @@ export class ResynthError {
       case 6002:
         return ResynthError.Undercollateralized;
       case 6003:
-        return ResynthError.InvalidCollateralMintDecimals;
+        return ResynthError.MarginAccountHealthy;
+      case 6004:
+        return ResynthError.InvalidLiquidationParameters;
+      case 6005:
+        return ResynthError.MathOverflow;
+      case 6006:
+        return ResynthError.OracleNotTrading;
+      case 6007:
+        return ResynthError.OracleConfidenceTooWide;
+      case 6008:
+        return ResynthError.InvalidOracleParameters;
+      case 6009:
+        return ResynthError.InvalidCollateralRatios;
+      case 6010:
+        return ResynthError.InvalidCollateral;
+      case 6011:
+        return ResynthError.InvalidCollateralParameters;
+      case 6012:
+        return ResynthError.CollateralTypesFull;
+      case 6013:
+        return ResynthError.InvalidFeeParameters;
+      case 6014:
+        return ResynthError.InsufficientCollateralForFee;
+      case 6015:
+        return ResynthError.InvalidDebtPool;
+      case 6016:
+        return ResynthError.SyntheticAssetPooled;
+      case 6017:
+        return ResynthError.DebtPoolFull;
+      case 6018:
+        return ResynthError.OutstandingDebt;
+      case 6019:
+        return ResynthError.CrossMarginPositionsFull;
+      case 6020:
+        return ResynthError.InvalidCrossMarginPosition;
+      case 6021:
+        return ResynthError.DebtCeilingExceeded;
+      case 6022:
+        return ResynthError.AccountDebtCapExceeded;
+      case 6023:
+        return ResynthError.DebtBelowMinimum;
+      case 6024:
+        return ResynthError.InvalidDebtLimits;
+      case 6025:
+        return ResynthError.CollateralAccountingMismatch;
+      case 6026:
+        return ResynthError.DebtAccountingMismatch;
+      case 6027:
+        return ResynthError.MarginAccountHasDebt;
+      case 6028:
+        return ResynthError.SyntheticAssetNotActive;
+      case 6029:
+        return ResynthError.SyntheticAssetShutdown;
+      case 6030:
+        return ResynthError.SyntheticAssetNotShutdown;
+      case 6031:
+        return ResynthError.InvalidSettlementPrice;
+      case 6032:
+        return ResynthError.SettlementPending;
+      case 6033:
+        return ResynthError.InvalidMarginAccount;
+      case 6034:
+        return ResynthError.InvalidRedemptionOrder;
+      case 6035:
+        return ResynthError.CircuitBreakerCooldown;
+      case 6036:
+        return ResynthError.InvalidOracleOwner;
+      case 6037:
+        return ResynthError.InvalidSymbol;
+      case 6038:
+        return ResynthError.InvalidOraclePrograms;
+      case 6039:
+        return ResynthError.InvalidProgramAuthority;
+      case 6040:
+        return ResynthError.DuplicateMarginAccount;
+      case 6041:
+        return ResynthError.MarginAccountsOpen;
       default:
         return { name: "Unknown", code: errorCode };
     }
@@ -1 +1,26 @@
+import { BN } from "@coral-xyz/anchor";
+import { SyntheticAssetParams } from "../types";
+
 export const SYNTH_DECIMALS = 9;
+
+/** Risk parameters for new synthetic assets, with fees and the circuit breaker disabled */
+export const DEFAULT_SYNTHETIC_ASSET_PARAMS: SyntheticAssetParams = {
+  initialCollateralRatioBps: new BN(15_000),
+  maintenanceCollateralRatioBps: new BN(12_000),
+  liquidationBonusBps: new BN(500),
+  liquidationCloseFactorBps: new BN(5_000),
+  maxOracleAge: new BN(60),
+  haltedPriceGracePeriod: new BN(3_600),
+  maxOracleConfidenceBps: new BN(200),
+  pricingPolicy: new BN(0),
+  maxPriceChangeBpsPerSlot: new BN(0),
+  circuitBreakerCooldownSlots: new BN(0),
+  confidenceMultiplierBps: new BN(0),
+  stabilityFeeBps: new BN(0),
+  mintFeeBps: new BN(0),
+  burnFeeBps: new BN(0),
+  redemptionFeeBps: new BN(0),
+  debtCeiling: new BN("18446744073709551615"),
+  maxAccountDebt: new BN(0),
+  minDebt: new BN(0),
+};
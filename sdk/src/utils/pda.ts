@@ -6,12 +6,28 @@ const FEE_RECEIVER_WALLET = new PublicKey(
   "HjnXUGGMgtN9WaPAJxzdwnWip6f76xGp4rUMRoVicsLr"
 );
 
-export function syntheticAssetPDA(
-  programId: PublicKey,
-  syntheticOracle: Address
-) {
+const BPF_LOADER_UPGRADEABLE_PROGRAM_ID = new PublicKey(
+  "BPFLoaderUpgradeab1e11111111111111111111111"
+);
+
+export function programConfigPDA(programId: PublicKey) {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("program_config")],
+    programId
+  )[0];
+}
+
+/** Get the program data account holding the upgrade authority of a program */
+export function programDataPDA(programId: PublicKey) {
+  return PublicKey.findProgramAddressSync(
+    [programId.toBuffer()],
+    BPF_LOADER_UPGRADEABLE_PROGRAM_ID
+  )[0];
+}
+
+export function syntheticAssetPDA(programId: PublicKey, symbol: string) {
   const syntheticAsset = PublicKey.findProgramAddressSync(
-    [Buffer.from("asset"), translateAddress(syntheticOracle).toBuffer()],
+    [Buffer.from("asset"), Buffer.from(symbol)],
     programId
   )[0];
   const collateralVault = PublicKey.findProgramAddressSync(
@@ -29,13 +45,10 @@ export function syntheticAssetPDA(
   return { syntheticAsset, collateralVault, syntheticMint, assetAuthority };
 }
 
-/** Get only the synthetic mint from a synthetic oracle */
-export function syntheticMintPDA(
-  programId: PublicKey,
-  syntheticOracle: Address
-) {
+/** Get only the synthetic mint from a synthetic asset symbol */
+export function syntheticMintPDA(programId: PublicKey, symbol: string) {
   const syntheticAsset = PublicKey.findProgramAddressSync(
-    [Buffer.from("asset"), translateAddress(syntheticOracle).toBuffer()],
+    [Buffer.from("asset"), Buffer.from(symbol)],
     programId
   )[0];
   const syntheticMint = PublicKey.findProgramAddressSync(
@@ -45,6 +58,22 @@ export function syntheticMintPDA(
   return syntheticMint;
 }
 
+/** Get the fee treasury of a synthetic asset, in one of its collateral mints */
+export function treasuryPDA(
+  programId: PublicKey,
+  syntheticAsset: Address,
+  mint: Address
+) {
+  return PublicKey.findProgramAddressSync(
+    [
+      Buffer.from("treasury"),
+      translateAddress(syntheticAsset).toBuffer(),
+      translateAddress(mint).toBuffer(),
+    ],
+    programId
+  )[0];
+}
+
 export function marginAccountPDA(
   programId: PublicKey,
   owner: PublicKey,
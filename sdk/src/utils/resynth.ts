@@ -82,7 +82,7 @@ export async function fetchSyntheticAsset(
   const { oracle } = oracles[syntheticAssetLabel];
   const { syntheticAsset, collateralVault, syntheticMint } = syntheticAssetPDA(
     client.programId,
-    translateAddress(oracle)
+    syntheticAssetLabel
   );
 
   // wallet accounts
@@ -7,12 +7,15 @@ import {
   SystemProgram,
   Transaction,
 } from "@solana/web3.js";
-import { use as chaiUse } from "chai";
+import { expect, use as chaiUse } from "chai";
 import * as chaiAsPromised from "chai-as-promised";
 import {
   Context,
+  DEFAULT_SYNTHETIC_ASSET_PARAMS,
+  marginAccountPDA,
   PythClient,
   ResynthClient,
+  ResynthError,
   syntheticAssetPDA,
   TokenFaucetClient,
 } from "../sdk/src";
@@ -39,6 +42,8 @@ describe("resynth", () => {
 
   const goldDecimals: number = 9;
 
+  const goldSymbol: string = "rsXAU";
+
   // The gold synthetic asset account
   let goldAsset: PublicKey;
 
@@ -102,15 +107,24 @@ describe("resynth", () => {
     });
   });
 
+  it("Initialize program config", async () => {
+    await resynth.initializeProgramConfig({
+      oraclePrograms: [new PublicKey(context.config.pythProgramId)],
+    });
+  });
+
   it("Initialize synthetic gold asset", async () => {
     await resynth.initializeSyntheticAsset({
+      symbol: goldSymbol,
+      params: DEFAULT_SYNTHETIC_ASSET_PARAMS,
       collateralMint: stablecoinMint,
       syntheticOracle: goldOracle,
+      syntheticDecimals: goldDecimals,
     });
 
     ({ syntheticAsset: goldAsset, syntheticMint: goldMint } = syntheticAssetPDA(
       resynth.programId,
-      goldOracle
+      goldSymbol
     ));
   });
 
@@ -148,6 +162,7 @@ describe("resynth", () => {
       oracle: goldOracle,
     });
     await resynth.mintSyntheticAsset({
+      symbol: goldSymbol,
       owner: userA.wallet.publicKey,
       syntheticOracle: goldOracle,
       collateralMint: stablecoinMint,
@@ -157,15 +172,24 @@ describe("resynth", () => {
     });
   });
 
-  //TODO: Fix this test
-  // it("User B mints an unhealthy amount of synthetic gold", async () => {
-  //   await expect(
-  //     mintSyntheticAsset(userB, goldOracle, goldAsset, 2000, 1)
-  //   ).to.be.rejectedWith('"Custom":1');
-  // });
+  it("User B mints an unhealthy amount of synthetic gold", async () => {
+    // $200 of collateral against $180 of debt, at 150% requires $270
+    await expect(
+      resynth.mintSyntheticAsset({
+        symbol: goldSymbol,
+        owner: userB.wallet.publicKey,
+        syntheticOracle: goldOracle,
+        collateralMint: stablecoinMint,
+        collateralAmount: new BN(200 * 10 ** stablecoinDecimals),
+        mintAmount: new BN(0.1 * 10 ** goldDecimals),
+        signers: [userB.wallet],
+      })
+    ).to.be.rejectedWith(`"Custom":${ResynthError.Undercollateralized.code}`);
+  });
 
   it("User B mints a healthy amount of synthetic gold", async () => {
     await resynth.mintSyntheticAsset({
+      symbol: goldSymbol,
       owner: userB.wallet.publicKey,
       syntheticOracle: goldOracle,
       collateralMint: stablecoinMint,
@@ -183,6 +207,7 @@ describe("resynth", () => {
       oracle: goldOracle,
     });
     await resynth.burnSyntheticAsset({
+      symbol: goldSymbol,
       owner: userA.wallet.publicKey,
       syntheticOracle: goldOracle,
       collateralMint: stablecoinMint,
@@ -194,6 +219,7 @@ describe("resynth", () => {
 
   it("User B burns synthetic gold", async () => {
     await resynth.burnSyntheticAsset({
+      symbol: goldSymbol,
       owner: userB.wallet.publicKey,
       syntheticOracle: goldOracle,
       collateralMint: stablecoinMint,
@@ -202,4 +228,60 @@ describe("resynth", () => {
       signers: [userB.wallet],
     });
   });
+
+  it("User A can't liquidate a healthy margin account", async () => {
+    await resynth.mintSyntheticAsset({
+      symbol: goldSymbol,
+      owner: userB.wallet.publicKey,
+      syntheticOracle: goldOracle,
+      collateralMint: stablecoinMint,
+      collateralAmount: new BN(100 * 10 ** stablecoinDecimals),
+      mintAmount: new BN(0.005 * 10 ** goldDecimals),
+      signers: [userB.wallet],
+    });
+
+    await expect(
+      resynth.liquidateMarginAccount({
+        repayAmount: new BN(0.005 * 10 ** goldDecimals),
+        liquidator: userA.wallet.publicKey,
+        syntheticAsset: await resynth.fetchSyntheticAsset(goldAsset),
+        marginAccount: marginAccountPDA(resynth.programId, userB.wallet.publicKey, goldAsset),
+        signers: [userA.wallet],
+      })
+    ).to.be.rejectedWith(`"Custom":${ResynthError.MarginAccountHealthy.code}`);
+  });
+
+  it("User A liquidates user B after the gold price rises", async () => {
+    // $100 of collateral against $100 of debt
+    await pyth.setPrice({
+      price: 20_000,
+      expo: -goldDecimals,
+      conf: 1,
+      oracle: goldOracle,
+    });
+
+    // User A mints the synthetic gold to repay with
+    await resynth.mintSyntheticAsset({
+      symbol: goldSymbol,
+      owner: userA.wallet.publicKey,
+      syntheticOracle: goldOracle,
+      collateralMint: stablecoinMint,
+      collateralAmount: new BN(100 * 10 ** stablecoinDecimals),
+      mintAmount: new BN(0.0025 * 10 ** goldDecimals),
+      signers: [userA.wallet],
+    });
+
+    // Only half of the debt can be repaid, for $50 of collateral plus a 5% bonus
+    const marginAccount = marginAccountPDA(resynth.programId, userB.wallet.publicKey, goldAsset);
+    await resynth.liquidateMarginAccount({
+      repayAmount: new BN(0.005 * 10 ** goldDecimals),
+      liquidator: userA.wallet.publicKey,
+      syntheticAsset: await resynth.fetchSyntheticAsset(goldAsset),
+      marginAccount,
+      signers: [userA.wallet],
+    });
+
+    const { account } = await resynth.fetchMarginAccount(marginAccount);
+    expect(account.collateralDeposited.toNumber()).to.equal(47.5 * 10 ** stablecoinDecimals);
+  });
 });